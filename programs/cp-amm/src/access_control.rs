@@ -1,10 +1,66 @@
-use crate::assert_eq_admin;
+use crate::const_pda;
+use crate::state::AdminState;
+use crate::state::GlobalPause;
 use crate::state::Operator;
 use crate::state::OperatorPermission;
+use crate::state::PauseAction;
 use crate::PoolError;
+use crate::{assert_admin_signers, assert_eq_admin};
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
-pub fn is_admin(signer: &Pubkey) -> Result<()> {
+/// Signer and admin co-signers in remaining accounts must reach the admin threshold of the admin state
+pub fn is_admin<'info>(
+    admin_state: &AccountLoader<'info, AdminState>,
+    signer: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let admin_state = admin_state.load()?;
+    validate_admin_signers(&admin_state, signer, remaining_accounts)
+}
+
+fn validate_admin_signers(
+    admin_state: &AdminState,
+    signer: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let co_signers: Vec<Pubkey> = remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key())
+        .collect();
+    require!(
+        assert_admin_signers(admin_state, signer.key(), &co_signers),
+        PoolError::InvalidAdmin
+    );
+    Ok(())
+}
+
+/// Same as is_admin once the admin state is initialized, predefined admins until then
+pub fn is_admin_or_predefined_admin(
+    admin_state: &AccountInfo,
+    signer: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    // not initialized yet
+    if admin_state.owner != &crate::ID {
+        return is_predefined_admin(signer);
+    }
+
+    let data = admin_state.try_borrow_data()?;
+    let disc = AdminState::DISCRIMINATOR;
+    require!(
+        data.len() >= disc.len() + std::mem::size_of::<AdminState>() && data.starts_with(disc),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let admin_state: AdminState = bytemuck::pod_read_unaligned(
+        &data[disc.len()..disc.len() + std::mem::size_of::<AdminState>()],
+    );
+    validate_admin_signers(&admin_state, signer, remaining_accounts)
+}
+
+/// Only used to initialize the admin state
+pub fn is_predefined_admin(signer: &Pubkey) -> Result<()> {
     require!(assert_eq_admin(signer.key()), PoolError::InvalidAdmin);
     Ok(())
 }
@@ -16,9 +72,69 @@ pub fn is_valid_operator_role<'info>(
 ) -> Result<()> {
     let operator = operator.load()?;
 
+    require!(
+        !operator.is_expired(Clock::get()?.unix_timestamp as u64),
+        PoolError::OperatorRoleExpired
+    );
+
     if operator.whitelisted_address.eq(signer) && operator.is_permission_allow(permission) {
         Ok(())
     } else {
         err!(PoolError::InvalidPermission)
     }
 }
+
+/// For endpoints open to the pool creator or an operator: when the signer is not allowed otherwise,
+/// the operator account at `operator_index` of remaining accounts must hold a valid role with the permission
+pub fn is_valid_remaining_operator_role<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    operator_index: usize,
+    signer: &Pubkey,
+    permission: OperatorPermission,
+) -> Result<()> {
+    let operator_account = remaining_accounts
+        .get(operator_index)
+        .ok_or_else(|| PoolError::MissingOperatorAccount)?;
+    let operator_loader: AccountLoader<'info, Operator> =
+        AccountLoader::try_from(operator_account)?;
+    let operator = operator_loader.load()?;
+    require!(
+        operator.is_valid_role(signer, permission, Clock::get()?.unix_timestamp as u64),
+        PoolError::InvalidAuthority
+    );
+    Ok(())
+}
+
+/// Global pause is optional and goes first in remaining accounts, returns remaining accounts after it.
+/// The action is open when the global pause is not passed or not created yet
+pub fn validate_action_not_paused<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    action: PauseAction,
+) -> Result<&'a [AccountInfo<'info>]> {
+    let Some((account, rest)) = remaining_accounts.split_first() else {
+        return Ok(remaining_accounts);
+    };
+    if account.key() != const_pda::global_pause::ID {
+        return Ok(remaining_accounts);
+    }
+    // not created yet
+    if account.owner != &crate::ID {
+        return Ok(rest);
+    }
+
+    let data = account.try_borrow_data()?;
+    let disc = GlobalPause::DISCRIMINATOR;
+    require!(
+        data.len() >= disc.len() + std::mem::size_of::<GlobalPause>() && data.starts_with(disc),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let global_pause: GlobalPause = bytemuck::pod_read_unaligned(
+        &data[disc.len()..disc.len() + std::mem::size_of::<GlobalPause>()],
+    );
+    require!(
+        !global_pause.is_action_paused(action),
+        PoolError::ActionPaused
+    );
+
+    Ok(rest)
+}
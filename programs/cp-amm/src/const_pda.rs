@@ -22,6 +22,30 @@ pub mod pool_authority {
     pub const BUMP: u8 = POOL_AUTHORITY_AND_BUMP.1;
 }
 
+pub mod global_pause {
+    use super::*;
+
+    const GLOBAL_PAUSE_AND_BUMP: ([u8; 32], u8) = ed25519::derive_program_address(
+        &[crate::constants::seeds::GLOBAL_PAUSE_PREFIX],
+        &crate::ID_CONST.to_bytes(),
+    );
+
+    pub const ID: Pubkey = Pubkey::new_from_array(GLOBAL_PAUSE_AND_BUMP.0);
+    pub const BUMP: u8 = GLOBAL_PAUSE_AND_BUMP.1;
+}
+
+pub mod admin_state {
+    use super::*;
+
+    const ADMIN_STATE_AND_BUMP: ([u8; 32], u8) = ed25519::derive_program_address(
+        &[crate::constants::seeds::ADMIN_STATE_PREFIX],
+        &crate::ID_CONST.to_bytes(),
+    );
+
+    pub const ID: Pubkey = Pubkey::new_from_array(ADMIN_STATE_AND_BUMP.0);
+    pub const BUMP: u8 = ADMIN_STATE_AND_BUMP.1;
+}
+
 // Potential optimization on event authority too since anchor internally do Pubkey::find_program_address during runtime.
 
 #[cfg(test)]
@@ -38,4 +62,24 @@ mod tests {
         assert_eq!(pool_authority::ID, derived_pool_authority);
         assert_eq!(pool_authority::BUMP, derived_bump);
     }
+
+    #[test]
+    fn test_const_global_pause() {
+        let (derived_global_pause, derived_bump) = Pubkey::find_program_address(
+            &[crate::constants::seeds::GLOBAL_PAUSE_PREFIX],
+            &crate::ID,
+        );
+        assert_eq!(global_pause::ID, derived_global_pause);
+        assert_eq!(global_pause::BUMP, derived_bump);
+    }
+
+    #[test]
+    fn test_const_admin_state() {
+        let (derived_admin_state, derived_bump) = Pubkey::find_program_address(
+            &[crate::constants::seeds::ADMIN_STATE_PREFIX],
+            &crate::ID,
+        );
+        assert_eq!(admin_state::ID, derived_admin_state);
+        assert_eq!(admin_state::BUMP, derived_bump);
+    }
 }
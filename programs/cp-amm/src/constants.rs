@@ -44,11 +44,29 @@ pub const NUM_REWARDS: usize = 2;
 pub const REWARD_INDEX_0: usize = 0;
 pub const REWARD_INDEX_1: usize = 1;
 
+// Number of additional rewards supported by pool reward extension, addressed by reward index NUM_REWARDS..
+pub const NUM_EXTENDED_REWARDS: usize = 8;
+
 // Minimum reward duration
 pub const MIN_REWARD_DURATION: u64 = 24 * 60 * 60; // 1 day
 
 pub const MAX_REWARD_DURATION: u64 = 31536000; // 1 year = 365 * 24 * 3600
 
+// Reward duration in slots, for rewards following slot activated pools. 1 slot = 400 mls
+pub const MIN_REWARD_SLOT_DURATION: u64 = 216_000; // 1 day
+pub const MAX_REWARD_SLOT_DURATION: u64 = 78_840_000; // 1 year
+
+// Max boost of reward for vested and permanent locked liquidity, 5x
+pub const MAX_REWARD_BOOST_MULTIPLIER_BPS: u16 = 50_000;
+
+// Number of steps of halving reward emission schedule
+pub const MIN_REWARD_EMISSION_STEPS: u8 = 2;
+pub const MAX_REWARD_EMISSION_STEPS: u8 = 8;
+
+// Percentage of reward emitted in the first half of front loaded reward emission schedule
+pub const MIN_FRONT_LOADED_EMISSION_PERCENTAGE: u8 = 51;
+pub const MAX_FRONT_LOADED_EMISSION_PERCENTAGE: u8 = 100;
+
 // need to export as constant in case someone want to calculate split position numerator
 #[constant]
 pub const SPLIT_POSITION_DENOMINATOR: u32 = 1_000_000_000; // 1b
@@ -58,6 +76,12 @@ pub const MAX_RATE_LIMITER_DURATION_IN_SLOTS: u32 = 108000; // 12 hours
 
 pub const MAX_OPERATION: u8 = 11;
 
+// Max number of explicit periods in extended vesting schedule table
+pub const MAX_VESTING_SCHEDULE_LENGTH: usize = 32;
+
+// Max exponent of polynomial vesting curve
+pub const MAX_VESTING_CURVE_EXPONENT: u32 = 4;
+
 static_assertions::const_assert_eq!(
     MAX_RATE_LIMITER_DURATION_IN_SECONDS * 1000 / 400,
     MAX_RATE_LIMITER_DURATION_IN_SLOTS
@@ -125,6 +149,33 @@ pub mod zap {
         &zap::constants::JUP_V6_SHARED_ACCOUNT_ROUTE_DISC;
 }
 
+/// Store constants related to position nft metadata
+pub mod position_nft_metadata {
+    use anchor_lang::prelude::*;
+
+    pub const POOL_KEY: &str = "pool";
+    pub const TOKEN_A_MINT_KEY: &str = "token_a_mint";
+    pub const TOKEN_B_MINT_KEY: &str = "token_b_mint";
+    pub const TOKEN_A_SYMBOL_KEY: &str = "token_a_symbol";
+    pub const TOKEN_B_SYMBOL_KEY: &str = "token_b_symbol";
+    pub const UNLOCKED_LIQUIDITY_KEY: &str = "unlocked_liquidity";
+    pub const VESTED_LIQUIDITY_KEY: &str = "vested_liquidity";
+    pub const PERMANENT_LOCKED_LIQUIDITY_KEY: &str = "permanent_locked_liquidity";
+
+    /// Same as max symbol length of metaplex token metadata
+    pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+    /// Max length of a u128 in decimal
+    pub const MAX_LIQUIDITY_LENGTH: usize = 39;
+
+    /// Space reserved when the position nft is created, so symbols and liquidity attributes can be refreshed later without rent top up.
+    /// Symbols are created as empty string, liquidity attributes are created with at least 1 character.
+    pub const RESERVED_SPACE: usize = 2 * MAX_SYMBOL_LENGTH + 3 * (MAX_LIQUIDITY_LENGTH - 1);
+
+    pub const METAPLEX_TOKEN_METADATA_PROGRAM_ID: Pubkey =
+        pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
 pub mod activation {
     #[cfg(not(feature = "local"))]
     pub const SLOT_BUFFER: u64 = 9000; // 1 slot = 400 mls => 1 hour
@@ -256,6 +307,18 @@ pub mod seeds {
     pub const REWARD_VAULT_PREFIX: &[u8] = b"reward_vault";
     pub const CLAIM_FEE_OPERATOR_PREFIX: &[u8] = b"cf_operator";
     pub const OPERATOR_PREFIX: &[u8] = b"operator";
+    pub const REWARD_EXTENSION_PREFIX: &[u8] = b"reward_extension";
+    pub const POSITION_REWARD_EXTENSION_PREFIX: &[u8] = b"position_reward_extension";
+    pub const REWARD_FUNDING_PREFIX: &[u8] = b"reward_funding";
+    pub const REWARD_FUNDER_LEDGER_PREFIX: &[u8] = b"reward_funder_ledger";
+    pub const TRADING_REWARD_CAMPAIGN_PREFIX: &[u8] = b"trading_reward_campaign";
+    pub const TRADING_REWARD_VAULT_PREFIX: &[u8] = b"trading_reward_vault";
+    pub const TRADER_REWARD_PREFIX: &[u8] = b"trader_reward";
+    pub const POOL_ALLOWLIST_PREFIX: &[u8] = b"pool_allowlist";
+    pub const ALLOWLIST_ENTRY_PREFIX: &[u8] = b"allowlist_entry";
+    pub const POOL_CREATOR_TRANSFER_PREFIX: &[u8] = b"pool_creator_transfer";
+    pub const GLOBAL_PAUSE_PREFIX: &[u8] = b"global_pause";
+    pub const ADMIN_STATE_PREFIX: &[u8] = b"admin_state";
 }
 
 pub mod treasury {
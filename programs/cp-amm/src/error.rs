@@ -205,4 +205,124 @@ pub enum PoolError {
 
     #[msg("Invalid zap accounts")]
     InvalidZapAccounts,
+
+    #[msg("Invalid position nft mint")]
+    InvalidPositionNftMint,
+
+    #[msg("Invalid token metadata account")]
+    InvalidTokenMetadataAccount,
+
+    #[msg("Missing reward extension account")]
+    MissingRewardExtensionAccount,
+
+    #[msg("Invalid reward extension account")]
+    InvalidRewardExtensionAccount,
+
+    #[msg("Invalid reward boost multiplier")]
+    InvalidRewardBoostMultiplier,
+
+    #[msg("Reward already funded")]
+    RewardAlreadyFunded,
+
+    #[msg("Invalid reward emission schedule")]
+    InvalidRewardEmissionSchedule,
+
+    #[msg("Invalid batch claim accounts")]
+    InvalidBatchClaimAccounts,
+
+    #[msg("Invalid reward funding mode")]
+    InvalidRewardFundingMode,
+
+    #[msg("Invalid reward funding account")]
+    InvalidRewardFundingAccount,
+
+    #[msg("Invalid reward time unit")]
+    InvalidRewardTimeUnit,
+
+    #[msg("Invalid trading reward campaign")]
+    InvalidTradingRewardCampaign,
+
+    #[msg("Trading reward campaign ended")]
+    TradingRewardCampaignEnded,
+
+    #[msg("Trading reward campaign not ended")]
+    TradingRewardCampaignNotEnded,
+
+    #[msg("Invalid trading reward accounts")]
+    InvalidTradingRewardAccounts,
+
+    #[msg("Missing allowlist entry")]
+    MissingAllowlistEntry,
+
+    #[msg("Invalid allowlist entry")]
+    InvalidAllowlistEntry,
+
+    #[msg("Invalid allowlist permission")]
+    InvalidAllowlistPermission,
+
+    #[msg("Pool is already permissioned")]
+    PoolAlreadyPermissioned,
+
+    #[msg("Pool is not empty")]
+    PoolIsNotEmpty,
+
+    #[msg("Pool reward is not finished")]
+    PoolRewardNotFinished,
+
+    #[msg("Price range can only be narrowed when pool has no liquidity")]
+    PriceRangeNarrowingRequiresNoLiquidity,
+
+    #[msg("Invalid pool type")]
+    InvalidPoolType,
+
+    #[msg("Invalid partner account")]
+    InvalidPartnerAccount,
+
+    #[msg("Partner fee not claimed")]
+    PartnerFeeNotClaimed,
+
+    #[msg("Invalid pre-activation buyer")]
+    InvalidPreActivationBuyer,
+
+    #[msg("Invalid liquidity bootstrapping parameters")]
+    InvalidLbpParameters,
+
+    #[msg("Action is paused")]
+    ActionPaused,
+
+    #[msg("Invalid pause actions")]
+    InvalidPauseActions,
+
+    #[msg("Operator role is expired")]
+    OperatorRoleExpired,
+
+    #[msg("Invalid admin rotation")]
+    InvalidAdminRotation,
+
+    #[msg("Invalid admin threshold")]
+    InvalidAdminThreshold,
+
+    #[msg("Funder ledger has pending ineligible reward")]
+    PendingIneligibleReward,
+
+    #[msg("Reward funding has open funder ledgers")]
+    RewardFunderLedgerNotClosed,
+
+    #[msg("Trader reward not claimed")]
+    TraderRewardNotClaimed,
+
+    #[msg("Trading reward campaign has open trader rewards")]
+    TraderRewardNotClosed,
+
+    #[msg("Pool has open trading reward campaigns")]
+    TradingRewardCampaignNotClosed,
+
+    #[msg("Pool creator transfer is pending")]
+    PoolCreatorTransferPending,
+
+    #[msg("Pool allowlist has open entries")]
+    AllowlistEntryNotClosed,
+
+    #[msg("Invalid pool allowlist")]
+    InvalidPoolAllowlist,
 }
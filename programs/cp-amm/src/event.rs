@@ -2,9 +2,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    constants::NUM_REWARDS,
     params::fee_parameters::PoolFeeParameters,
     state::{SplitAmountInfo, SplitPositionInfo, SwapResult2},
-    SplitPositionParameters2, SwapParameters2, UpdatePoolFeesParameters,
+    SplitPositionParameters2, StaticConfigParameters, SwapParameters2, UpdatePoolFeesParameters,
 };
 
 /// Close config
@@ -78,6 +79,17 @@ pub struct EvtClaimPositionFee {
     pub fee_b_claimed: u64,
 }
 
+#[event]
+pub struct EvtBatchClaim {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub number_of_positions: u16,
+    pub fee_a_claimed: u64,
+    pub fee_b_claimed: u64,
+    // Total reward claimed per reward index, 0 if reward accounts are not provided
+    pub rewards_claimed: [u64; NUM_REWARDS],
+}
+
 #[event]
 pub struct EvtCreatePosition {
     pub pool: Pubkey,
@@ -124,6 +136,42 @@ pub struct EvtLockPosition {
     pub liquidity_per_period: u128,
     pub number_of_period: u16,
 }
+#[event]
+pub struct EvtLockPositionWithExtendedVesting {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub vesting: Pubkey,
+    pub cliff_point: u64,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub periodic_unlock_liquidity: u128,
+    pub number_of_period: u16,
+    pub curve_type: u8,
+    pub curve_parameter: u32,
+    pub schedule: Vec<u128>,
+}
+
+#[event]
+pub struct EvtSetVestingRevoker {
+    pub vesting: Pubkey,
+    pub revoker: Pubkey,
+    pub revoke_position: Pubkey,
+}
+
+#[event]
+pub struct EvtRevokeVesting {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub vesting: Pubkey,
+    pub revoker: Pubkey,
+    pub revoke_position: Pubkey,
+    /// Liquidity released to position before revoking
+    pub released_liquidity: u128,
+    /// Locked liquidity moved to revoke position
+    pub revoked_liquidity: u128,
+}
+
 #[event]
 pub struct EvtPermanentLockPosition {
     pub pool: Pubkey,
@@ -153,6 +201,20 @@ pub struct EvtSetPoolStatus {
 }
 
 // Initialize reward
+#[event]
+pub struct EvtInitializeRewardExtension {
+    pub pool: Pubkey,
+    pub reward_extension: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct EvtInitializePositionRewardExtension {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_reward_extension: Pubkey,
+}
+
 #[event]
 pub struct EvtInitializeReward {
     // Liquidity pool
@@ -191,6 +253,61 @@ pub struct EvtFundReward {
     pub post_reward_rate: u128,
 }
 
+#[event]
+pub struct EvtTopUpReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Address of the funder
+    pub funder: Pubkey,
+    // Mint reward
+    pub mint_reward: Pubkey,
+    // Index of the farm reward being funded
+    pub reward_index: u8,
+    // Amount of farm reward funded
+    pub amount: u64,
+    // Amount excluded transfer fee
+    pub transfer_fee_excluded_amount_in: u64,
+    // Ineligible reward shared among previous funders
+    pub distributed_ineligible_reward: u64,
+    // Total amount funded by the funder
+    pub funder_funded_amount: u64,
+    // reward duration end
+    pub reward_duration_end: u64,
+    // reward rate before funding
+    pub pre_reward_rate: u128,
+    // reward rate after funding
+    pub post_reward_rate: u128,
+}
+
+#[event]
+pub struct EvtEnablePermissionlessRewardFunding {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward
+    pub reward_index: u8,
+    pub reward_funding: Pubkey,
+}
+
+#[event]
+pub struct EvtInitializeRewardFunderLedger {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward
+    pub reward_index: u8,
+    pub funder: Pubkey,
+    pub funder_ledger: Pubkey,
+}
+
+#[event]
+pub struct EvtCloseRewardFunderLedger {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward
+    pub reward_index: u8,
+    pub funder: Pubkey,
+    pub funder_ledger: Pubkey,
+}
+
 #[event]
 pub struct EvtClaimReward {
     // Liquidity pool
@@ -219,6 +336,46 @@ pub struct EvtUpdateRewardDuration {
     pub new_reward_duration: u64,
 }
 
+#[event]
+pub struct EvtUpdateRewardBoost {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward being updated
+    pub reward_index: u8,
+    // Old locked liquidity weight in basis point
+    pub old_boost_multiplier_bps: u16,
+    // New locked liquidity weight in basis point
+    pub new_boost_multiplier_bps: u16,
+}
+
+#[event]
+pub struct EvtUpdateRewardEmissionSchedule {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward being updated
+    pub reward_index: u8,
+    // Old emission schedule and its parameter
+    pub old_emission_schedule: u8,
+    pub old_emission_parameter: u8,
+    // New emission schedule and its parameter
+    pub new_emission_schedule: u8,
+    pub new_emission_parameter: u8,
+}
+
+#[event]
+pub struct EvtUpdateRewardTimeUnit {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Index of the farm reward being updated
+    pub reward_index: u8,
+    // Old unit of reward duration
+    pub old_time_unit: u8,
+    // New unit of reward duration
+    pub new_time_unit: u8,
+    // Reward duration in the new unit
+    pub reward_duration: u64,
+}
+
 #[event]
 pub struct EvtUpdateRewardFunder {
     // Liquidity pool
@@ -279,3 +436,323 @@ pub struct EvtUpdatePoolFees {
     pub operator: Pubkey,
     pub params: UpdatePoolFeesParameters,
 }
+
+#[event]
+pub struct EvtInitializeTradingRewardCampaign {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Mint address of the campaign reward
+    pub reward_mint: Pubkey,
+    // Address of the funder
+    pub funder: Pubkey,
+    // Creator of the campaign, pool creator or operator
+    pub creator: Pubkey,
+    // Index of the campaign in the pool
+    pub campaign_index: u64,
+    // Campaign start and end, in pool activation type
+    pub start_point: u64,
+    pub end_point: u64,
+    // How swaps earn points
+    pub point_mode: u8,
+}
+
+#[event]
+pub struct EvtFundTradingReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Address of the funder
+    pub funder: Pubkey,
+    // Amount of funder token transferred
+    pub amount: u64,
+    // Amount added to the campaign reward, excluding transfer fee
+    pub transfer_fee_excluded_amount_in: u64,
+    // Total reward of the campaign after funding
+    pub total_reward: u64,
+}
+
+#[event]
+pub struct EvtInitializeTraderReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Owner of the points
+    pub trader: Pubkey,
+    // Point account of the trader
+    pub trader_reward: Pubkey,
+}
+
+#[event]
+pub struct EvtClaimTradingReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Owner of the points
+    pub trader: Pubkey,
+    // Points earned by the trader
+    pub points: u128,
+    // Reward claimed
+    pub total_reward: u64,
+}
+
+#[event]
+pub struct EvtWithdrawIneligibleTradingReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Address of the funder
+    pub funder: Pubkey,
+    // Reward withdrawn
+    pub amount: u64,
+}
+
+#[event]
+pub struct EvtCloseTraderReward {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Trading reward campaign
+    pub campaign: Pubkey,
+    // Owner of the points
+    pub trader: Pubkey,
+    // Closed point account of the trader
+    pub trader_reward: Pubkey,
+}
+
+#[event]
+pub struct EvtCloseTradingRewardCampaign {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Closed trading reward campaign
+    pub campaign: Pubkey,
+    // Address of the funder
+    pub funder: Pubkey,
+    // Reward left in the vault, returned to funder
+    pub amount: u64,
+}
+
+#[event]
+pub struct EvtInitializePoolAllowlist {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Allowlist of the pool
+    pub pool_allowlist: Pubkey,
+    // Who can add and remove wallets of the allowlist
+    pub authority: Pubkey,
+    // Pool creator or operator
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct EvtCreateAllowlistEntry {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Allowlisted wallet
+    pub wallet: Pubkey,
+    // Allowlist entry of the wallet
+    pub allowlist_entry: Pubkey,
+    // Bit flags of allowed actions
+    pub permission: u8,
+}
+
+#[event]
+pub struct EvtCloseAllowlistEntry {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Removed wallet
+    pub wallet: Pubkey,
+    // Closed allowlist entry
+    pub allowlist_entry: Pubkey,
+}
+
+#[event]
+pub struct EvtClosePool {
+    // Closed pool
+    pub pool: Pubkey,
+    // Pool creator, receives rent
+    pub creator: Pubkey,
+    // token a left in the vault, transferred to creator
+    pub token_a_amount: u64,
+    // token b left in the vault, transferred to creator
+    pub token_b_amount: u64,
+}
+
+#[event]
+pub struct EvtUpgradePoolVersion {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Operator
+    pub operator: Pubkey,
+    // Pool version before upgrade
+    pub previous_version: u8,
+    // Pool version after upgrade
+    pub version: u8,
+    // Pool fees update applied after upgrade
+    pub pool_fees: Option<UpdatePoolFeesParameters>,
+}
+
+#[event]
+pub struct EvtUpdatePoolPriceRange {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Pool creator or operator
+    pub signer: Pubkey,
+    pub previous_sqrt_min_price: u128,
+    pub previous_sqrt_max_price: u128,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    // Current pool price, inside the new price range
+    pub sqrt_price: u128,
+    // Pool liquidity, unchanged
+    pub liquidity: u128,
+    // Token a reserve added for the widened max price
+    pub token_a_amount: u64,
+    // Token b reserve added for the widened min price
+    pub token_b_amount: u64,
+    // Token a transferred by the signer, transfer fee included
+    pub total_amount_a: u64,
+    // Token b transferred by the signer, transfer fee included
+    pub total_amount_b: u64,
+}
+
+#[event]
+pub struct EvtTransferPoolCreator {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Current pool creator
+    pub creator: Pubkey,
+    // Pool creator once accepted
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct EvtAcceptPoolCreator {
+    // Liquidity pool
+    pub pool: Pubkey,
+    pub previous_creator: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct EvtCancelPoolCreatorTransfer {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Current pool creator
+    pub creator: Pubkey,
+    // Pool creator of the cancelled transfer
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdatePoolPartner {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Pool creator
+    pub creator: Pubkey,
+    pub previous_partner: Pubkey,
+    pub partner: Pubkey,
+}
+
+#[event]
+pub struct EvtLowerPoolBaseFee {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Pool creator
+    pub creator: Pubkey,
+    pub previous_cliff_fee_numerator: u64,
+    pub cliff_fee_numerator: u64,
+}
+
+#[event]
+pub struct EvtUpdateConfigPreActivationBuyer {
+    // Config
+    pub config: Pubkey,
+    // Operator
+    pub operator: Pubkey,
+    // Pre-activation buyer mode, see PreActivationBuyerMode
+    pub mode: u8,
+    // Program id in ProgramDerived mode, or whitelisted address in Address mode
+    pub pre_activation_buyer: Pubkey,
+}
+
+#[event]
+pub struct EvtInitializeLbp {
+    // Liquidity pool
+    pub pool: Pubkey,
+    // Pool creator
+    pub creator: Pubkey,
+    // sqrt max price at start point
+    pub start_sqrt_max_price: u128,
+    // sqrt max price at end point
+    pub end_sqrt_max_price: u128,
+    // Pool activation point
+    pub start_point: u64,
+    pub end_point: u64,
+}
+
+#[event]
+pub struct EvtPauseActions {
+    // Actions paused by this instruction, bit index refers to PauseAction
+    pub actions: u8,
+    // Paused actions after this instruction
+    pub paused_actions: u8,
+}
+
+#[event]
+pub struct EvtUnpauseActions {
+    // Actions unpaused by this instruction, bit index refers to PauseAction
+    pub actions: u8,
+    // Paused actions after this instruction
+    pub paused_actions: u8,
+}
+
+#[event]
+pub struct EvtUpdateConfig {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+    // Config version after the update
+    pub version: u64,
+    pub old_config_parameters: StaticConfigParameters,
+    pub new_config_parameters: StaticConfigParameters,
+}
+
+#[event]
+pub struct EvtUpdateOperatorPermission {
+    pub operator: Pubkey,
+    pub whitelisted_address: Pubkey,
+    pub old_permission: u128,
+    pub new_permission: u128,
+    // Expiry timestamp of the role, 0 means the role never expires
+    pub old_expires_at: u64,
+    pub new_expires_at: u64,
+}
+
+#[event]
+pub struct EvtInitializeAdminState {
+    pub admins: Vec<Pubkey>,
+}
+
+#[event]
+pub struct EvtProposeAdminRotation {
+    // Admin to be replaced, default pubkey when a new admin is added
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct EvtAcceptAdminRotation {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdateAdminThreshold {
+    pub old_threshold: u8,
+    pub new_threshold: u8,
+}
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::state::AdminState;
+
 pub mod admin {
     use anchor_lang::{prelude::Pubkey, solana_program::pubkey::pubkey};
 
+    /// Admins allowed to initialize the admin state, which is the source of admins afterward
     pub const ADMINS: [Pubkey; 2] = [
         pubkey!("5unTfT2kssBuNvHPY6LbJfJpLqEcdMxGYLWHwShaeTLi"),
         pubkey!("DHLXnJdACTY83yKwnUkeoDjqi4QBbsYGa1v8tJL76ViX"),
@@ -20,3 +23,23 @@ pub fn assert_eq_admin(admin: Pubkey) -> bool {
         .iter()
         .any(|predefined_admin| predefined_admin.eq(&admin))
 }
+
+// local tests can not sign for the predefined admins seeded in the admin state,
+// threshold checks are covered by unit tests of the non local version
+#[cfg(feature = "local")]
+pub fn assert_admin_signers(
+    _admin_state: &AdminState,
+    _signer: Pubkey,
+    _co_signers: &[Pubkey],
+) -> bool {
+    true
+}
+
+#[cfg(not(feature = "local"))]
+pub fn assert_admin_signers(
+    admin_state: &AdminState,
+    signer: Pubkey,
+    co_signers: &[Pubkey],
+) -> bool {
+    admin_state.is_authorized(&signer, co_signers)
+}
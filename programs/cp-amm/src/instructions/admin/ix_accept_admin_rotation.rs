@@ -0,0 +1,25 @@
+use crate::{const_pda, state::AdminState, EvtAcceptAdminRotation};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdminRotationCtx<'info> {
+    #[account(mut, address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    /// Admin proposed by the pending rotation
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handle_accept_admin_rotation(ctx: Context<AcceptAdminRotationCtx>) -> Result<()> {
+    let mut admin_state = ctx.accounts.admin_state.load_mut()?;
+    let old_admin = admin_state.pending_old_admin;
+    admin_state.accept_rotation(ctx.accounts.new_admin.key())?;
+
+    emit_cpi!(EvtAcceptAdminRotation {
+        old_admin,
+        new_admin: ctx.accounts.new_admin.key(),
+    });
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::state::Operator;
+use crate::{const_pda, state::Operator};
 use anchor_lang::prelude::*;
 
 #[event_cpi]
@@ -10,6 +10,10 @@ pub struct CloseOperatorAccountCtx<'info> {
     )]
     pub operator: AccountLoader<'info, Operator>,
 
+    /// CHECK: admin state, predefined admins are checked until it is initialized
+    #[account(address = const_pda::admin_state::ID)]
+    pub admin_state: UncheckedAccount<'info>,
+
     pub signer: Signer<'info>,
 
     /// CHECK: Account to receive closed account rental SOL
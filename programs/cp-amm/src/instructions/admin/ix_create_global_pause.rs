@@ -0,0 +1,35 @@
+use crate::{
+    const_pda,
+    constants::seeds::GLOBAL_PAUSE_PREFIX,
+    state::{AdminState, GlobalPause},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateGlobalPauseCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [GLOBAL_PAUSE_PREFIX.as_ref()],
+        bump,
+        space = 8 + GlobalPause::INIT_SPACE
+    )]
+    pub global_pause: AccountLoader<'info, GlobalPause>,
+
+    #[account(address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_global_pause(ctx: Context<CreateGlobalPauseCtx>) -> Result<()> {
+    // nothing is paused on creation
+    ctx.accounts.global_pause.load_init()?;
+    Ok(())
+}
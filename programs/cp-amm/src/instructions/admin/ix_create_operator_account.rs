@@ -1,4 +1,5 @@
 use crate::{
+    const_pda,
     constants::{seeds::OPERATOR_PREFIX, MAX_OPERATION},
     state::Operator,
     PoolError,
@@ -23,6 +24,10 @@ pub struct CreateOperatorAccountCtx<'info> {
     /// CHECK: can be any address
     pub whitelisted_address: UncheckedAccount<'info>,
 
+    /// CHECK: admin state, predefined admins are checked until it is initialized
+    #[account(address = const_pda::admin_state::ID)]
+    pub admin_state: UncheckedAccount<'info>,
+
     pub signer: Signer<'info>,
 
     #[account(mut)]
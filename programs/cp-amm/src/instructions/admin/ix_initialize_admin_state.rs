@@ -0,0 +1,37 @@
+use crate::{
+    admin::admin::ADMINS, constants::seeds::ADMIN_STATE_PREFIX, state::AdminState,
+    EvtInitializeAdminState,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAdminStateCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [ADMIN_STATE_PREFIX.as_ref()],
+        bump,
+        space = 8 + AdminState::INIT_SPACE
+    )]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Seed the admin state with the predefined admins, with threshold of 1
+pub fn handle_initialize_admin_state(ctx: Context<InitializeAdminStateCtx>) -> Result<()> {
+    let mut admin_state = ctx.accounts.admin_state.load_init()?;
+    admin_state.initialize(&ADMINS)?;
+
+    emit_cpi!(EvtInitializeAdminState {
+        admins: ADMINS.to_vec(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::{const_pda, state::AdminState, EvtProposeAdminRotation};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdminRotationCtx<'info> {
+    #[account(mut, address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Propose new_admin to replace old_admin, or to be added when old_admin is default pubkey.
+/// Replaces any pending rotation, and takes effect when new_admin accepts
+pub fn handle_propose_admin_rotation(
+    ctx: Context<ProposeAdminRotationCtx>,
+    old_admin: Pubkey,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let mut admin_state = ctx.accounts.admin_state.load_mut()?;
+    admin_state.propose_rotation(old_admin, new_admin)?;
+
+    emit_cpi!(EvtProposeAdminRotation {
+        old_admin,
+        new_admin,
+        proposer: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{const_pda, state::AdminState, EvtUpdateAdminThreshold};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateAdminThresholdCtx<'info> {
+    #[account(mut, address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_admin_threshold(
+    ctx: Context<UpdateAdminThresholdCtx>,
+    threshold: u8,
+) -> Result<()> {
+    let mut admin_state = ctx.accounts.admin_state.load_mut()?;
+    let old_threshold = admin_state.threshold;
+    admin_state.update_threshold(threshold)?;
+
+    emit_cpi!(EvtUpdateAdminThreshold {
+        old_threshold,
+        new_threshold: threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::{
+    const_pda,
+    state::{AdminState, GlobalPause},
+    EvtPauseActions, EvtUnpauseActions,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateGlobalPauseCtx<'info> {
+    #[account(mut, address = const_pda::global_pause::ID)]
+    pub global_pause: AccountLoader<'info, GlobalPause>,
+
+    #[account(address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_pause_actions(ctx: Context<UpdateGlobalPauseCtx>, actions: u8) -> Result<()> {
+    let mut global_pause = ctx.accounts.global_pause.load_mut()?;
+    global_pause.pause(actions, Clock::get()?.unix_timestamp as u64)?;
+
+    emit_cpi!(EvtPauseActions {
+        actions,
+        paused_actions: global_pause.paused_actions,
+    });
+
+    Ok(())
+}
+
+pub fn handle_unpause_actions(ctx: Context<UpdateGlobalPauseCtx>, actions: u8) -> Result<()> {
+    let mut global_pause = ctx.accounts.global_pause.load_mut()?;
+    global_pause.unpause(actions, Clock::get()?.unix_timestamp as u64)?;
+
+    emit_cpi!(EvtUnpauseActions {
+        actions,
+        paused_actions: global_pause.paused_actions,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::{
+    const_pda,
+    constants::MAX_OPERATION,
+    state::{AdminState, Operator},
+    EvtUpdateOperatorPermission, PoolError,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOperatorPermissionCtx<'info> {
+    #[account(mut)]
+    pub operator: AccountLoader<'info, Operator>,
+
+    #[account(address = const_pda::admin_state::ID)]
+    pub admin_state: AccountLoader<'info, AdminState>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_operator_permission(
+    ctx: Context<UpdateOperatorPermissionCtx>,
+    permission: u128,
+    expires_at: Option<u64>,
+) -> Result<()> {
+    require!(
+        permission > 0 && permission < 1 << MAX_OPERATION,
+        PoolError::InvalidPermission
+    );
+
+    let expires_at = match expires_at {
+        Some(expires_at) => {
+            let current_timestamp = Clock::get()?.unix_timestamp as u64;
+            require!(expires_at > current_timestamp, PoolError::InvalidInput);
+            expires_at
+        }
+        None => 0,
+    };
+
+    let mut operator = ctx.accounts.operator.load_mut()?;
+    let old_permission = operator.permission;
+    let old_expires_at = operator.expires_at;
+    operator.update_permission(permission, expires_at);
+
+    emit_cpi!(EvtUpdateOperatorPermission {
+        operator: ctx.accounts.operator.key(),
+        whitelisted_address: operator.whitelisted_address,
+        old_permission,
+        new_permission: permission,
+        old_expires_at,
+        new_expires_at: expires_at,
+    });
+
+    Ok(())
+}
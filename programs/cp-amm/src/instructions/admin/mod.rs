@@ -2,5 +2,19 @@ pub mod ix_close_operator_account;
 pub mod ix_create_operator_account;
 pub use ix_close_operator_account::*;
 pub use ix_create_operator_account::*;
+pub mod ix_update_operator_permission;
+pub use ix_update_operator_permission::*;
 pub mod auth;
 pub use auth::*;
+pub mod ix_create_global_pause;
+pub use ix_create_global_pause::*;
+pub mod ix_update_global_pause;
+pub use ix_update_global_pause::*;
+pub mod ix_initialize_admin_state;
+pub use ix_initialize_admin_state::*;
+pub mod ix_propose_admin_rotation;
+pub use ix_propose_admin_rotation::*;
+pub mod ix_accept_admin_rotation;
+pub use ix_accept_admin_rotation::*;
+pub mod ix_update_admin_threshold;
+pub use ix_update_admin_threshold::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Pool, PoolCreatorTransfer},
+    EvtAcceptPoolCreator,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptPoolCreatorCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = new_creator,
+        close = rent_receiver
+    )]
+    pub pool_creator_transfer: AccountLoader<'info, PoolCreatorTransfer>,
+
+    pub new_creator: Signer<'info>,
+
+    /// CHECK: creator who started the transfer
+    #[account(mut, address = pool_creator_transfer.load()?.creator)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_accept_pool_creator(ctx: Context<AcceptPoolCreatorCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let previous_creator = pool.creator;
+
+    pool.creator = ctx.accounts.new_creator.key();
+    pool.metrics.pending_creator_transfer_flag = 0;
+
+    emit_cpi!(EvtAcceptPoolCreator {
+        pool: ctx.accounts.pool.key(),
+        previous_creator,
+        creator: pool.creator,
+    });
+
+    Ok(())
+}
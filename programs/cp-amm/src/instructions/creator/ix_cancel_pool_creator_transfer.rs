@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Pool, PoolCreatorTransfer},
+    EvtCancelPoolCreatorTransfer,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelPoolCreatorTransferCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        close = rent_receiver
+    )]
+    pub pool_creator_transfer: AccountLoader<'info, PoolCreatorTransfer>,
+
+    pub creator: Signer<'info>,
+
+    /// CHECK: creator who started the transfer
+    #[account(mut, address = pool_creator_transfer.load()?.creator)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_cancel_pool_creator_transfer(
+    ctx: Context<CancelPoolCreatorTransferCtx>,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    pool.metrics.pending_creator_transfer_flag = 0;
+
+    let pool_creator_transfer = ctx.accounts.pool_creator_transfer.load()?;
+
+    emit_cpi!(EvtCancelPoolCreatorTransfer {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        new_creator: pool_creator_transfer.new_creator,
+    });
+
+    Ok(())
+}
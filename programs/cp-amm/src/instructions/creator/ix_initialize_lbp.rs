@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    state::{Pool, PoolType},
+    EvtInitializeLbp, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct InitializeLbpParameters {
+    /// sqrt max price at end point, between sqrt min price and current sqrt max price
+    pub end_sqrt_max_price: u128,
+    /// point sqrt max price reaches end sqrt max price, in pool activation type
+    pub end_point: u64,
+}
+
+/// Put the pool in liquidity bootstrapping mode: from activation point, sqrt max price declines
+/// linearly to end sqrt max price, pushing down pool price without trades
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeLbpCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handle_initialize_lbp(
+    ctx: Context<InitializeLbpCtx>,
+    params: InitializeLbpParameters,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    // price range of config pools is set by the config
+    require!(
+        pool.pool_type != u8::from(PoolType::Permissionless),
+        PoolError::InvalidPoolType
+    );
+
+    // schedule is part of pool launch, it can't change once traders can swap
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    require!(
+        current_point < pool.activation_point,
+        PoolError::InvalidLbpParameters
+    );
+
+    pool.initialize_lbp(params.end_sqrt_max_price, params.end_point)?;
+
+    emit_cpi!(EvtInitializeLbp {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        start_sqrt_max_price: pool.sqrt_max_price,
+        end_sqrt_max_price: params.end_sqrt_max_price,
+        start_point: pool.activation_point,
+        end_point: params.end_point,
+    });
+
+    Ok(())
+}
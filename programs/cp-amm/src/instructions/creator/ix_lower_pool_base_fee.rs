@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_fee::BaseFeeHandlerBuilder, state::Pool, EvtLowerPoolBaseFee, PoolError,
+    UpdatePoolFeesParameters,
+};
+
+/// Lower the static base fee of a pool. Fee can only go down, and stays within fee bounds
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LowerPoolBaseFeeCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handle_lower_pool_base_fee(
+    ctx: Context<LowerPoolBaseFeeCtx>,
+    cliff_fee_numerator: u64,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    let previous_cliff_fee_numerator = pool
+        .pool_fees
+        .base_fee
+        .base_fee_info
+        .get_base_fee_handler()?
+        .get_min_base_fee_numerator()?;
+    require!(
+        cliff_fee_numerator < previous_cliff_fee_numerator,
+        PoolError::InvalidUpdatePoolFeesParameters
+    );
+
+    // validate base fee is static and new cliff fee numerator is within fee bounds
+    pool.validate_and_update_pool_fees(&UpdatePoolFeesParameters {
+        cliff_fee_numerator: Some(cliff_fee_numerator),
+        dynamic_fee: None,
+    })?;
+
+    emit_cpi!(EvtLowerPoolBaseFee {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        previous_cliff_fee_numerator,
+        cliff_fee_numerator,
+    });
+
+    Ok(())
+}
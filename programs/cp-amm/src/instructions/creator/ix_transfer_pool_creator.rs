@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::POOL_CREATOR_TRANSFER_PREFIX,
+    state::{Pool, PoolCreatorTransfer},
+    EvtTransferPoolCreator, PoolError,
+};
+
+/// Start transfer of the pool creator authority. The new creator must accept it
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferPoolCreatorCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [POOL_CREATOR_TRANSFER_PREFIX.as_ref(), pool.key().as_ref()],
+        bump,
+        payer = creator,
+        space = 8 + PoolCreatorTransfer::INIT_SPACE
+    )]
+    pub pool_creator_transfer: AccountLoader<'info, PoolCreatorTransfer>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_transfer_pool_creator(
+    ctx: Context<TransferPoolCreatorCtx>,
+    new_creator: Pubkey,
+) -> Result<()> {
+    require!(
+        new_creator != Pubkey::default() && new_creator != ctx.accounts.creator.key(),
+        PoolError::InvalidPoolCreator
+    );
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    pool.metrics.pending_creator_transfer_flag = 1;
+
+    let mut pool_creator_transfer = ctx.accounts.pool_creator_transfer.load_init()?;
+    pool_creator_transfer.initialize(
+        ctx.accounts.pool.key(),
+        ctx.accounts.creator.key(),
+        new_creator,
+    );
+
+    emit_cpi!(EvtTransferPoolCreator {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        new_creator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Pool, EvtUpdatePoolPartner};
+
+/// Move the partner fee share of a pool to a new address. Only when the pool creator is the partner,
+/// the partner fee percent set by the config is unchanged
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoolPartnerCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handle_update_pool_partner(
+    ctx: Context<UpdatePoolPartnerCtx>,
+    new_partner: Pubkey,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    let previous_partner = pool.update_partner(new_partner)?;
+
+    emit_cpi!(EvtUpdatePoolPartner {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        previous_partner,
+        partner: new_partner,
+    });
+
+    Ok(())
+}
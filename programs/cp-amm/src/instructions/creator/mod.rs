@@ -0,0 +1,12 @@
+pub mod ix_transfer_pool_creator;
+pub use ix_transfer_pool_creator::*;
+pub mod ix_accept_pool_creator;
+pub use ix_accept_pool_creator::*;
+pub mod ix_cancel_pool_creator_transfer;
+pub use ix_cancel_pool_creator_transfer::*;
+pub mod ix_update_pool_partner;
+pub use ix_update_pool_partner::*;
+pub mod ix_lower_pool_base_fee;
+pub use ix_lower_pool_base_fee::*;
+pub mod ix_initialize_lbp;
+pub use ix_initialize_lbp::*;
@@ -18,12 +18,15 @@ use crate::{
     create_position_nft,
     curve::get_initialize_amounts,
     params::{activation::ActivationParams, fee_parameters::PoolFeeParameters},
-    state::{CollectFeeMode, Pool, PoolType, Position},
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, get_position_nft_pool_attributes, get_token_symbol,
+    },
+    state::{CollectFeeMode, PauseAction, Pool, PoolType, Position},
     token::{
         calculate_transfer_fee_included_amount, get_token_program_flags, is_supported_mint,
         is_token_badge_initialized, transfer_from_user,
     },
-    EvtCreatePosition, EvtInitializePool, PoolError,
+    validate_action_not_paused, EvtCreatePosition, EvtInitializePool, PoolError,
 };
 
 use super::{max_key, min_key};
@@ -222,9 +225,12 @@ pub struct InitializeCustomizablePoolCtx<'info> {
 }
 
 pub fn handle_initialize_customizable_pool<'c: 'info, 'info>(
-    ctx: Context<'_, '_, 'c, 'info, InitializeCustomizablePoolCtx<'info>>,
+    mut ctx: Context<'_, '_, 'c, 'info, InitializeCustomizablePoolCtx<'info>>,
     params: InitializeCustomizablePoolParameters,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::AddLiquidity)?;
+
     params.validate()?;
     if !is_supported_mint(&ctx.accounts.token_a_mint)? {
         require!(
@@ -311,8 +317,22 @@ pub fn handle_initialize_customizable_pool<'c: 'info, 'info>(
         ctx.accounts.position_nft_mint.key(),
         liquidity,
     );
+    position.cost_basis.accumulate_deposit(
+        token_a_amount,
+        token_b_amount,
+        sqrt_price,
+        Clock::get()?.unix_timestamp as u64,
+    )?;
 
     // create position nft
+    let mut attributes = get_position_nft_pool_attributes(
+        ctx.accounts.pool.key(),
+        &pool,
+        get_token_symbol(&ctx.accounts.token_a_mint.to_account_info(), &[])?,
+        get_token_symbol(&ctx.accounts.token_b_mint.to_account_info(), &[])?,
+    );
+    attributes.extend(get_position_nft_liquidity_attributes(&position));
+
     drop(position);
     create_position_nft(
         ctx.accounts.payer.to_account_info(),
@@ -321,6 +341,7 @@ pub fn handle_initialize_customizable_pool<'c: 'info, 'info>(
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_2022_program.to_account_info(),
         ctx.accounts.position_nft_account.to_account_info(),
+        &attributes,
     )?;
 
     emit_cpi!(EvtCreatePosition {
@@ -17,12 +17,15 @@ use crate::{
     create_position_nft,
     curve::get_initialize_amounts,
     params::activation::ActivationParams,
-    state::{Config, ConfigType, Pool, PoolType, Position},
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, get_position_nft_pool_attributes, get_token_symbol,
+    },
+    state::{Config, ConfigType, PauseAction, Pool, PoolType, Position},
     token::{
         calculate_transfer_fee_included_amount, get_token_program_flags, is_supported_mint,
         is_token_badge_initialized, transfer_from_user,
     },
-    EvtCreatePosition, EvtInitializePool, PoolError,
+    validate_action_not_paused, EvtCreatePosition, EvtInitializePool, PoolError,
 };
 
 // To fix IDL generation: https://github.com/coral-xyz/anchor/issues/3209
@@ -183,9 +186,12 @@ pub struct InitializePoolCtx<'info> {
 }
 
 pub fn handle_initialize_pool<'c: 'info, 'info>(
-    ctx: Context<'_, '_, 'c, 'info, InitializePoolCtx<'info>>,
+    mut ctx: Context<'_, '_, 'c, 'info, InitializePoolCtx<'info>>,
     params: InitializePoolParameters,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::AddLiquidity)?;
+
     if !is_supported_mint(&ctx.accounts.token_a_mint)? {
         require!(
             is_token_badge_initialized(
@@ -265,7 +271,7 @@ pub fn handle_initialize_pool<'c: 'info, 'info>(
     let token_b_flag: u8 = get_token_program_flags(&ctx.accounts.token_b_mint).into();
     let pool_type: u8 = PoolType::Permissionless.into();
 
-    let alpha_vault = config.get_whitelisted_alpha_vault(ctx.accounts.pool.key());
+    let alpha_vault = config.get_whitelisted_alpha_vault(ctx.accounts.pool.key())?;
     pool.initialize(
         ctx.accounts.creator.key(),
         config.pool_fees.to_pool_fees_struct(sqrt_price),
@@ -296,8 +302,22 @@ pub fn handle_initialize_pool<'c: 'info, 'info>(
         ctx.accounts.position_nft_mint.key(),
         liquidity,
     );
+    position.cost_basis.accumulate_deposit(
+        token_a_amount,
+        token_b_amount,
+        sqrt_price,
+        Clock::get()?.unix_timestamp as u64,
+    )?;
 
     // create position nft
+    let mut attributes = get_position_nft_pool_attributes(
+        ctx.accounts.pool.key(),
+        &pool,
+        get_token_symbol(&ctx.accounts.token_a_mint.to_account_info(), &[])?,
+        get_token_symbol(&ctx.accounts.token_b_mint.to_account_info(), &[])?,
+    );
+    attributes.extend(get_position_nft_liquidity_attributes(&position));
+
     drop(position);
     create_position_nft(
         ctx.accounts.payer.to_account_info(),
@@ -306,6 +326,7 @@ pub fn handle_initialize_pool<'c: 'info, 'info>(
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_2022_program.to_account_info(),
         ctx.accounts.position_nft_account.to_account_info(),
+        &attributes,
     )?;
 
     emit_cpi!(EvtCreatePosition {
@@ -12,13 +12,16 @@ use crate::{
     },
     create_position_nft,
     curve::get_initialize_amounts,
-    get_whitelisted_alpha_vault,
-    state::{Config, ConfigType, Pool, PoolType, Position},
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, get_position_nft_pool_attributes, get_token_symbol,
+    },
+    state::{Config, ConfigType, PauseAction, Pool, PoolType, Position},
     token::{
         calculate_transfer_fee_included_amount, get_token_program_flags, is_supported_mint,
         is_token_badge_initialized, transfer_from_user,
     },
-    EvtCreatePosition, EvtInitializePool, InitializeCustomizablePoolParameters, PoolError,
+    validate_action_not_paused, EvtCreatePosition, EvtInitializePool,
+    InitializeCustomizablePoolParameters, PoolError,
 };
 
 use super::{max_key, min_key};
@@ -165,9 +168,12 @@ pub struct InitializePoolWithDynamicConfigCtx<'info> {
 }
 
 pub fn handle_initialize_pool_with_dynamic_config<'c: 'info, 'info>(
-    ctx: Context<'_, '_, 'c, 'info, InitializePoolWithDynamicConfigCtx<'info>>,
+    mut ctx: Context<'_, '_, 'c, 'info, InitializePoolWithDynamicConfigCtx<'info>>,
     params: InitializeCustomizablePoolParameters,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::AddLiquidity)?;
+
     params.validate()?;
     if !is_supported_mint(&ctx.accounts.token_a_mint)? {
         require!(
@@ -227,11 +233,11 @@ pub fn handle_initialize_pool_with_dynamic_config<'c: 'info, 'info>(
     let token_b_flag: u8 = get_token_program_flags(&ctx.accounts.token_b_mint).into();
     let activation_point =
         activation_point.unwrap_or(ActivationHandler::get_current_point(activation_type)?);
-    let alpha_vault = get_whitelisted_alpha_vault(
-        ctx.accounts.payer.key(),
-        ctx.accounts.pool.key(),
-        has_alpha_vault,
-    );
+    let alpha_vault = if has_alpha_vault {
+        config.derive_pre_activation_buyer(ctx.accounts.payer.key(), ctx.accounts.pool.key())?
+    } else {
+        Pubkey::default()
+    };
     let pool_type: u8 = PoolType::Customizable.into();
 
     pool.initialize(
@@ -262,8 +268,22 @@ pub fn handle_initialize_pool_with_dynamic_config<'c: 'info, 'info>(
         ctx.accounts.position_nft_mint.key(),
         liquidity,
     );
+    position.cost_basis.accumulate_deposit(
+        token_a_amount,
+        token_b_amount,
+        sqrt_price,
+        Clock::get()?.unix_timestamp as u64,
+    )?;
 
     // create position nft
+    let mut attributes = get_position_nft_pool_attributes(
+        ctx.accounts.pool.key(),
+        &pool,
+        get_token_symbol(&ctx.accounts.token_a_mint.to_account_info(), &[])?,
+        get_token_symbol(&ctx.accounts.token_b_mint.to_account_info(), &[])?,
+    );
+    attributes.extend(get_position_nft_liquidity_attributes(&position));
+
     drop(position);
     create_position_nft(
         ctx.accounts.payer.to_account_info(),
@@ -272,6 +292,7 @@ pub fn handle_initialize_pool_with_dynamic_config<'c: 'info, 'info>(
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_2022_program.to_account_info(),
         ctx.accounts.position_nft_account.to_account_info(),
+        &attributes,
     )?;
 
     emit_cpi!(EvtCreatePosition {
@@ -2,11 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    get_pool_access_validator,
-    state::{ModifyLiquidityResult, Pool, Position},
+    activation_handler::ActivationHandler,
+    allowlist::load_allowlist_entry_if_permissioned,
+    get_pool_access_validator, refresh_position_nft_metadata_if_provided,
+    reward_extension::update_extended_rewards_if_enabled,
+    state::{ModifyLiquidityResult, PauseAction, Pool, Position},
     token::{calculate_transfer_fee_included_amount, transfer_from_user},
     u128x128_math::Rounding,
-    EvtLiquidityChange, PoolError,
+    validate_action_not_paused, EvtLiquidityChange, PoolError,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
@@ -71,10 +74,13 @@ pub struct AddLiquidityCtx<'info> {
     pub token_b_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_add_liquidity(
-    ctx: Context<AddLiquidityCtx>,
+pub fn handle_add_liquidity<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, AddLiquidityCtx<'info>>,
     params: AddLiquidityParameters,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::AddLiquidity)?;
+
     let AddLiquidityParameters {
         liquidity_delta,
         token_a_amount_threshold,
@@ -82,11 +88,18 @@ pub fn handle_add_liquidity(
     } = params;
     require!(params.liquidity_delta > 0, PoolError::InvalidParameters);
 
+    // permissioned pools: allowlist entry of the owner goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let allowlist_entry = load_allowlist_entry_if_permissioned(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+            &pool,
+        )?;
+        let access_validator = get_pool_access_validator(&pool, allowlist_entry.as_ref())?;
         require!(
-            access_validator.can_add_liquidity(),
+            access_validator.can_add_liquidity(&ctx.accounts.owner.key()),
             PoolError::PoolDisabled
         );
     }
@@ -95,9 +108,14 @@ pub fn handle_add_liquidity(
 
     let mut position = ctx.accounts.position.load_mut()?;
 
+    // move sqrt max price down the liquidity bootstrapping schedule before liquidity change
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    pool.update_lbp_price_bound(current_point)?;
+
     // update current pool reward & postion reward before any logic
-    let current_time = Clock::get()?.unix_timestamp as u64;
-    position.update_rewards(&mut pool, current_time)?;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time, clock.slot)?;
 
     let ModifyLiquidityResult {
         token_a_amount,
@@ -109,7 +127,25 @@ pub fn handle_add_liquidity(
         PoolError::AmountIsZero
     );
 
+    // update extended rewards before liquidity change
+    update_extended_rewards_if_enabled(
+        &mut remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &*position)],
+        current_time,
+        clock.slot,
+    )?;
+
     pool.apply_add_liquidity(&mut position, liquidity_delta)?;
+    position.cost_basis.accumulate_deposit(
+        token_a_amount,
+        token_b_amount,
+        pool.sqrt_price,
+        current_time,
+    )?;
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
 
     let total_amount_a = calculate_transfer_fee_included_amount(
         &ctx.accounts
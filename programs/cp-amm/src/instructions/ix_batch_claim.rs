@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    constants::{NUM_REWARDS, REWARD_INDEX_0, REWARD_INDEX_1},
+    safe_math::SafeMath,
+    state::{PauseAction, Pool, Position},
+    token::transfer_from_pool,
+    validate_action_not_paused, EvtBatchClaim, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchClaimCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// The user token a account
+    #[account(mut)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token b account
+    #[account(mut)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for input token
+    #[account(mut, token::token_program = token_a_program, token::mint = token_a_mint)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    #[account(mut, token::token_program = token_b_program, token::mint = token_b_mint)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Reward 0 accounts, reward 0 is claimed only if all of them are provided and the reward vault is not frozen
+    #[account(mut)]
+    pub reward_0_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub reward_0_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub reward_0_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub reward_0_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Reward 1 accounts, reward 1 is claimed only if all of them are provided and the reward vault is not frozen
+    #[account(mut)]
+    pub reward_1_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub reward_1_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub reward_1_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub reward_1_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// owner of all positions
+    pub owner: Signer<'info>,
+
+    /// Token a program
+    pub token_a_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+struct RewardAccounts<'a, 'info> {
+    vault: &'a InterfaceAccount<'info, TokenAccount>,
+    mint: &'a InterfaceAccount<'info, Mint>,
+    token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'info> BatchClaimCtx<'info> {
+    fn get_reward_accounts(
+        &self,
+        reward_index: usize,
+        pool: &Pool,
+    ) -> Result<Option<RewardAccounts<'_, 'info>>> {
+        let (vault, mint, token_account, token_program) = match reward_index {
+            REWARD_INDEX_0 => (
+                &self.reward_0_vault,
+                &self.reward_0_mint,
+                &self.reward_0_token_account,
+                &self.reward_0_token_program,
+            ),
+            REWARD_INDEX_1 => (
+                &self.reward_1_vault,
+                &self.reward_1_mint,
+                &self.reward_1_token_account,
+                &self.reward_1_token_program,
+            ),
+            _ => return Err(PoolError::InvalidRewardIndex.into()),
+        };
+
+        match (vault, mint, token_account, token_program) {
+            (Some(vault), Some(mint), Some(token_account), Some(token_program)) => {
+                let reward_info = &pool.reward_infos[reward_index];
+                require!(reward_info.initialized(), PoolError::RewardUninitialized);
+                require!(
+                    reward_info.vault.eq(&vault.key()) && reward_info.mint.eq(&mint.key()),
+                    PoolError::InvalidRewardVault
+                );
+
+                // unlike claim_reward, reward of a frozen vault is kept pending in positions
+                // so that fees and other rewards can still be claimed
+                if vault.is_frozen() {
+                    return Ok(None);
+                }
+
+                Ok(Some(RewardAccounts {
+                    vault,
+                    mint,
+                    token_account,
+                    token_program,
+                }))
+            }
+            (None, None, None, None) => Ok(None),
+            _ => Err(PoolError::InvalidBatchClaimAccounts.into()),
+        }
+    }
+}
+
+/// Claim fees and rewards of many positions of the same pool owned by owner.
+/// remaining accounts: position and its position nft account, for each position
+pub fn handle_batch_claim<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, BatchClaimCtx<'info>>,
+) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % 2 == 0,
+        PoolError::InvalidBatchClaimAccounts
+    );
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let clock = Clock::get()?;
+    pool.update_rewards(clock.unix_timestamp as u64, clock.slot)?;
+
+    let mut claim_rewards = [false; NUM_REWARDS];
+    for (reward_index, claim_reward) in claim_rewards.iter_mut().enumerate() {
+        *claim_reward = ctx
+            .accounts
+            .get_reward_accounts(reward_index, &pool)?
+            .is_some();
+    }
+
+    let mut total_fee_a_claimed: u64 = 0;
+    let mut total_fee_b_claimed: u64 = 0;
+    let mut total_rewards_claimed = [0u64; NUM_REWARDS];
+
+    for accounts in remaining_accounts.chunks(2) {
+        let position_loader: AccountLoader<'info, Position> =
+            AccountLoader::try_from(&accounts[0])?;
+        let position_nft_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[1])?;
+
+        let mut position = position_loader.load_mut()?;
+        require!(
+            position.pool.eq(&ctx.accounts.pool.key()),
+            PoolError::InvalidBatchClaimAccounts
+        );
+        require!(
+            position_nft_account.mint.eq(&position.nft_mint)
+                && position_nft_account.amount == 1
+                && position_nft_account.owner.eq(&ctx.accounts.owner.key()),
+            PoolError::InvalidBatchClaimAccounts
+        );
+
+        // claim fee
+        position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+        let fee_a_pending = position.fee_a_pending;
+        let fee_b_pending = position.fee_b_pending;
+        position
+            .metrics
+            .accumulate_claimed_fee(fee_a_pending, fee_b_pending)?;
+        position.reset_pending_fee();
+
+        total_fee_a_claimed = total_fee_a_claimed.safe_add(fee_a_pending)?;
+        total_fee_b_claimed = total_fee_b_claimed.safe_add(fee_b_pending)?;
+
+        // claim rewards, rewards without accounts are kept pending in the position
+        position.update_position_reward(&pool)?;
+        for (reward_index, claim_reward) in claim_rewards.iter().enumerate() {
+            if *claim_reward {
+                let reward = position.claim_reward(reward_index)?;
+                total_rewards_claimed[reward_index] =
+                    total_rewards_claimed[reward_index].safe_add(reward)?;
+            }
+        }
+    }
+
+    if total_fee_a_claimed > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_a_vault,
+            &ctx.accounts.token_a_account.to_account_info(),
+            &ctx.accounts.token_a_program,
+            total_fee_a_claimed,
+        )?;
+    }
+
+    if total_fee_b_claimed > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_b_vault,
+            &ctx.accounts.token_b_account.to_account_info(),
+            &ctx.accounts.token_b_program,
+            total_fee_b_claimed,
+        )?;
+    }
+
+    for (reward_index, total_reward) in total_rewards_claimed.iter().enumerate() {
+        if *total_reward == 0 {
+            continue;
+        }
+        if let Some(reward_accounts) = ctx.accounts.get_reward_accounts(reward_index, &pool)? {
+            transfer_from_pool(
+                ctx.accounts.pool_authority.to_account_info(),
+                reward_accounts.mint,
+                reward_accounts.vault,
+                &reward_accounts.token_account.to_account_info(),
+                reward_accounts.token_program,
+                *total_reward,
+            )?;
+        }
+    }
+
+    emit_cpi!(EvtBatchClaim {
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        number_of_positions: (remaining_accounts.len() / 2) as u16,
+        fee_a_claimed: total_fee_a_claimed,
+        fee_b_claimed: total_fee_b_claimed,
+        rewards_claimed: total_rewards_claimed,
+    });
+
+    Ok(())
+}
@@ -3,9 +3,9 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     const_pda,
-    state::{Pool, Position},
+    state::{PauseAction, Pool, Position},
     token::transfer_from_pool,
-    EvtClaimPositionFee,
+    validate_action_not_paused, EvtClaimPositionFee,
 };
 
 #[event_cpi]
@@ -71,6 +71,8 @@ pub struct ClaimPositionFeeCtx<'info> {
 }
 
 pub fn handle_claim_position_fee(ctx: Context<ClaimPositionFeeCtx>) -> Result<()> {
+    validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let mut position = ctx.accounts.position.load_mut()?;
 
     let pool = ctx.accounts.pool.load()?;
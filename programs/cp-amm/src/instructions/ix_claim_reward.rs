@@ -3,11 +3,13 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     const_pda,
-    constants::NUM_REWARDS,
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
     error::PoolError,
     event::EvtClaimReward,
-    state::{pool::Pool, position::Position},
+    reward_extension::{load_position_reward_extension, load_reward_extension},
+    state::{is_extended_reward_index, pool::Pool, position::Position, PauseAction, RewardInfo},
     token::transfer_from_pool,
+    validate_action_not_paused,
 };
 
 #[event_cpi]
@@ -52,10 +54,15 @@ pub struct ClaimRewardCtx<'info> {
 
 impl<'info> ClaimRewardCtx<'info> {
     fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
+
+        Ok(())
+    }
 
-        let reward_info = &pool.reward_infos[reward_index];
+    fn validate_reward_info(&self, reward_info: &RewardInfo) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
         require!(
             reward_info.vault.eq(&self.reward_vault.key()),
@@ -66,11 +73,14 @@ impl<'info> ClaimRewardCtx<'info> {
     }
 }
 
-pub fn handle_claim_reward(
-    ctx: Context<ClaimRewardCtx>,
+pub fn handle_claim_reward<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, ClaimRewardCtx<'info>>,
     reward_index: u8,
     skip_reward: u8,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
@@ -79,13 +89,35 @@ pub fn handle_claim_reward(
     let mut position = ctx.accounts.position.load_mut()?;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
-    let current_time = Clock::get()?.unix_timestamp as u64;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
 
     // update pool reward & position reward
-    position.update_rewards(&mut pool, current_time)?;
+    position.update_rewards(&mut pool, current_time, clock.slot)?;
 
     // get all pending reward
-    let total_reward = position.claim_reward(index)?;
+    let total_reward = if is_extended_reward_index(index) {
+        // remaining accounts: pool reward extension, then position reward extension
+        let mut remaining_accounts = &ctx.remaining_accounts[..];
+        let reward_extension_loader =
+            load_reward_extension(&mut remaining_accounts, ctx.accounts.pool.key())?;
+        let position_reward_extension_loader =
+            load_position_reward_extension(&mut remaining_accounts, ctx.accounts.position.key())?;
+
+        let mut reward_extension = reward_extension_loader.load_mut()?;
+        let mut position_reward_extension = position_reward_extension_loader.load_mut()?;
+
+        reward_extension.update_rewards(&pool, current_time, clock.slot)?;
+        position_reward_extension.update_position_reward(&position, &reward_extension)?;
+
+        ctx.accounts
+            .validate_reward_info(reward_extension.get_reward_info(index)?)?;
+        position_reward_extension.claim_reward(index)?
+    } else {
+        ctx.accounts
+            .validate_reward_info(&pool.reward_infos[index])?;
+        position.claim_reward(index)?
+    };
 
     // transfer rewards to user
     if total_reward > 0 {
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda,
+    event::EvtClaimTradingReward,
+    state::{PauseAction, Pool, TraderReward, TradingRewardCampaign},
+    token::transfer_from_pool,
+    validate_action_not_paused, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimTradingRewardCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = reward_vault, has_one = reward_mint)]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(mut, has_one = campaign, has_one = trader)]
+    pub trader_reward: AccountLoader<'info, TraderReward>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub trader: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_claim_trading_reward(ctx: Context<ClaimTradingRewardCtx>) -> Result<()> {
+    validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
+    let pool = ctx.accounts.pool.load()?;
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+    let mut trader_reward = ctx.accounts.trader_reward.load_mut()?;
+
+    // reward is shared from total points at campaign end
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    require!(
+        campaign.is_ended(current_point),
+        PoolError::TradingRewardCampaignNotEnded
+    );
+
+    let total_reward = campaign.claim_reward(&mut trader_reward)?;
+
+    if total_reward > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.reward_mint,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.user_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            total_reward,
+        )?;
+    }
+
+    emit_cpi!(EvtClaimTradingReward {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        trader: ctx.accounts.trader.key(),
+        points: trader_reward.points,
+        total_reward,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event::EvtCloseAllowlistEntry,
+    safe_math::SafeMath,
+    state::{AllowlistEntry, Pool, PoolAllowlist},
+};
+
+/// Remove a wallet from the allowlist of a permissioned pool. Liquidity of the wallet can still be removed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAllowlistEntryCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = authority)]
+    pub pool_allowlist: AccountLoader<'info, PoolAllowlist>,
+
+    #[account(mut, has_one = pool, close = rent_receiver)]
+    pub allowlist_entry: AccountLoader<'info, AllowlistEntry>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Account to receive closed account rental SOL
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_allowlist_entry(ctx: Context<CloseAllowlistEntryCtx>) -> Result<()> {
+    let mut pool_allowlist = ctx.accounts.pool_allowlist.load_mut()?;
+    pool_allowlist.number_of_entries = pool_allowlist.number_of_entries.safe_sub(1)?;
+
+    let allowlist_entry = ctx.accounts.allowlist_entry.load()?;
+
+    emit_cpi!(EvtCloseAllowlistEntry {
+        pool: ctx.accounts.pool.key(),
+        wallet: allowlist_entry.wallet,
+        allowlist_entry: ctx.accounts.allowlist_entry.key(),
+    });
+
+    Ok(())
+}
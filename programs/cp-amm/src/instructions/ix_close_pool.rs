@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+use crate::{
+    const_pda,
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    reward_extension::load_reward_extension,
+    state::{Pool, PoolAllowlist, RewardFunding, RewardInfo},
+    token::{get_token_program_from_flag, transfer_from_pool},
+    EvtClosePool, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePoolCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = creator,
+        close = creator
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// The vault token account for token a
+    #[account(mut, token::token_program = token_a_program, token::mint = token_a_mint)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token b
+    #[account(mut, token::token_program = token_b_program, token::mint = token_b_mint)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Creator token a account, receives token a left in the vault
+    #[account(mut, token::authority = creator, token::mint = token_a_mint, token::token_program = token_a_program)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator token b account, receives token b left in the vault
+    #[account(mut, token::authority = creator, token::mint = token_b_mint, token::token_program = token_b_program)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool creator, receives rent of the pool and vaults
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Token a program
+    pub token_a_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClosePoolCtx<'info> {
+    /// Transfer tokens left in the vault (rounding dust or donations) to the creator, then close the vault
+    fn sweep_and_close_vault(
+        &self,
+        token_mint: &InterfaceAccount<'info, Mint>,
+        token_vault: &InterfaceAccount<'info, TokenAccount>,
+        token_account: &AccountInfo<'info>,
+        token_program: &Interface<'info, TokenInterface>,
+    ) -> Result<u64> {
+        let amount = token_vault.amount;
+        if amount > 0 {
+            transfer_from_pool(
+                self.pool_authority.to_account_info(),
+                token_mint,
+                token_vault,
+                token_account,
+                token_program,
+                amount,
+            )?;
+        }
+
+        let signer_seeds = pool_authority_seeds!();
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: token_vault.to_account_info(),
+                destination: self.creator.to_account_info(),
+                authority: self.pool_authority.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ))?;
+
+        Ok(amount)
+    }
+
+    /// Remaining accounts of a reward: reward vault, reward mint, funder reward token account and reward token program,
+    /// followed by reward funding in permissionless funding mode. Reward left in the vault is returned to the reward funder
+    fn close_reward_vault<'c: 'info>(
+        &self,
+        reward_index: usize,
+        reward_info: &RewardInfo,
+        remaining_accounts: &mut &'c [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(remaining_accounts.len() >= 4, PoolError::InvalidRewardVault);
+        let (accounts, rest) = remaining_accounts.split_at(4);
+        *remaining_accounts = rest;
+
+        let reward_vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[0])?;
+        let reward_mint: InterfaceAccount<'info, Mint> = InterfaceAccount::try_from(&accounts[1])?;
+        let token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[2])?;
+        let token_program: Interface<'info, TokenInterface> = Interface::try_from(&accounts[3])?;
+
+        require!(
+            reward_vault.key() == reward_info.vault
+                && reward_mint.key() == reward_info.mint
+                && token_program.key()
+                    == get_token_program_from_flag(reward_info.reward_token_flag)?,
+            PoolError::InvalidRewardVault
+        );
+        require!(
+            token_account.owner == reward_info.funder && token_account.mint == reward_info.mint,
+            PoolError::InvalidRewardVault
+        );
+
+        // funders keep a share of ineligible reward until their ledger is closed
+        if reward_info.is_permissionless_funding() {
+            let (account, rest) = remaining_accounts
+                .split_first()
+                .ok_or(PoolError::InvalidRewardFundingAccount)?;
+            *remaining_accounts = rest;
+
+            let reward_funding_loader: AccountLoader<'info, RewardFunding> =
+                AccountLoader::try_from(account)?;
+            {
+                let reward_funding = reward_funding_loader.load()?;
+                require!(
+                    reward_funding.pool == self.pool.key()
+                        && usize::from(reward_funding.reward_index) == reward_index,
+                    PoolError::InvalidRewardFundingAccount
+                );
+                require!(
+                    reward_funding.number_of_funder_ledgers == 0,
+                    PoolError::RewardFunderLedgerNotClosed
+                );
+            }
+            reward_funding_loader.close(self.creator.to_account_info())?;
+        }
+
+        self.sweep_and_close_vault(
+            &reward_mint,
+            &reward_vault,
+            &token_account.to_account_info(),
+            &token_program,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Close an empty pool, its vaults and reward vaults, and return rent to the pool creator.
+/// Pool must have no liquidity and no position, protocol and partner fees claimed, all rewards ended
+/// with ineligible reward withdrawn and funder ledgers closed, all trading reward campaigns and allowlist entries
+/// closed, and no pending pool creator transfer.
+/// Remaining accounts: pool reward extension if any, pool allowlist if the pool is permissioned, then accounts of
+/// each initialized reward in reward index order
+pub fn handle_close_pool<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClosePoolCtx<'info>>,
+) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    let (reward_extension_loader, pool_allowlist_loader) = {
+        let pool = ctx.accounts.pool.load()?;
+        let reward_extension_loader = if pool.has_reward_extension() {
+            Some(load_reward_extension(
+                &mut remaining_accounts,
+                ctx.accounts.pool.key(),
+            )?)
+        } else {
+            None
+        };
+        let pool_allowlist_loader = if pool.is_permissioned() {
+            Some(load_empty_pool_allowlist(
+                &mut remaining_accounts,
+                ctx.accounts.pool.key(),
+            )?)
+        } else {
+            None
+        };
+        (reward_extension_loader, pool_allowlist_loader)
+    };
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+
+    let mut reward_infos = Vec::with_capacity(NUM_REWARDS + NUM_EXTENDED_REWARDS);
+    {
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        require!(pool.is_empty(), PoolError::PoolIsNotEmpty);
+        require!(
+            pool.metrics.number_of_trading_reward_campaigns == 0,
+            PoolError::TradingRewardCampaignNotClosed
+        );
+        require!(
+            pool.metrics.pending_creator_transfer_flag == 0,
+            PoolError::PoolCreatorTransferPending
+        );
+
+        pool.update_rewards(current_time, clock.slot)?;
+        reward_infos.extend(pool.reward_infos);
+
+        if let Some(loader) = reward_extension_loader.as_ref() {
+            let mut reward_extension = loader.load_mut()?;
+            reward_extension.update_rewards(&pool, current_time, clock.slot)?;
+            reward_infos.extend(reward_extension.reward_infos);
+        }
+
+        let activation_type = pool.activation_type;
+        for reward_info in reward_infos.iter() {
+            let current_point =
+                reward_info.get_current_point(activation_type, current_time, clock.slot);
+            require!(
+                reward_info.is_finished(current_point),
+                PoolError::PoolRewardNotFinished
+            );
+        }
+    }
+
+    let token_a_amount = ctx.accounts.sweep_and_close_vault(
+        &ctx.accounts.token_a_mint,
+        &ctx.accounts.token_a_vault,
+        &ctx.accounts.token_a_account.to_account_info(),
+        &ctx.accounts.token_a_program,
+    )?;
+    let token_b_amount = ctx.accounts.sweep_and_close_vault(
+        &ctx.accounts.token_b_mint,
+        &ctx.accounts.token_b_vault,
+        &ctx.accounts.token_b_account.to_account_info(),
+        &ctx.accounts.token_b_program,
+    )?;
+
+    for (reward_index, reward_info) in reward_infos
+        .iter()
+        .enumerate()
+        .filter(|(_, reward_info)| reward_info.initialized())
+    {
+        ctx.accounts
+            .close_reward_vault(reward_index, reward_info, &mut remaining_accounts)?;
+    }
+
+    if let Some(loader) = reward_extension_loader {
+        loader.close(ctx.accounts.creator.to_account_info())?;
+    }
+
+    if let Some(loader) = pool_allowlist_loader {
+        loader.close(ctx.accounts.creator.to_account_info())?;
+    }
+
+    emit_cpi!(EvtClosePool {
+        pool: ctx.accounts.pool.key(),
+        creator: ctx.accounts.creator.key(),
+        token_a_amount,
+        token_b_amount,
+    });
+
+    Ok(())
+}
+
+/// Pool allowlist goes after the pool reward extension in remaining accounts, all allowlist entries must be closed
+fn load_empty_pool_allowlist<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    pool: Pubkey,
+) -> Result<AccountLoader<'info, PoolAllowlist>> {
+    let (account, rest) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::InvalidPoolAllowlist)?;
+    *remaining_accounts = rest;
+
+    let pool_allowlist_loader: AccountLoader<'info, PoolAllowlist> =
+        AccountLoader::try_from(account)?;
+    {
+        let pool_allowlist = pool_allowlist_loader.load()?;
+        require!(pool_allowlist.pool == pool, PoolError::InvalidPoolAllowlist);
+        require!(
+            pool_allowlist.number_of_entries == 0,
+            PoolError::AllowlistEntryNotClosed
+        );
+    }
+
+    Ok(pool_allowlist_loader)
+}
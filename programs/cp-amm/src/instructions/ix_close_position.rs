@@ -6,6 +6,7 @@ use anchor_spl::{
 
 use crate::{
     const_pda,
+    reward_extension::{load_position_reward_extension_if_initialized, load_reward_extension},
     state::{Pool, Position},
     EvtClosePosition, PoolError,
 };
@@ -51,13 +52,44 @@ pub struct ClosePositionCtx<'info> {
     pub token_program: Program<'info, Token2022>,
 }
 
-pub fn handle_close_position(ctx: Context<ClosePositionCtx>) -> Result<()> {
+/// Once the pool has a reward extension, remaining accounts must be the pool reward extension
+/// followed by the position reward extension address. The position reward extension is closed together with the position if created
+pub fn handle_close_position<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClosePositionCtx<'info>>,
+) -> Result<()> {
     let position = ctx.accounts.position.load()?;
     require!(position.is_empty()?, PoolError::PositionIsNotEmpty);
 
     let mut pool = ctx.accounts.pool.load_mut()?;
     pool.metrics.reduce_position();
 
+    if pool.has_reward_extension() {
+        let mut remaining_accounts = ctx.remaining_accounts;
+        let reward_extension_loader =
+            load_reward_extension(&mut remaining_accounts, ctx.accounts.pool.key())?;
+        let position_reward_extension_loader = load_position_reward_extension_if_initialized(
+            &mut remaining_accounts,
+            ctx.accounts.position.key(),
+        )?;
+
+        if let Some(position_reward_extension_loader) = position_reward_extension_loader {
+            {
+                let clock = Clock::get()?;
+                let mut reward_extension = reward_extension_loader.load_mut()?;
+                reward_extension.update_rewards(&pool, clock.unix_timestamp as u64, clock.slot)?;
+
+                let mut position_reward_extension = position_reward_extension_loader.load_mut()?;
+                position_reward_extension.update_position_reward(&position, &reward_extension)?;
+                require!(
+                    position_reward_extension.is_empty(),
+                    PoolError::PositionIsNotEmpty
+                );
+            }
+
+            position_reward_extension_loader.close(ctx.accounts.rent_receiver.to_account_info())?;
+        }
+    }
+
     // burn
     token_2022::burn(
         CpiContext::new(
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event::EvtCloseRewardFunderLedger,
+    state::{pool::Pool, RewardFunderLedger, RewardFunding},
+    PoolError,
+};
+
+/// Close the ledger of a funder in permissionless funding mode. Reward must be ended and its ineligible reward
+/// distributed to funders, then share of the ledger withdrawn before
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseRewardFunderLedgerCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_funding: AccountLoader<'info, RewardFunding>,
+
+    #[account(mut, has_one = reward_funding, has_one = funder, close = rent_receiver)]
+    pub funder_ledger: AccountLoader<'info, RewardFunderLedger>,
+
+    pub funder: Signer<'info>,
+
+    /// CHECK: Account to receive closed account rental SOL
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_reward_funder_ledger(ctx: Context<CloseRewardFunderLedgerCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut reward_funding = ctx.accounts.reward_funding.load_mut()?;
+    let mut funder_ledger = ctx.accounts.funder_ledger.load_mut()?;
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    pool.update_rewards(current_time, clock.slot)?;
+
+    let activation_type = pool.activation_type;
+    let reward_info = &pool.reward_infos[usize::from(reward_funding.reward_index)];
+    let current_point = reward_info.get_current_point(activation_type, current_time, clock.slot);
+    require!(
+        current_point > reward_info.reward_duration_end,
+        PoolError::RewardNotEnded
+    );
+    // ineligible reward of the pool must be distributed to funders by withdraw_ineligible_reward
+    require!(
+        reward_info.is_finished(current_point),
+        PoolError::MustWithdrawnIneligibleReward
+    );
+
+    funder_ledger.close(&mut reward_funding)?;
+
+    emit_cpi!(EvtCloseRewardFunderLedger {
+        pool: reward_funding.pool,
+        reward_index: reward_funding.reward_index,
+        funder: ctx.accounts.funder.key(),
+        funder_ledger: ctx.accounts.funder_ledger.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    event::EvtCloseTraderReward,
+    state::{Pool, TraderReward, TradingRewardCampaign},
+    PoolError,
+};
+
+/// Close the point account of a trader after the campaign ends and the trader claimed its reward
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseTraderRewardCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(mut, has_one = campaign, has_one = trader, close = rent_receiver)]
+    pub trader_reward: AccountLoader<'info, TraderReward>,
+
+    pub trader: Signer<'info>,
+
+    /// CHECK: Account to receive closed account rental SOL
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_trader_reward(ctx: Context<CloseTraderRewardCtx>) -> Result<()> {
+    let pool = ctx.accounts.pool.load()?;
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+    let trader_reward = ctx.accounts.trader_reward.load()?;
+
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    require!(
+        campaign.is_ended(current_point),
+        PoolError::TradingRewardCampaignNotEnded
+    );
+
+    campaign.close_trader_reward(&trader_reward)?;
+
+    emit_cpi!(EvtCloseTraderReward {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        trader: ctx.accounts.trader.key(),
+        trader_reward: ctx.accounts.trader_reward.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::ALLOWLIST_ENTRY_PREFIX,
+    event::EvtCreateAllowlistEntry,
+    safe_math::SafeMath,
+    state::{AllowlistEntry, Pool, PoolAllowlist, MAX_ALLOWLIST_PERMISSION},
+    PoolError,
+};
+
+/// Allow a wallet to swap or provide liquidity in a permissioned pool
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateAllowlistEntryCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = authority)]
+    pub pool_allowlist: AccountLoader<'info, PoolAllowlist>,
+
+    #[account(
+        init,
+        seeds = [ALLOWLIST_ENTRY_PREFIX.as_ref(), pool.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + AllowlistEntry::INIT_SPACE
+    )]
+    pub allowlist_entry: AccountLoader<'info, AllowlistEntry>,
+
+    /// CHECK: allowlisted wallet
+    pub wallet: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_allowlist_entry(
+    ctx: Context<CreateAllowlistEntryCtx>,
+    permission: u8,
+) -> Result<()> {
+    require!(
+        permission > 0 && permission <= MAX_ALLOWLIST_PERMISSION,
+        PoolError::InvalidAllowlistPermission
+    );
+
+    let mut pool_allowlist = ctx.accounts.pool_allowlist.load_mut()?;
+    pool_allowlist.number_of_entries = pool_allowlist.number_of_entries.safe_add(1)?;
+
+    let mut allowlist_entry = ctx.accounts.allowlist_entry.load_init()?;
+    allowlist_entry.initialize(
+        ctx.accounts.pool.key(),
+        ctx.accounts.wallet.key(),
+        permission,
+    );
+
+    emit_cpi!(EvtCreateAllowlistEntry {
+        pool: ctx.accounts.pool.key(),
+        wallet: ctx.accounts.wallet.key(),
+        allowlist_entry: ctx.accounts.allowlist_entry.key(),
+        permission,
+    });
+
+    Ok(())
+}
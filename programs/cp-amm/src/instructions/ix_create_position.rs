@@ -5,9 +5,17 @@ use anchor_spl::{
 };
 
 use crate::{
+    allowlist::load_allowlist_entry_if_permissioned,
     const_pda,
-    constants::seeds::{POSITION_NFT_ACCOUNT_PREFIX, POSITION_PREFIX},
+    constants::{
+        position_nft_metadata::RESERVED_SPACE,
+        seeds::{POSITION_NFT_ACCOUNT_PREFIX, POSITION_PREFIX},
+    },
     get_pool_access_validator,
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, get_position_nft_pool_attributes,
+        update_position_nft_attributes,
+    },
     state::{Pool, Position},
     token::update_account_lamports_to_minimum_balance,
     EvtCreatePosition, PoolError,
@@ -75,12 +83,20 @@ pub struct CreatePositionCtx<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
+pub fn handle_create_position<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CreatePositionCtx<'info>>,
+) -> Result<()> {
     {
+        // permissioned pools: allowlist entry of the owner goes first in remaining accounts
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let allowlist_entry = load_allowlist_entry_if_permissioned(
+            &mut &ctx.remaining_accounts[..],
+            ctx.accounts.pool.key(),
+            &pool,
+        )?;
+        let access_validator = get_pool_access_validator(&pool, allowlist_entry.as_ref())?;
         require!(
-            access_validator.can_create_position(),
+            access_validator.can_create_position(&ctx.accounts.owner.key()),
             PoolError::PoolDisabled
         );
     }
@@ -98,6 +114,15 @@ pub fn handle_create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
         liquidity,
     );
 
+    // token symbols are filled later by refresh_position_nft_metadata, as token mints are not part of this instruction
+    let mut attributes = get_position_nft_pool_attributes(
+        ctx.accounts.pool.key(),
+        &pool,
+        String::new(),
+        String::new(),
+    );
+    attributes.extend(get_position_nft_liquidity_attributes(&position));
+
     drop(position);
     create_position_nft(
         ctx.accounts.payer.to_account_info(),
@@ -106,6 +131,7 @@ pub fn handle_create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         ctx.accounts.position_nft_account.to_account_info(),
+        &attributes,
     )?;
 
     emit_cpi!(EvtCreatePosition {
@@ -125,6 +151,7 @@ pub fn create_position_nft<'info>(
     system_program: AccountInfo<'info>,
     token_program: AccountInfo<'info>,
     position_nft_account: AccountInfo<'info>,
+    attributes: &[(&str, String)],
 ) -> Result<()> {
     // init token metadata
     let seeds = pool_authority_seeds!();
@@ -145,11 +172,21 @@ pub fn create_position_nft<'info>(
         String::from("https://raw.githubusercontent.com/MeteoraAg/token-metadata/main/meteora_position_nft.png"), 
     )?;
 
-    // transfer minimum rent to mint account
+    // pool, token pair and liquidity attributes
+    update_position_nft_attributes(
+        &position_nft_mint,
+        &pool_authority,
+        &token_program,
+        attributes,
+        true,
+    )?;
+
+    // transfer minimum rent to mint account, with reserved space for later attribute refreshes
     update_account_lamports_to_minimum_balance(
         position_nft_mint.clone(),
         payer.clone(),
         system_program.clone(),
+        RESERVED_SPACE,
     )?;
 
     // Mint the NFT
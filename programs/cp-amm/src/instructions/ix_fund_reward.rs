@@ -2,12 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    constants::{NUM_REWARDS, REWARD_RATE_SCALE},
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
     event::EvtFundReward,
     math::safe_math::SafeMath,
-    state::Pool,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, Pool, RewardInfo},
     token::{calculate_transfer_fee_excluded_amount, transfer_from_user},
-    utils_math::safe_mul_shr_cast,
     PoolError,
 };
 
@@ -32,11 +32,15 @@ pub struct FundRewardCtx<'info> {
 
 impl<'info> FundRewardCtx<'info> {
     fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
 
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+        Ok(())
+    }
 
-        let reward_info = &pool.reward_infos[reward_index];
+    fn validate_reward_info(&self, reward_info: &RewardInfo) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
         require!(
             reward_info.vault.eq(&self.reward_vault.key()),
@@ -46,13 +50,18 @@ impl<'info> FundRewardCtx<'info> {
             reward_info.is_valid_funder(self.funder.key()),
             PoolError::InvalidAdmin
         );
+        // every funding must be recorded in funder ledgers, see top_up_reward
+        require!(
+            !reward_info.is_permissionless_funding(),
+            PoolError::InvalidRewardFundingMode
+        );
 
         Ok(())
     }
 }
 
-pub fn handle_fund_reward(
-    ctx: Context<FundRewardCtx>,
+pub fn handle_fund_reward<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, FundRewardCtx<'info>>,
     reward_index: u8,
     amount: u64,
     carry_forward: bool,
@@ -74,33 +83,49 @@ pub fn handle_fund_reward(
 
     require!(transfer_fee_excluded_amount_in > 0, PoolError::AmountIsZero);
 
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut &ctx.remaining_accounts[..],
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
     let mut pool = ctx.accounts.pool.load_mut()?;
-    let current_time = Clock::get()?.unix_timestamp;
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
     // 1. update pool rewards
-    pool.update_rewards(current_time as u64)?;
+    pool.update_rewards(current_time, clock.slot)?;
+    if let Some(reward_extension) = reward_extension.as_mut() {
+        reward_extension.update_rewards(&pool, current_time, clock.slot)?;
+    }
+    let activation_type = pool.activation_type;
 
     // 2. set new farming rate
-    let reward_info = &mut pool.reward_infos[index];
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts.validate_reward_info(reward_info)?;
     let pre_reward_rate = reward_info.reward_rate;
 
     let total_amount = if carry_forward {
-        let carry_forward_ineligible_reward: u64 = safe_mul_shr_cast(
-            reward_info.reward_rate,
-            reward_info
-                .cumulative_seconds_with_empty_liquidity_reward
-                .into(),
-            REWARD_RATE_SCALE,
-        )?;
-
-        // Reset cumulative seconds with empty liquidity reward
+        // Reset cumulative seconds with empty liquidity reward and cumulative ineligible reward
         // because it will be brought forward to next reward window
-        reward_info.cumulative_seconds_with_empty_liquidity_reward = 0;
+        let carry_forward_ineligible_reward = reward_info.claim_ineligible_reward()?;
 
         transfer_fee_excluded_amount_in.safe_add(carry_forward_ineligible_reward)?
     } else {
         // Because the program only keep track of cumulative seconds of rewards with empty liquidity,
         // and funding will affect the reward rate, which directly affect ineligible reward calculation.
         // ineligible_reward = reward_rate_per_seconds * cumulative_seconds_with_empty_liquidity_reward
+        // cumulative_ineligible_reward of non constant emission schedules doesn't depend on reward rate
         require!(
             reward_info.cumulative_seconds_with_empty_liquidity_reward == 0,
             PoolError::MustWithdrawnIneligibleReward
@@ -110,7 +135,8 @@ pub fn handle_fund_reward(
     };
 
     // Reward rate might include ineligible reward based on whether to brought forward
-    reward_info.update_rate_after_funding(current_time as u64, total_amount)?;
+    let current_point = reward_info.get_current_point(activation_type, current_time, clock.slot);
+    reward_info.update_rate_after_funding(current_point, total_amount)?;
 
     // Transfer without ineligible reward because it's already in the vault
     transfer_from_user(
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    event::EvtFundTradingReward,
+    state::{Pool, TradingRewardCampaign},
+    token::{calculate_transfer_fee_excluded_amount, transfer_from_user},
+    PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundTradingRewardCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = reward_vault, has_one = reward_mint, has_one = funder)]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_fund_trading_reward(ctx: Context<FundTradingRewardCtx>, amount: u64) -> Result<()> {
+    // actual amount need to transfer
+    let transfer_fee_excluded_amount_in = calculate_transfer_fee_excluded_amount(
+        &ctx.accounts
+            .reward_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        amount,
+    )?
+    .amount;
+
+    require!(transfer_fee_excluded_amount_in > 0, PoolError::AmountIsZero);
+
+    let pool = ctx.accounts.pool.load()?;
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+
+    // traders must know the reward before the campaign ends
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    require!(
+        !campaign.is_ended(current_point),
+        PoolError::TradingRewardCampaignEnded
+    );
+
+    campaign.accumulate_reward(transfer_fee_excluded_amount_in)?;
+
+    transfer_from_user(
+        &ctx.accounts.funder,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    emit_cpi!(EvtFundTradingReward {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        transfer_fee_excluded_amount_in,
+        total_reward: campaign.total_reward,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::{POSITION_REWARD_EXTENSION_PREFIX, REWARD_EXTENSION_PREFIX},
+    event::EvtInitializePositionRewardExtension,
+    state::{Pool, Position, PositionRewardExtension, RewardExtension},
+};
+
+/// Create reward extension of a position. Can be called by anyone
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePositionRewardExtensionCtx<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        seeds = [REWARD_EXTENSION_PREFIX.as_ref(), pool.key().as_ref()],
+        bump,
+    )]
+    pub reward_extension: AccountLoader<'info, RewardExtension>,
+
+    #[account(has_one = pool)]
+    pub position: AccountLoader<'info, Position>,
+
+    #[account(
+        init,
+        seeds = [POSITION_REWARD_EXTENSION_PREFIX.as_ref(), position.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + PositionRewardExtension::INIT_SPACE
+    )]
+    pub position_reward_extension: AccountLoader<'info, PositionRewardExtension>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_position_reward_extension(
+    ctx: Context<InitializePositionRewardExtensionCtx>,
+) -> Result<()> {
+    let pool = ctx.accounts.pool.load()?;
+    let mut reward_extension = ctx.accounts.reward_extension.load_mut()?;
+
+    let clock = Clock::get()?;
+    reward_extension.update_rewards(&pool, clock.unix_timestamp as u64, clock.slot)?;
+
+    let mut position_reward_extension = ctx.accounts.position_reward_extension.load_init()?;
+    position_reward_extension.initialize(ctx.accounts.position.key(), &reward_extension);
+
+    emit_cpi!(EvtInitializePositionRewardExtension {
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        position_reward_extension: ctx.accounts.position_reward_extension.key(),
+    });
+
+    Ok(())
+}
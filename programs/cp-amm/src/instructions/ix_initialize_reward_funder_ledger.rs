@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::REWARD_FUNDER_LEDGER_PREFIX,
+    event::EvtInitializeRewardFunderLedger,
+    safe_math::SafeMath,
+    state::{RewardFunderLedger, RewardFunding},
+};
+
+/// Create the ledger of a funder for a reward in permissionless funding mode. Can be called by anyone
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeRewardFunderLedgerCtx<'info> {
+    #[account(mut)]
+    pub reward_funding: AccountLoader<'info, RewardFunding>,
+
+    #[account(
+        init,
+        seeds = [REWARD_FUNDER_LEDGER_PREFIX.as_ref(), reward_funding.key().as_ref(), funder.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + RewardFunderLedger::INIT_SPACE
+    )]
+    pub funder_ledger: AccountLoader<'info, RewardFunderLedger>,
+
+    /// CHECK: funder of the ledger
+    pub funder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_reward_funder_ledger(
+    ctx: Context<InitializeRewardFunderLedgerCtx>,
+) -> Result<()> {
+    let mut reward_funding = ctx.accounts.reward_funding.load_mut()?;
+    reward_funding.number_of_funder_ledgers =
+        reward_funding.number_of_funder_ledgers.safe_add(1)?;
+
+    let mut funder_ledger = ctx.accounts.funder_ledger.load_init()?;
+    funder_ledger.initialize(
+        ctx.accounts.reward_funding.key(),
+        ctx.accounts.funder.key(),
+        &reward_funding,
+    );
+
+    emit_cpi!(EvtInitializeRewardFunderLedger {
+        pool: reward_funding.pool,
+        reward_index: reward_funding.reward_index,
+        funder: ctx.accounts.funder.key(),
+        funder_ledger: ctx.accounts.funder_ledger.key(),
+    });
+
+    Ok(())
+}
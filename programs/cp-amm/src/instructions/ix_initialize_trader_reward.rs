@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::TRADER_REWARD_PREFIX,
+    event::EvtInitializeTraderReward,
+    safe_math::SafeMath,
+    state::{TraderReward, TradingRewardCampaign},
+};
+
+/// Create the point account of a trader in a trading reward campaign. Can be called by anyone
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeTraderRewardCtx<'info> {
+    #[account(mut)]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(
+        init,
+        seeds = [TRADER_REWARD_PREFIX.as_ref(), campaign.key().as_ref(), trader.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + TraderReward::INIT_SPACE
+    )]
+    pub trader_reward: AccountLoader<'info, TraderReward>,
+
+    /// CHECK: trader of the point account
+    pub trader: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_trader_reward(ctx: Context<InitializeTraderRewardCtx>) -> Result<()> {
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+    campaign.number_of_trader_rewards = campaign.number_of_trader_rewards.safe_add(1)?;
+
+    let mut trader_reward = ctx.accounts.trader_reward.load_init()?;
+    trader_reward.initialize(ctx.accounts.campaign.key(), ctx.accounts.trader.key());
+
+    emit_cpi!(EvtInitializeTraderReward {
+        pool: campaign.pool,
+        campaign: ctx.accounts.campaign.key(),
+        trader: ctx.accounts.trader.key(),
+        trader_reward: ctx.accounts.trader_reward.key(),
+    });
+
+    Ok(())
+}
@@ -4,9 +4,10 @@ use anchor_spl::token_interface::TokenAccount;
 use crate::{
     activation_handler::ActivationHandler,
     error::PoolError,
+    reward_boost::update_boosted_rewards_if_needed,
     safe_math::SafeMath,
     state::{Pool, Position, Vesting},
-    {get_pool_access_validator, EvtLockPosition},
+    {get_pool_access_validator, refresh_position_nft_metadata_if_provided, EvtLockPosition},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
@@ -72,6 +73,7 @@ impl VestingParameters {
 #[derive(Accounts)]
 #[instruction(params: VestingParameters)]
 pub struct LockPositionCtx<'info> {
+    #[account(mut)]
     pub pool: AccountLoader<'info, Pool>,
 
     #[account(mut, has_one = pool)]
@@ -101,12 +103,54 @@ pub struct LockPositionCtx<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_lock_position(
-    ctx: Context<LockPositionCtx>,
+pub fn handle_lock_position<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LockPositionCtx<'info>>,
     params: VestingParameters,
 ) -> Result<()> {
-    let pool = ctx.accounts.pool.load()?;
-    let access_validator = get_pool_access_validator(&pool)?;
+    let mut vesting = ctx.accounts.vesting.load_init()?;
+    let mut position = ctx.accounts.position.load_mut()?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    let cliff_point = lock_position_with_vesting(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        &mut position,
+        &mut vesting,
+        &params,
+        &mut remaining_accounts,
+    )?;
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
+
+    emit_cpi!(EvtLockPosition {
+        position: ctx.accounts.position.key(),
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        vesting: ctx.accounts.vesting.key(),
+        cliff_point,
+        period_frequency: params.period_frequency,
+        cliff_unlock_liquidity: params.cliff_unlock_liquidity,
+        liquidity_per_period: params.liquidity_per_period,
+        number_of_period: params.number_of_period,
+    });
+
+    Ok(())
+}
+
+/// Validate vesting parameters, initialize vesting and move position liquidity to vested liquidity. Return cliff point
+pub fn lock_position_with_vesting<'c: 'info, 'info>(
+    pool_loader: &AccountLoader<Pool>,
+    position_key: Pubkey,
+    position: &mut Position,
+    vesting: &mut Vesting,
+    params: &VestingParameters,
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+) -> Result<u64> {
+    let is_boosted =
+        update_boosted_rewards_if_needed(pool_loader, position_key, position, remaining_accounts)?;
+
+    let pool = pool_loader.load()?;
+    let access_validator = get_pool_access_validator(&pool, None)?;
     require!(
         access_validator.can_lock_position(),
         PoolError::PoolDisabled
@@ -120,38 +164,23 @@ pub fn handle_lock_position(
     let total_lock_liquidity = params.get_total_lock_amount()?;
     let cliff_point = params.get_cliff_point(current_point)?;
 
-    let VestingParameters {
-        period_frequency,
-        cliff_unlock_liquidity,
-        liquidity_per_period,
-        number_of_period,
-        ..
-    } = params;
-
-    let mut vesting = ctx.accounts.vesting.load_init()?;
     vesting.initialize(
-        ctx.accounts.position.key(),
+        position_key,
         cliff_point,
-        period_frequency,
-        cliff_unlock_liquidity,
-        liquidity_per_period,
-        number_of_period,
+        params.period_frequency,
+        params.cliff_unlock_liquidity,
+        params.liquidity_per_period,
+        params.number_of_period,
     );
 
-    let mut position = ctx.accounts.position.load_mut()?;
     position.lock(total_lock_liquidity)?;
 
-    emit_cpi!(EvtLockPosition {
-        position: ctx.accounts.position.key(),
-        pool: ctx.accounts.pool.key(),
-        owner: ctx.accounts.owner.key(),
-        vesting: ctx.accounts.vesting.key(),
-        cliff_point,
-        period_frequency,
-        cliff_unlock_liquidity,
-        liquidity_per_period,
-        number_of_period,
-    });
+    drop(pool);
+    if is_boosted {
+        pool_loader
+            .load_mut()?
+            .lock_boosted_vested_liquidity(position, total_lock_liquidity)?;
+    }
 
-    Ok(())
+    Ok(cliff_point)
 }
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    constants::{fee::MAX_BASIS_POINT, MAX_VESTING_CURVE_EXPONENT, MAX_VESTING_SCHEDULE_LENGTH},
+    error::PoolError,
+    fee_math::pow,
+    get_pool_access_validator, refresh_position_nft_metadata_if_provided,
+    reward_boost::update_boosted_rewards_if_needed,
+    safe_math::SafeMath,
+    state::{get_vesting_growth_rate, ExtendedVesting, Pool, Position, VestingCurveType},
+    EvtLockPositionWithExtendedVesting,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExtendedVestingParameters {
+    // Set to None to start vesting immediately
+    pub cliff_point: Option<u64>,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    /// Total liquidity unlocked by periods after the cliff. Must equal to sum of schedule for custom curve
+    pub periodic_unlock_liquidity: u128,
+    /// Must equal to schedule length for custom curve
+    pub number_of_period: u16,
+    /// VestingCurveType
+    pub curve_type: u8,
+    /// Exponent for polynomial curve, growth rate in bps for exponential curve, 0 for other curves
+    pub curve_parameter: u32,
+    /// Liquidity unlocked in each period for custom curve, empty for other curves
+    pub schedule: Vec<u128>,
+}
+
+impl ExtendedVestingParameters {
+    pub fn get_cliff_point(&self, current_point: u64) -> Result<u64> {
+        Ok(self.cliff_point.unwrap_or(current_point))
+    }
+
+    pub fn get_total_lock_amount(&self) -> Result<u128> {
+        let total_amount = self
+            .cliff_unlock_liquidity
+            .safe_add(self.periodic_unlock_liquidity)?;
+        Ok(total_amount)
+    }
+
+    pub fn validate(&self, current_point: u64, max_vesting_duration: u64) -> Result<()> {
+        let cliff_point = self.get_cliff_point(current_point)?;
+
+        require!(cliff_point >= current_point, PoolError::InvalidVestingInfo);
+
+        if cliff_point == current_point {
+            require!(self.number_of_period > 0, PoolError::InvalidVestingInfo);
+        }
+
+        if self.number_of_period > 0 {
+            require!(
+                self.period_frequency > 0 && self.periodic_unlock_liquidity > 0,
+                PoolError::InvalidVestingInfo
+            );
+        } else {
+            require!(
+                self.periodic_unlock_liquidity == 0,
+                PoolError::InvalidVestingInfo
+            );
+        }
+
+        let curve_type = VestingCurveType::try_from(self.curve_type)
+            .map_err(|_| PoolError::InvalidVestingInfo)?;
+
+        match curve_type {
+            VestingCurveType::Linear => {
+                require!(self.curve_parameter == 0, PoolError::InvalidVestingInfo);
+            }
+            VestingCurveType::Polynomial => {
+                require!(
+                    self.curve_parameter > 0 && self.curve_parameter <= MAX_VESTING_CURVE_EXPONENT,
+                    PoolError::InvalidVestingInfo
+                );
+            }
+            VestingCurveType::Exponential => {
+                require!(
+                    self.curve_parameter > 0 && u64::from(self.curve_parameter) <= MAX_BASIS_POINT,
+                    PoolError::InvalidVestingInfo
+                );
+                // growth must not overflow over the whole schedule
+                let growth_rate = get_vesting_growth_rate(self.curve_parameter)?;
+                require!(
+                    pow(growth_rate, self.number_of_period.into()).is_some(),
+                    PoolError::InvalidVestingInfo
+                );
+            }
+            VestingCurveType::Custom => {
+                require!(self.curve_parameter == 0, PoolError::InvalidVestingInfo);
+                require!(
+                    self.schedule.len() <= MAX_VESTING_SCHEDULE_LENGTH
+                        && self.schedule.len() == usize::from(self.number_of_period),
+                    PoolError::InvalidVestingInfo
+                );
+                let mut total_schedule_liquidity: u128 = 0;
+                for amount in self.schedule.iter() {
+                    total_schedule_liquidity = total_schedule_liquidity.safe_add(*amount)?;
+                }
+                require!(
+                    total_schedule_liquidity == self.periodic_unlock_liquidity,
+                    PoolError::InvalidVestingInfo
+                );
+            }
+        }
+
+        if curve_type != VestingCurveType::Custom {
+            require!(self.schedule.is_empty(), PoolError::InvalidVestingInfo);
+        }
+
+        let vesting_duration = cliff_point.safe_sub(current_point)?.safe_add(
+            self.period_frequency
+                .safe_mul(self.number_of_period.into())?,
+        )?;
+
+        require!(
+            vesting_duration <= max_vesting_duration,
+            PoolError::InvalidVestingInfo
+        );
+
+        require!(
+            self.get_total_lock_amount()? > 0,
+            PoolError::InvalidVestingInfo
+        );
+
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LockPositionWithExtendedVestingCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub position: AccountLoader<'info, Position>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ExtendedVesting::INIT_SPACE
+    )]
+    pub vesting: AccountLoader<'info, ExtendedVesting>,
+
+    /// The token account for nft
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+            token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner of position
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_lock_position_with_extended_vesting<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LockPositionWithExtendedVestingCtx<'info>>,
+    params: ExtendedVestingParameters,
+) -> Result<()> {
+    let mut position = ctx.accounts.position.load_mut()?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let is_boosted = update_boosted_rewards_if_needed(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        &mut position,
+        &mut remaining_accounts,
+    )?;
+
+    let pool = ctx.accounts.pool.load()?;
+    let access_validator = get_pool_access_validator(&pool, None)?;
+    require!(
+        access_validator.can_lock_position(),
+        PoolError::PoolDisabled
+    );
+
+    let (current_point, max_vesting_duration) =
+        ActivationHandler::get_current_point_and_max_vesting_duration(pool.activation_type)?;
+
+    params.validate(current_point, max_vesting_duration)?;
+
+    let total_lock_liquidity = params.get_total_lock_amount()?;
+    let cliff_point = params.get_cliff_point(current_point)?;
+
+    let ExtendedVestingParameters {
+        period_frequency,
+        cliff_unlock_liquidity,
+        periodic_unlock_liquidity,
+        number_of_period,
+        curve_type,
+        curve_parameter,
+        schedule,
+        ..
+    } = params;
+
+    let mut vesting = ctx.accounts.vesting.load_init()?;
+    vesting.initialize(
+        ctx.accounts.position.key(),
+        cliff_point,
+        period_frequency,
+        cliff_unlock_liquidity,
+        periodic_unlock_liquidity,
+        number_of_period,
+        curve_type,
+        curve_parameter,
+        &schedule,
+    );
+
+    position.lock(total_lock_liquidity)?;
+
+    drop(pool);
+    if is_boosted {
+        ctx.accounts
+            .pool
+            .load_mut()?
+            .lock_boosted_vested_liquidity(&mut position, total_lock_liquidity)?;
+    }
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
+
+    emit_cpi!(EvtLockPositionWithExtendedVesting {
+        position: ctx.accounts.position.key(),
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        vesting: ctx.accounts.vesting.key(),
+        cliff_point,
+        period_frequency,
+        cliff_unlock_liquidity,
+        periodic_unlock_liquidity,
+        number_of_period,
+        curve_type,
+        curve_parameter,
+        schedule,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::PoolError,
+    lock_position_with_vesting, refresh_position_nft_metadata_if_provided,
+    state::{Pool, Position, Vesting},
+    EvtLockPosition, EvtSetVestingRevoker, VestingParameters,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: VestingParameters)]
+pub struct LockRevocablePositionCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub position: AccountLoader<'info, Position>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Vesting::INIT_SPACE
+    )]
+    pub vesting: AccountLoader<'info, Vesting>,
+
+    /// Position receiving locked liquidity when vesting is revoked
+    #[account(
+        has_one = pool,
+        constraint = revoke_position.key() != position.key() @ PoolError::SamePosition
+    )]
+    pub revoke_position: AccountLoader<'info, Position>,
+
+    /// The token account for nft
+    #[account(
+            constraint = position_nft_account.mint == position.load()?.nft_mint,
+            constraint = position_nft_account.amount == 1,
+            token::authority = owner
+    )]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner of position
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_lock_revocable_position<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LockRevocablePositionCtx<'info>>,
+    params: VestingParameters,
+    revoker: Pubkey,
+) -> Result<()> {
+    require!(revoker != Pubkey::default(), PoolError::InvalidAuthority);
+
+    let mut vesting = ctx.accounts.vesting.load_init()?;
+    let mut position = ctx.accounts.position.load_mut()?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    let cliff_point = lock_position_with_vesting(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        &mut position,
+        &mut vesting,
+        &params,
+        &mut remaining_accounts,
+    )?;
+    vesting.set_revoker(revoker, ctx.accounts.revoke_position.key());
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
+
+    emit_cpi!(EvtLockPosition {
+        position: ctx.accounts.position.key(),
+        pool: ctx.accounts.pool.key(),
+        owner: ctx.accounts.owner.key(),
+        vesting: ctx.accounts.vesting.key(),
+        cliff_point,
+        period_frequency: params.period_frequency,
+        cliff_unlock_liquidity: params.cliff_unlock_liquidity,
+        liquidity_per_period: params.liquidity_per_period,
+        number_of_period: params.number_of_period,
+    });
+
+    emit_cpi!(EvtSetVestingRevoker {
+        vesting: ctx.accounts.vesting.key(),
+        revoker,
+        revoke_position: ctx.accounts.revoke_position.key(),
+    });
+
+    Ok(())
+}
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::TokenAccount;
 
 use crate::{
-    get_pool_access_validator,
+    get_pool_access_validator, refresh_position_nft_metadata_if_provided,
+    reward_boost::update_boosted_rewards_if_needed,
     state::{Pool, Position},
     EvtPermanentLockPosition, PoolError,
 };
@@ -28,25 +29,36 @@ pub struct PermanentLockPositionCtx<'info> {
     pub owner: Signer<'info>,
 }
 
-pub fn handle_permanent_lock_position(
-    ctx: Context<PermanentLockPositionCtx>,
+pub fn handle_permanent_lock_position<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PermanentLockPositionCtx<'info>>,
     permanent_lock_liquidity: u128,
 ) -> Result<()> {
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let access_validator = get_pool_access_validator(&pool, None)?;
         require!(
             access_validator.can_lock_position(),
             PoolError::PoolDisabled
         );
     }
 
-    let mut pool = ctx.accounts.pool.load_mut()?;
     let mut position = ctx.accounts.position.load_mut()?;
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    // boosted rewards must be updated before liquidity is locked
+    update_boosted_rewards_if_needed(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        &mut position,
+        &mut remaining_accounts,
+    )?;
 
+    let mut pool = ctx.accounts.pool.load_mut()?;
     position.permanent_lock_liquidity(permanent_lock_liquidity)?;
     pool.accumulate_permanent_locked_liquidity(permanent_lock_liquidity)?;
 
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
+
     emit_cpi!(EvtPermanentLockPosition {
         pool: ctx.accounts.pool.key(),
         position: ctx.accounts.position.key(),
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface::Mint};
+use std::collections::BTreeSet;
+
+use crate::{
+    const_pda,
+    constants::position_nft_metadata::RESERVED_SPACE,
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, get_position_nft_pool_attributes, get_token_symbol,
+        update_position_nft_attributes,
+    },
+    state::{Pool, Position},
+    token::update_account_lamports_to_minimum_balance,
+    PoolError,
+};
+
+/// Write all attributes to the position nft metadata, including token symbols. Can be called by anyone.
+/// Remaining accounts: optional metaplex metadata accounts of token a and token b
+#[derive(Accounts)]
+pub struct RefreshPositionNftMetadataCtx<'info> {
+    #[account(has_one = token_a_mint, has_one = token_b_mint)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(has_one = pool)]
+    pub position: AccountLoader<'info, Position>,
+
+    /// CHECK: position nft mint, validated against position
+    #[account(mut, address = position.load()?.nft_mint @ PoolError::InvalidPositionNftMint)]
+    pub position_nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Address paying rent for the new attributes. Can be anyone
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Program to update position nft metadata
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_refresh_position_nft_metadata(
+    ctx: Context<RefreshPositionNftMetadataCtx>,
+) -> Result<()> {
+    let token_a_symbol = get_token_symbol(
+        &ctx.accounts.token_a_mint.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+    let token_b_symbol = get_token_symbol(
+        &ctx.accounts.token_b_mint.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    let attributes = {
+        let pool = ctx.accounts.pool.load()?;
+        let position = ctx.accounts.position.load()?;
+        let mut attributes = get_position_nft_pool_attributes(
+            ctx.accounts.pool.key(),
+            &pool,
+            token_a_symbol,
+            token_b_symbol,
+        );
+        attributes.extend(get_position_nft_liquidity_attributes(&position));
+        attributes
+    };
+
+    update_position_nft_attributes(
+        &ctx.accounts.position_nft_mint.to_account_info(),
+        &ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &attributes,
+        true,
+    )?;
+
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.position_nft_mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        RESERVED_SPACE,
+    )?;
+
+    Ok(())
+}
+
+/// Optional remaining accounts of add/remove liquidity, lock and split position endpoints to refresh liquidity attributes of position nft
+#[derive(Accounts)]
+pub struct PositionNftMetadataAccounts<'info> {
+    /// CHECK: position nft mint, validated against position
+    #[account(mut)]
+    pub position_nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> PositionNftMetadataAccounts<'info> {
+    pub fn refresh_liquidity_attributes(&self, position: &Position) -> Result<()> {
+        require!(
+            self.position_nft_mint.key() == position.nft_mint,
+            PoolError::InvalidPositionNftMint
+        );
+
+        // only refresh attributes which already exist, so rent reserved at position creation is enough
+        update_position_nft_attributes(
+            &self.position_nft_mint.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            &self.token_program.to_account_info(),
+            &get_position_nft_liquidity_attributes(position),
+            false,
+        )
+    }
+}
+
+/// Refresh liquidity attributes of position nft if metadata accounts are passed in remaining accounts
+pub fn refresh_position_nft_metadata_if_provided<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    position: &Position,
+) -> Result<()> {
+    if remaining_accounts.is_empty() {
+        return Ok(());
+    }
+
+    let metadata_accounts = PositionNftMetadataAccounts::try_accounts(
+        &crate::ID,
+        remaining_accounts,
+        &[],
+        &mut PositionNftMetadataAccountsBumps {},
+        &mut BTreeSet::new(),
+    )?;
+
+    metadata_accounts.refresh_liquidity_attributes(position)
+}
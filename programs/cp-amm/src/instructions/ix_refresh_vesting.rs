@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use anchor_spl::token_interface::TokenAccount;
 use std::cell::RefMut;
 use std::collections::BTreeSet;
 
 use crate::{
     activation_handler::ActivationHandler,
-    state::{Pool, Position, Vesting},
-    PoolError,
+    reward_boost::update_boosted_rewards_if_needed,
+    safe_math::SafeMath,
+    state::{ExtendedVesting, Pool, Position, Vesting},
+    PoolError, PositionNftMetadataAccounts, PositionNftMetadataAccountsBumps,
 };
 
 #[derive(Accounts)]
 pub struct RefreshVesting<'info> {
+    #[account(mut)]
     pub pool: AccountLoader<'info, Pool>,
 
     #[account(
@@ -48,22 +52,90 @@ impl<'info> VestingRemainingAccount<'info> {
     }
 }
 
+#[derive(Accounts)]
+pub struct ExtendedVestingRemainingAccount<'info> {
+    #[account(mut)]
+    pub vesting: AccountLoader<'info, ExtendedVesting>,
+}
+
+impl<'info> ExtendedVestingRemainingAccount<'info> {
+    pub fn load_and_validate(&self, position: Pubkey) -> Result<RefMut<'_, ExtendedVesting>> {
+        let vesting = self.vesting.load_mut()?;
+        require!(
+            vesting.position == position,
+            PoolError::InvalidVestingAccount
+        );
+        Ok(vesting)
+    }
+}
+
 pub fn handle_refresh_vesting<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, RefreshVesting<'info>>,
 ) -> Result<()> {
-    let pool = ctx.accounts.pool.load()?;
-
-    let (current_point, _) =
-        ActivationHandler::get_current_point_and_buffer_duration(pool.activation_type)?;
-
     let mut position: RefMut<'_, Position> = ctx.accounts.position.load_mut()?;
     let mut remaining_accounts = &ctx.remaining_accounts[..];
+    // boosted rewards must be updated before vested liquidity is released
+    update_boosted_rewards_if_needed(
+        &ctx.accounts.pool,
+        ctx.accounts.position.key(),
+        &mut position,
+        &mut remaining_accounts,
+    )?;
+
+    let activation_type = ctx.accounts.pool.load()?.activation_type;
+    let (current_point, _) =
+        ActivationHandler::get_current_point_and_buffer_duration(activation_type)?;
+
+    let mut total_released_liquidity: u128 = 0;
+    let mut metadata_accounts = None;
 
     loop {
         if remaining_accounts.is_empty() {
             break;
         }
 
+        // position nft metadata accounts can be passed along with vesting accounts
+        if remaining_accounts[0].key() == position.nft_mint {
+            metadata_accounts = Some(PositionNftMetadataAccounts::try_accounts(
+                &crate::ID,
+                &mut remaining_accounts,
+                &[],
+                &mut PositionNftMetadataAccountsBumps {},
+                &mut BTreeSet::new(),
+            )?);
+            continue;
+        }
+
+        // vesting and extended vesting accounts can be mixed
+        let is_extended_vesting = remaining_accounts[0]
+            .try_borrow_data()?
+            .starts_with(ExtendedVesting::DISCRIMINATOR);
+        if is_extended_vesting {
+            let vesting_account = ExtendedVestingRemainingAccount::try_accounts(
+                &crate::ID,
+                &mut remaining_accounts,
+                &[],
+                &mut ExtendedVestingRemainingAccountBumps {},
+                &mut BTreeSet::new(),
+            )?;
+
+            let mut vesting = vesting_account.load_and_validate(ctx.accounts.position.key())?;
+            let released_liquidity = vesting.get_new_release_liquidity(current_point)?;
+            if released_liquidity > 0 {
+                position.release_vested_liquidity(released_liquidity)?;
+                vesting.accumulate_released_liquidity(released_liquidity)?;
+                total_released_liquidity = total_released_liquidity.safe_add(released_liquidity)?;
+            }
+
+            if vesting.done()? {
+                drop(vesting);
+                vesting_account
+                    .vesting
+                    .close(ctx.accounts.owner.to_account_info())?;
+            }
+            continue;
+        }
+
         let vesting_account = VestingRemainingAccount::try_accounts(
             &crate::ID,
             &mut remaining_accounts,
@@ -73,7 +145,9 @@ pub fn handle_refresh_vesting<'a, 'b, 'c: 'info, 'info>(
         )?;
 
         let mut vesting = vesting_account.load_and_validate(ctx.accounts.position.key())?;
-        release_vesting_liquidity_to_position(&mut vesting, &mut position, current_point)?;
+        let released_liquidity =
+            release_vesting_liquidity_to_position(&mut vesting, &mut position, current_point)?;
+        total_released_liquidity = total_released_liquidity.safe_add(released_liquidity)?;
 
         if vesting.done()? {
             drop(vesting);
@@ -83,6 +157,19 @@ pub fn handle_refresh_vesting<'a, 'b, 'c: 'info, 'info>(
         }
     }
 
+    // counted vested liquidity is removed even once the pool has no boosted reward left, so boosted
+    // liquidity supply stays in sync if a reward is boosted again
+    if position.boosted_vested_liquidity > 0 && total_released_liquidity > 0 {
+        ctx.accounts
+            .pool
+            .load_mut()?
+            .unlock_boosted_vested_liquidity(&mut position, total_released_liquidity)?;
+    }
+
+    if let Some(metadata_accounts) = metadata_accounts {
+        metadata_accounts.refresh_liquidity_attributes(&position)?;
+    }
+
     Ok(())
 }
 
@@ -90,12 +177,12 @@ fn release_vesting_liquidity_to_position(
     vesting: &mut RefMut<'_, Vesting>,
     position: &mut RefMut<'_, Position>,
     current_point: u64,
-) -> Result<()> {
+) -> Result<u128> {
     let released_liquidity = vesting.get_new_release_liquidity(current_point)?;
     if released_liquidity > 0 {
         position.release_vested_liquidity(released_liquidity)?;
         vesting.accumulate_released_liquidity(released_liquidity)?;
     }
 
-    Ok(())
+    Ok(released_liquidity)
 }
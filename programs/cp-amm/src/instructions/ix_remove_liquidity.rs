@@ -4,11 +4,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda, get_pool_access_validator,
-    state::{ModifyLiquidityResult, Pool, Position},
+    activation_handler::ActivationHandler,
+    const_pda, get_pool_access_validator, refresh_position_nft_metadata_if_provided,
+    reward_extension::update_extended_rewards_if_enabled,
+    state::{ModifyLiquidityResult, PauseAction, Pool, Position},
     token::{calculate_transfer_fee_excluded_amount, transfer_from_pool},
     u128x128_math::Rounding,
-    EvtLiquidityChange, PoolError,
+    validate_action_not_paused, EvtLiquidityChange, PoolError,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
@@ -77,15 +79,18 @@ pub struct RemoveLiquidityCtx<'info> {
     pub token_b_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_remove_liquidity(
-    ctx: Context<RemoveLiquidityCtx>,
+pub fn handle_remove_liquidity<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, RemoveLiquidityCtx<'info>>,
     liquidity_delta: Option<u128>,
     token_a_amount_threshold: u64,
     token_b_amount_threshold: u64,
 ) -> Result<()> {
+    ctx.remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::RemoveLiquidity)?;
+
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let access_validator = get_pool_access_validator(&pool, None)?;
         require!(
             access_validator.can_remove_liquidity(),
             PoolError::PoolDisabled
@@ -101,9 +106,14 @@ pub fn handle_remove_liquidity(
         PoolError::InsufficientLiquidity
     );
 
+    // move sqrt max price down the liquidity bootstrapping schedule before liquidity change
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    pool.update_lbp_price_bound(current_point)?;
+
     // update current pool reward & postion reward before any logic
-    let current_time = Clock::get()?.unix_timestamp as u64;
-    position.update_rewards(&mut pool, current_time)?;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    position.update_rewards(&mut pool, current_time, clock.slot)?;
 
     let ModifyLiquidityResult {
         token_a_amount,
@@ -142,7 +152,26 @@ pub fn handle_remove_liquidity(
         PoolError::ExceededSlippage
     );
 
+    // update extended rewards before liquidity change
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    update_extended_rewards_if_enabled(
+        &mut remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[(ctx.accounts.position.key(), &*position)],
+        current_time,
+        clock.slot,
+    )?;
+
     pool.apply_remove_liquidity(&mut position, liquidity_delta)?;
+    position.cost_basis.accumulate_withdrawal(
+        token_a_amount,
+        token_b_amount,
+        pool.sqrt_price,
+        current_time,
+    )?;
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
 
     // send to user
     transfer_from_pool(
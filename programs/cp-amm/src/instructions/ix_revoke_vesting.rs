@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    refresh_position_nft_metadata_if_provided,
+    reward_extension::update_extended_rewards_if_enabled,
+    safe_math::SafeMath,
+    state::{Pool, Position, Vesting},
+    EvtRevokeVesting,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeVestingCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// Position of the beneficiary
+    #[account(mut, has_one = pool)]
+    pub position: AccountLoader<'info, Position>,
+
+    #[account(
+        mut,
+        has_one = position,
+        has_one = revoker,
+        has_one = revoke_position,
+        close = revoker
+    )]
+    pub vesting: AccountLoader<'info, Vesting>,
+
+    /// Position receiving locked liquidity
+    #[account(mut, has_one = pool)]
+    pub revoke_position: AccountLoader<'info, Position>,
+
+    #[account(mut)]
+    pub revoker: Signer<'info>,
+}
+
+pub fn handle_revoke_vesting<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RevokeVestingCtx<'info>>,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut position = ctx.accounts.position.load_mut()?;
+    let mut revoke_position = ctx.accounts.revoke_position.load_mut()?;
+    let mut vesting = ctx.accounts.vesting.load_mut()?;
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    // update current pool reward
+    pool.update_rewards(current_time, clock.slot)?;
+    // update both positions reward before moving liquidity
+    position.update_position_reward(&pool)?;
+    revoke_position.update_position_reward(&pool)?;
+
+    // remaining accounts: reward extension accounts if pool has reward extension,
+    // then metadata accounts of position nft, then revoke position nft
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    update_extended_rewards_if_enabled(
+        &mut remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[
+            (ctx.accounts.position.key(), &*position),
+            (ctx.accounts.revoke_position.key(), &*revoke_position),
+        ],
+        current_time,
+        clock.slot,
+    )?;
+
+    // beneficiary keeps liquidity released until now
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    let released_liquidity = vesting.get_new_release_liquidity(current_point)?;
+    if released_liquidity > 0 {
+        position.release_vested_liquidity(released_liquidity)?;
+        vesting.accumulate_released_liquidity(released_liquidity)?;
+    }
+
+    let revoked_liquidity = vesting.get_locked_liquidity()?;
+    pool.apply_revoke_vesting(&mut position, &mut revoke_position, revoked_liquidity)?;
+    pool.unlock_boosted_vested_liquidity(
+        &mut position,
+        released_liquidity.safe_add(revoked_liquidity)?,
+    )?;
+
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &position)?;
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &revoke_position)?;
+
+    emit_cpi!(EvtRevokeVesting {
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        vesting: ctx.accounts.vesting.key(),
+        revoker: ctx.accounts.revoker.key(),
+        revoke_position: ctx.accounts.revoke_position.key(),
+        released_liquidity,
+        revoked_liquidity,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{REWARD_INDEX_0, REWARD_INDEX_1, SPLIT_POSITION_DENOMINATOR},
-    get_pool_access_validator,
+    get_pool_access_validator, refresh_position_nft_metadata_if_provided,
+    reward_extension::update_extended_rewards_if_enabled,
     state::{SplitAmountInfo, SplitPositionInfo},
     EvtSplitPosition2, PoolError, SplitPositionCtx,
 };
@@ -58,13 +59,13 @@ impl SplitPositionParameters2 {
     }
 }
 
-pub fn handle_split_position2(
-    ctx: Context<SplitPositionCtx>,
+pub fn handle_split_position2<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SplitPositionCtx<'info>>,
     params: SplitPositionParameters2,
 ) -> Result<()> {
     {
         let pool = ctx.accounts.pool.load()?;
-        let access_validator = get_pool_access_validator(&pool)?;
+        let access_validator = get_pool_access_validator(&pool, None)?;
         require!(
             access_validator.can_split_position(),
             PoolError::PoolDisabled
@@ -95,13 +96,29 @@ pub fn handle_split_position2(
         PoolError::UnsupportPositionHasVestingLock
     );
 
-    let current_time = Clock::get()?.unix_timestamp as u64;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
     // update current pool reward
-    pool.update_rewards(current_time)?;
+    pool.update_rewards(current_time, clock.slot)?;
     // update first and second position reward
     first_position.update_position_reward(&pool)?;
     second_position.update_position_reward(&pool)?;
 
+    // remaining accounts: reward extension accounts if pool has reward extension,
+    // then metadata accounts of first position nft, then second position nft
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    update_extended_rewards_if_enabled(
+        &mut remaining_accounts,
+        ctx.accounts.pool.key(),
+        &pool,
+        &[
+            (ctx.accounts.first_position.key(), &*first_position),
+            (ctx.accounts.second_position.key(), &*second_position),
+        ],
+        current_time,
+        clock.slot,
+    )?;
+
     let split_amount_info: SplitAmountInfo = pool.apply_split_position(
         &mut first_position,
         &mut second_position,
@@ -113,6 +130,9 @@ pub fn handle_split_position2(
         reward_1_numerator,
     )?;
 
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &first_position)?;
+    refresh_position_nft_metadata_if_provided(&mut remaining_accounts, &second_position)?;
+
     emit_cpi!(EvtSplitPosition2 {
         pool: ctx.accounts.pool.key(),
         first_owner: ctx.accounts.first_owner.key(),
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    event::EvtTopUpReward,
+    safe_math::SafeMath,
+    state::{Pool, RewardFunderLedger, RewardFunding},
+    token::{calculate_transfer_fee_excluded_amount, transfer_from_user},
+    PoolError,
+};
+
+/// Fund a reward in permissionless funding mode. Can be called by anyone with a funder ledger
+/// Funding is not an action of the global pause, so top up stays open when actions are paused
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TopUpRewardCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub reward_funding: AccountLoader<'info, RewardFunding>,
+
+    #[account(mut, has_one = reward_funding, has_one = funder)]
+    pub funder_ledger: AccountLoader<'info, RewardFunderLedger>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_top_up_reward(ctx: Context<TopUpRewardCtx>, amount: u64) -> Result<()> {
+    // actual amount need to transfer
+    let transfer_fee_excluded_amount_in = calculate_transfer_fee_excluded_amount(
+        &ctx.accounts
+            .reward_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        amount,
+    )?
+    .amount;
+
+    require!(transfer_fee_excluded_amount_in > 0, PoolError::AmountIsZero);
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut reward_funding = ctx.accounts.reward_funding.load_mut()?;
+    let mut funder_ledger = ctx.accounts.funder_ledger.load_mut()?;
+    let reward_index = reward_funding.reward_index;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+
+    // 1. update pool rewards
+    pool.update_rewards(current_time, clock.slot)?;
+    let activation_type = pool.activation_type;
+
+    let reward_info = &mut pool.reward_infos[usize::from(reward_index)];
+    require!(
+        reward_info.is_permissionless_funding(),
+        PoolError::InvalidRewardFundingMode
+    );
+    require!(
+        reward_info.vault.eq(&ctx.accounts.reward_vault.key()),
+        PoolError::InvalidRewardVault
+    );
+
+    // 2. share ineligible reward among previous funders before the new funding.
+    // Without previous funders it is left in the pool for the reward funder to withdraw
+    let ineligible_reward = reward_info.claim_ineligible_reward()?;
+    let undistributed_ineligible_reward =
+        reward_funding.distribute_ineligible_reward(ineligible_reward)?;
+    reward_info.cumulative_ineligible_reward = reward_info
+        .cumulative_ineligible_reward
+        .safe_add(undistributed_ineligible_reward)?;
+    let distributed_ineligible_reward =
+        ineligible_reward.safe_sub(undistributed_ineligible_reward)?;
+    funder_ledger.accumulate_funded_amount(&mut reward_funding, transfer_fee_excluded_amount_in)?;
+
+    // 3. extend or increase farming rate
+    let pre_reward_rate = reward_info.reward_rate;
+    let current_point = reward_info.get_current_point(activation_type, current_time, clock.slot);
+    reward_info.update_rate_after_funding(current_point, transfer_fee_excluded_amount_in)?;
+
+    transfer_from_user(
+        &ctx.accounts.funder,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    emit_cpi!(EvtTopUpReward {
+        pool: ctx.accounts.pool.key(),
+        funder: ctx.accounts.funder.key(),
+        mint_reward: ctx.accounts.reward_mint.key(),
+        reward_index,
+        amount,
+        transfer_fee_excluded_amount_in,
+        distributed_ineligible_reward,
+        funder_funded_amount: funder_ledger.funded_amount,
+        pre_reward_rate,
+        post_reward_rate: reward_info.reward_rate,
+        reward_duration_end: reward_info.reward_duration_end,
+    });
+
+    Ok(())
+}
@@ -2,8 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda, constants::NUM_REWARDS, error::PoolError, event::EvtWithdrawIneligibleReward,
-    state::pool::Pool, token::transfer_from_pool,
+    const_pda,
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    error::PoolError,
+    event::EvtWithdrawIneligibleReward,
+    reward_extension::load_reward_extension,
+    safe_math::SafeMath,
+    state::{
+        is_extended_reward_index, pool::Pool, PauseAction, RewardFunderLedger, RewardFunding,
+        RewardInfo,
+    },
+    token::transfer_from_pool,
+    validate_action_not_paused,
 };
 
 #[event_cpi]
@@ -31,11 +41,15 @@ pub struct WithdrawIneligibleRewardCtx<'info> {
 
 impl<'info> WithdrawIneligibleRewardCtx<'info> {
     fn validate(&self, reward_index: usize) -> Result<()> {
-        let pool = self.pool.load()?;
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
 
-        let reward_info = &pool.reward_infos[reward_index];
+        Ok(())
+    }
 
+    fn validate_reward_info(&self, reward_info: &RewardInfo, activation_type: u8) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
 
         require!(
@@ -43,14 +57,18 @@ impl<'info> WithdrawIneligibleRewardCtx<'info> {
             PoolError::InvalidRewardVault
         );
 
+        // in permissionless funding mode, funder is validated by its funder ledger
         require!(
-            reward_info.is_valid_funder(self.funder.key()),
+            reward_info.is_permissionless_funding()
+                || reward_info.is_valid_funder(self.funder.key()),
             PoolError::InvalidFunder
         );
 
-        let current_timestamp = Clock::get()?.unix_timestamp as u64;
+        let clock = Clock::get()?;
+        let current_point =
+            reward_info.get_current_point(activation_type, clock.unix_timestamp as u64, clock.slot);
         require!(
-            current_timestamp > reward_info.reward_duration_end,
+            current_point > reward_info.reward_duration_end,
             PoolError::RewardNotEnded
         );
 
@@ -58,23 +76,87 @@ impl<'info> WithdrawIneligibleRewardCtx<'info> {
     }
 }
 
-pub fn handle_withdraw_ineligible_reward(
-    ctx: Context<WithdrawIneligibleRewardCtx>,
+pub fn handle_withdraw_ineligible_reward<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawIneligibleRewardCtx<'info>>,
     reward_index: u8,
 ) -> Result<()> {
+    let mut remaining_accounts =
+        validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
     ctx.accounts.validate(index)?;
 
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
     let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
 
-    let current_time = Clock::get()?.unix_timestamp as u64;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
 
     // update pool reward
-    pool.update_rewards(current_time)?;
-
-    let ineligible_reward = pool.claim_ineligible_reward(index)?;
+    pool.update_rewards(current_time, clock.slot)?;
+    if let Some(reward_extension) = reward_extension.as_mut() {
+        reward_extension.update_rewards(&pool, current_time, clock.slot)?;
+    }
+    let activation_type = pool.activation_type;
+
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts
+        .validate_reward_info(reward_info, activation_type)?;
+
+    let ineligible_reward = reward_info.claim_ineligible_reward()?;
+    let ineligible_reward = if reward_info.is_permissionless_funding() {
+        // remaining accounts: reward funding, then funder ledger of the funder
+        let reward_funding_loader = load_reward_funding(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+            reward_index,
+        )?;
+        let funder_ledger_loader = load_funder_ledger(
+            &mut remaining_accounts,
+            reward_funding_loader.key(),
+            ctx.accounts.funder.key(),
+        )?;
+        let mut reward_funding = reward_funding_loader.load_mut()?;
+        let mut funder_ledger = funder_ledger_loader.load_mut()?;
+
+        let undistributed_reward =
+            reward_funding.distribute_ineligible_reward(ineligible_reward)?;
+        let ineligible_reward = funder_ledger.claim_ineligible_reward(&reward_funding)?;
+
+        // nothing funded by funders, ineligible reward goes back to the reward funder
+        if undistributed_reward > 0 {
+            if reward_info.is_valid_funder(ctx.accounts.funder.key()) {
+                ineligible_reward.safe_add(undistributed_reward)?
+            } else {
+                reward_info.cumulative_ineligible_reward = reward_info
+                    .cumulative_ineligible_reward
+                    .safe_add(undistributed_reward)?;
+                ineligible_reward
+            }
+        } else {
+            ineligible_reward
+        }
+    } else {
+        ineligible_reward
+    };
 
     // transfer rewards to funder
     if ineligible_reward > 0 {
@@ -96,3 +178,47 @@ pub fn handle_withdraw_ineligible_reward(
 
     Ok(())
 }
+
+fn load_reward_funding<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    pool: Pubkey,
+    reward_index: u8,
+) -> Result<AccountLoader<'info, RewardFunding>> {
+    let (account, rest) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::InvalidRewardFundingAccount)?;
+    *remaining_accounts = rest;
+
+    let reward_funding_loader: AccountLoader<'info, RewardFunding> =
+        AccountLoader::try_from(account)?;
+    {
+        let reward_funding = reward_funding_loader.load()?;
+        require!(
+            reward_funding.pool.eq(&pool) && reward_funding.reward_index == reward_index,
+            PoolError::InvalidRewardFundingAccount
+        );
+    }
+    Ok(reward_funding_loader)
+}
+
+fn load_funder_ledger<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    reward_funding: Pubkey,
+    funder: Pubkey,
+) -> Result<AccountLoader<'info, RewardFunderLedger>> {
+    let (account, rest) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::InvalidRewardFundingAccount)?;
+    *remaining_accounts = rest;
+
+    let funder_ledger_loader: AccountLoader<'info, RewardFunderLedger> =
+        AccountLoader::try_from(account)?;
+    {
+        let funder_ledger = funder_ledger_loader.load()?;
+        require!(
+            funder_ledger.reward_funding.eq(&reward_funding) && funder_ledger.funder.eq(&funder),
+            PoolError::InvalidRewardFundingAccount
+        );
+    }
+    Ok(funder_ledger_loader)
+}
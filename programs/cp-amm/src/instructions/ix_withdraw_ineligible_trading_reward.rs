@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda,
+    event::EvtWithdrawIneligibleTradingReward,
+    state::{Pool, TradingRewardCampaign},
+    token::transfer_from_pool,
+    PoolError,
+};
+
+/// Withdraw reward of an ended trading reward campaign where no trader earned points
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawIneligibleTradingRewardCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = reward_vault, has_one = reward_mint, has_one = funder)]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_withdraw_ineligible_trading_reward(
+    ctx: Context<WithdrawIneligibleTradingRewardCtx>,
+) -> Result<()> {
+    let pool = ctx.accounts.pool.load()?;
+    let mut campaign = ctx.accounts.campaign.load_mut()?;
+
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    require!(
+        campaign.is_ended(current_point),
+        PoolError::TradingRewardCampaignNotEnded
+    );
+
+    let ineligible_reward = campaign.withdraw_ineligible_reward()?;
+
+    if ineligible_reward > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.reward_mint,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.funder_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            ineligible_reward,
+        )?;
+    }
+
+    emit_cpi!(EvtWithdrawIneligibleTradingReward {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        funder: ctx.accounts.funder.key(),
+        amount: ineligible_reward,
+    });
+
+    Ok(())
+}
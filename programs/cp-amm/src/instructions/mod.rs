@@ -12,26 +12,62 @@ pub mod ix_remove_liquidity;
 pub use ix_remove_liquidity::*;
 pub mod ix_claim_position_fee;
 pub use ix_claim_position_fee::*;
+pub mod ix_batch_claim;
+pub use ix_batch_claim::*;
 pub mod initialize_pool;
 pub use initialize_pool::*;
 pub mod ix_lock_position;
 pub use ix_lock_position::*;
+pub mod ix_lock_position_with_extended_vesting;
+pub use ix_lock_position_with_extended_vesting::*;
+pub mod ix_lock_revocable_position;
+pub use ix_lock_revocable_position::*;
+pub mod ix_revoke_vesting;
+pub use ix_revoke_vesting::*;
 pub mod ix_refresh_vesting;
 pub use ix_refresh_vesting::*;
 pub mod ix_permanent_lock_position;
 pub use ix_permanent_lock_position::*;
 pub mod ix_claim_reward;
 pub use ix_claim_reward::*;
+pub mod ix_initialize_position_reward_extension;
+pub use ix_initialize_position_reward_extension::*;
 pub mod partner;
 pub use partner::*;
+pub mod creator;
+pub use creator::*;
 pub mod ix_fund_reward;
 pub use ix_fund_reward::*;
+pub mod ix_top_up_reward;
+pub use ix_top_up_reward::*;
+pub mod ix_initialize_reward_funder_ledger;
+pub use ix_initialize_reward_funder_ledger::*;
+pub mod ix_close_reward_funder_ledger;
+pub use ix_close_reward_funder_ledger::*;
 pub mod ix_withdraw_ineligible_reward;
 pub use ix_withdraw_ineligible_reward::*;
+pub mod ix_fund_trading_reward;
+pub use ix_fund_trading_reward::*;
+pub mod ix_initialize_trader_reward;
+pub use ix_initialize_trader_reward::*;
+pub mod ix_claim_trading_reward;
+pub use ix_claim_trading_reward::*;
+pub mod ix_withdraw_ineligible_trading_reward;
+pub use ix_withdraw_ineligible_trading_reward::*;
+pub mod ix_close_trader_reward;
+pub use ix_close_trader_reward::*;
+pub mod ix_create_allowlist_entry;
+pub use ix_create_allowlist_entry::*;
+pub mod ix_close_allowlist_entry;
+pub use ix_close_allowlist_entry::*;
 pub mod ix_close_position;
 pub use ix_close_position::*;
+pub mod ix_close_pool;
+pub use ix_close_pool::*;
 pub mod ix_split_position;
 pub use ix_split_position::*;
 
 pub mod ix_split_position2;
 pub use ix_split_position2::*;
+pub mod ix_refresh_position_nft_metadata;
+pub use ix_refresh_position_nft_metadata::*;
@@ -1,9 +1,9 @@
 use crate::{
     const_pda,
     constants::treasury,
-    state::{Operator, Pool},
+    state::{Operator, PauseAction, Pool},
     token::{transfer_from_pool, validate_ata_token},
-    EvtClaimProtocolFee,
+    validate_action_not_paused, EvtClaimProtocolFee,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -60,6 +60,8 @@ pub fn handle_claim_protocol_fee(
     max_amount_a: u64,
     max_amount_b: u64,
 ) -> Result<()> {
+    validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let mut pool = ctx.accounts.pool.load_mut()?;
 
     let (token_a_amount, token_b_amount) = pool.claim_protocol_fee(max_amount_a, max_amount_b)?;
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+use crate::{
+    activation_handler::ActivationHandler,
+    const_pda,
+    error::PoolError,
+    event::EvtCloseTradingRewardCampaign,
+    is_valid_remaining_operator_role,
+    safe_math::SafeMath,
+    state::{OperatorPermission, Pool, TradingRewardCampaign},
+    token::transfer_from_pool,
+};
+
+/// Close an ended trading reward campaign once all trader reward accounts are closed.
+/// Reward left in the vault is returned to the campaign funder
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseTradingRewardCampaignCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = reward_vault,
+        has_one = reward_mint,
+        close = rent_receiver
+    )]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Funder token account, receives reward left in the vault
+    #[account(mut, token::authority = campaign.load()?.funder, token::mint = reward_mint, token::token_program = token_program)]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub signer: Signer<'info>,
+
+    /// CHECK: Account to receive closed account rental SOL
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_close_trading_reward_campaign<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseTradingRewardCampaignCtx<'info>>,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    if ctx.accounts.signer.key() != pool.creator {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::InitializeReward,
+        )?;
+    }
+
+    let (funder, amount) = {
+        let campaign = ctx.accounts.campaign.load()?;
+
+        let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+        require!(
+            campaign.is_ended(current_point),
+            PoolError::TradingRewardCampaignNotEnded
+        );
+        require!(
+            campaign.number_of_trader_rewards == 0,
+            PoolError::TraderRewardNotClosed
+        );
+
+        (campaign.funder, ctx.accounts.reward_vault.amount)
+    };
+
+    if amount > 0 {
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.reward_mint,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.funder_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+
+    let signer_seeds = pool_authority_seeds!();
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.reward_vault.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: ctx.accounts.pool_authority.to_account_info(),
+        },
+        &[&signer_seeds[..]],
+    ))?;
+
+    pool.metrics.number_of_trading_reward_campaigns = pool
+        .metrics
+        .number_of_trading_reward_campaigns
+        .safe_sub(1)?;
+
+    emit_cpi!(EvtCloseTradingRewardCampaign {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        funder,
+        amount,
+    });
+
+    Ok(())
+}
@@ -20,6 +20,51 @@ pub struct StaticConfigParameters {
     pub collect_fee_mode: u8,
 }
 
+impl StaticConfigParameters {
+    /// `has_alpha_vault` follows `Config::has_alpha_vault` of the config with these parameters
+    pub fn validate(&self, has_alpha_vault: bool) -> Result<()> {
+        let &StaticConfigParameters {
+            pool_fees,
+            activation_type,
+            sqrt_min_price,
+            sqrt_max_price,
+            collect_fee_mode,
+            ..
+        } = self;
+
+        require!(
+            sqrt_min_price >= MIN_SQRT_PRICE && sqrt_max_price <= MAX_SQRT_PRICE,
+            PoolError::InvalidPriceRange
+        );
+
+        require!(
+            sqrt_min_price < sqrt_max_price,
+            PoolError::InvalidPriceRange
+        );
+
+        let activation_point = Some(ActivationHandler::get_max_activation_point(
+            activation_type,
+        )?);
+
+        let activation_params = ActivationParams {
+            activation_point,
+            activation_type,
+            has_alpha_vault,
+        };
+        activation_params.validate()?;
+
+        let pool_activation_type = ActivationType::try_from(activation_type)
+            .map_err(|_| PoolError::InvalidActivationType)?;
+
+        let pool_collect_fee_mode = CollectFeeMode::try_from(collect_fee_mode)
+            .map_err(|_| PoolError::InvalidCollectFeeMode)?;
+
+        pool_fees.validate(pool_collect_fee_mode, pool_activation_type)?;
+
+        Ok(())
+    }
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(index: u64)]
@@ -48,6 +93,12 @@ pub fn handle_create_static_config(
     index: u64,
     config_parameters: StaticConfigParameters,
 ) -> Result<()> {
+    let mut config = ctx.accounts.config.load_init()?;
+    // new config starts in alpha vault pre-activation buyer mode
+    config_parameters.validate(
+        config.has_alpha_vault_with_vault_config_key(config_parameters.vault_config_key),
+    )?;
+
     let StaticConfigParameters {
         pool_fees,
         vault_config_key,
@@ -58,38 +109,6 @@ pub fn handle_create_static_config(
         collect_fee_mode,
     } = config_parameters;
 
-    require!(
-        sqrt_min_price >= MIN_SQRT_PRICE && sqrt_max_price <= MAX_SQRT_PRICE,
-        PoolError::InvalidPriceRange
-    );
-
-    require!(
-        sqrt_min_price < sqrt_max_price,
-        PoolError::InvalidPriceRange
-    );
-
-    let has_alpha_vault = vault_config_key.ne(&Pubkey::default());
-
-    let activation_point = Some(ActivationHandler::get_max_activation_point(
-        activation_type,
-    )?);
-
-    let activation_params = ActivationParams {
-        activation_point,
-        activation_type,
-        has_alpha_vault,
-    };
-    activation_params.validate()?;
-
-    let pool_activation_type =
-        ActivationType::try_from(activation_type).map_err(|_| PoolError::InvalidActivationType)?;
-
-    let pool_collect_fee_mode =
-        CollectFeeMode::try_from(collect_fee_mode).map_err(|_| PoolError::InvalidCollectFeeMode)?;
-
-    pool_fees.validate(pool_collect_fee_mode, pool_activation_type)?;
-
-    let mut config = ctx.accounts.config.load_init()?;
     config.init_static_config(
         index,
         &pool_fees,
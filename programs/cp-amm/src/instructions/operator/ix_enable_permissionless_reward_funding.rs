@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{seeds::REWARD_FUNDING_PREFIX, NUM_REWARDS},
+    error::PoolError,
+    event::EvtEnablePermissionlessRewardFunding,
+    is_valid_remaining_operator_role,
+    state::{OperatorPermission, Pool, RewardFunding, RewardFundingMode},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct EnablePermissionlessRewardFundingCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [REWARD_FUNDING_PREFIX.as_ref(), pool.key().as_ref(), reward_index.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + RewardFunding::INIT_SPACE
+    )]
+    pub reward_funding: AccountLoader<'info, RewardFunding>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EnablePermissionlessRewardFundingCtx<'info> {
+    fn validate(&self, reward_index: usize) -> Result<()> {
+        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+
+        let pool = self.pool.load()?;
+        let reward_info = &pool.reward_infos[reward_index];
+        require!(reward_info.initialized(), PoolError::RewardUninitialized);
+
+        let clock = Clock::get()?;
+        let current_point = reward_info.get_current_point(
+            pool.activation_type,
+            clock.unix_timestamp as u64,
+            clock.slot,
+        );
+        // ineligible reward of previous fundings would be shared with new funders
+        require!(
+            reward_info.reward_duration_end < current_point,
+            PoolError::RewardCampaignInProgress
+        );
+        require!(
+            reward_info.cumulative_seconds_with_empty_liquidity_reward == 0
+                && reward_info.cumulative_ineligible_reward == 0,
+            PoolError::MustWithdrawnIneligibleReward
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handle_enable_permissionless_reward_funding<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, EnablePermissionlessRewardFundingCtx<'info>>,
+    reward_index: u8,
+) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    // finish accounting of previous reward duration before checking ineligible reward
+    {
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        let clock = Clock::get()?;
+        pool.update_rewards(clock.unix_timestamp as u64, clock.slot)?;
+    }
+
+    ctx.accounts.validate(index)?;
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdateRewardFunder,
+        )?;
+    }
+
+    let mut reward_funding = ctx.accounts.reward_funding.load_init()?;
+    reward_funding.initialize(ctx.accounts.pool.key(), reward_index);
+
+    pool.reward_infos[index].funding_mode = RewardFundingMode::Permissionless.into();
+
+    emit_cpi!(EvtEnablePermissionlessRewardFunding {
+        pool: ctx.accounts.pool.key(),
+        reward_index,
+        reward_funding: ctx.accounts.reward_funding.key(),
+    });
+
+    Ok(())
+}
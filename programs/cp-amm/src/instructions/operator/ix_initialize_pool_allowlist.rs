@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    activation_handler::ActivationHandler,
+    constants::seeds::POOL_ALLOWLIST_PREFIX,
+    event::EvtInitializePoolAllowlist,
+    is_valid_remaining_operator_role,
+    state::{OperatorPermission, Pool, PoolAllowlist},
+    PoolError,
+};
+
+/// Make the pool permissioned: only wallets in the allowlist can swap or provide liquidity
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePoolAllowlistCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [POOL_ALLOWLIST_PREFIX.as_ref(), pool.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + PoolAllowlist::INIT_SPACE
+    )]
+    pub pool_allowlist: AccountLoader<'info, PoolAllowlist>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_pool_allowlist<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializePoolAllowlistCtx<'info>>,
+    authority: Pubkey,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(!pool.is_permissioned(), PoolError::PoolAlreadyPermissioned);
+
+    // pool creator can only restrict the pool before traders can swap
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    let is_creator_before_activation =
+        ctx.accounts.signer.key() == pool.creator && current_point < pool.activation_point;
+    if !is_creator_before_activation {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::SetPoolStatus,
+        )?;
+    }
+
+    // pool type is kept, permissioned pools can be of any pool type
+    pool.metrics.permissioned_flag = 1;
+
+    let mut pool_allowlist = ctx.accounts.pool_allowlist.load_init()?;
+    pool_allowlist.initialize(ctx.accounts.pool.key(), authority);
+
+    emit_cpi!(EvtInitializePoolAllowlist {
+        pool: ctx.accounts.pool.key(),
+        pool_allowlist: ctx.accounts.pool_allowlist.key(),
+        authority,
+        signer: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -4,11 +4,14 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::{
     const_pda,
     constants::{
-        seeds::REWARD_VAULT_PREFIX, MAX_REWARD_DURATION, MIN_REWARD_DURATION, NUM_REWARDS,
+        seeds::REWARD_VAULT_PREFIX, MAX_REWARD_DURATION, MIN_REWARD_DURATION, NUM_EXTENDED_REWARDS,
+        NUM_REWARDS,
     },
     error::PoolError,
     event::EvtInitializeReward,
-    state::{Operator, OperatorPermission, Pool},
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, OperatorPermission, Pool},
     token::{get_token_program_flags, is_supported_mint, is_token_badge_initialized},
 };
 
@@ -47,18 +50,16 @@ pub struct InitializeRewardCtx<'info> {
 
 impl<'info> InitializeRewardCtx<'info> {
     fn validate(&self, reward_index: usize, reward_duration: u64) -> Result<()> {
-        let pool = self.pool.load()?;
-
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
 
         require!(
             reward_duration >= MIN_REWARD_DURATION && reward_duration <= MAX_REWARD_DURATION,
             PoolError::InvalidRewardDuration
         );
 
-        let reward_info = &pool.reward_infos[reward_index];
-        require!(!reward_info.initialized(), PoolError::RewardInitialized);
-
         Ok(())
     }
 }
@@ -69,41 +70,55 @@ pub fn handle_initialize_reward<'c: 'info, 'info>(
     reward_duration: u64,
     funder: Pubkey,
 ) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    ctx.accounts.validate(index, reward_duration)?;
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
     if !is_supported_mint(&ctx.accounts.reward_mint)? {
         require!(
             is_token_badge_initialized(
                 ctx.accounts.reward_mint.key(),
-                ctx.remaining_accounts
+                remaining_accounts
                     .get(0)
                     .ok_or(PoolError::InvalidTokenBadge)?
             )?,
             PoolError::InvalidTokenBadge
         );
     }
-    let index: usize = reward_index
-        .try_into()
-        .map_err(|_| PoolError::TypeCastFailed)?;
-
-    ctx.accounts.validate(index, reward_duration)?;
 
     let mut pool = ctx.accounts.pool.load_mut()?;
 
     if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
-        let operator_account = ctx
-            .remaining_accounts
-            .get(1)
-            .ok_or_else(|| PoolError::MissingOperatorAccount)?;
-        let operator_loader: AccountLoader<'info, Operator> =
-            AccountLoader::try_from(operator_account)?;
-        let operator = operator_loader.load()?;
-        require!(
-            operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::InitializeReward),
-            PoolError::InvalidAuthority
-        )
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            1,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::InitializeReward,
+        )?;
     }
 
-    let reward_info = &mut pool.reward_infos[index];
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    require!(!reward_info.initialized(), PoolError::RewardInitialized);
 
     reward_info.init_reward(
         ctx.accounts.reward_mint.key(),
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::REWARD_EXTENSION_PREFIX,
+    event::EvtInitializeRewardExtension,
+    is_valid_remaining_operator_role,
+    state::{OperatorPermission, Pool, RewardExtension, REWARD_EXTENSION_ENABLED_FLAG},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeRewardExtensionCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [REWARD_EXTENSION_PREFIX.as_ref(), pool.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + RewardExtension::INIT_SPACE
+    )]
+    pub reward_extension: AccountLoader<'info, RewardExtension>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_reward_extension<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeRewardExtensionCtx<'info>>,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    // once enabled, every liquidity change of the pool requires reward extension accounts
+    if pool.creator != ctx.accounts.signer.key() {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::InitializeReward,
+        )?;
+    }
+
+    let mut reward_extension = ctx.accounts.reward_extension.load_init()?;
+    reward_extension.initialize(ctx.accounts.pool.key());
+
+    pool.reward_extension_flag |= REWARD_EXTENSION_ENABLED_FLAG;
+
+    emit_cpi!(EvtInitializeRewardExtension {
+        pool: ctx.accounts.pool.key(),
+        reward_extension: ctx.accounts.reward_extension.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::{ActivationHandler, ActivationType},
+    const_pda,
+    constants::{
+        seeds::{TRADING_REWARD_CAMPAIGN_PREFIX, TRADING_REWARD_VAULT_PREFIX},
+        MAX_REWARD_DURATION, MAX_REWARD_SLOT_DURATION,
+    },
+    error::PoolError,
+    event::EvtInitializeTradingRewardCampaign,
+    is_valid_remaining_operator_role,
+    safe_math::SafeMath,
+    state::{OperatorPermission, Pool, TradingRewardCampaign, TradingRewardPointMode},
+    token::{get_token_program_flags, is_supported_mint, is_token_badge_initialized},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TradingRewardCampaignParameters {
+    pub campaign_index: u64,
+    /// Campaign start, in pool activation type
+    pub start_point: u64,
+    /// Campaign end, in pool activation type
+    pub end_point: u64,
+    /// See TradingRewardPointMode
+    pub point_mode: u8,
+    pub funder: Pubkey,
+}
+
+impl TradingRewardCampaignParameters {
+    pub fn validate(&self, activation_type: u8, current_point: u64) -> Result<()> {
+        TradingRewardPointMode::try_from(self.point_mode)
+            .map_err(|_| PoolError::InvalidTradingRewardCampaign)?;
+
+        let activation_type = ActivationType::try_from(activation_type)
+            .map_err(|_| PoolError::InvalidActivationType)?;
+        let max_duration = match activation_type {
+            ActivationType::Slot => MAX_REWARD_SLOT_DURATION,
+            ActivationType::Timestamp => MAX_REWARD_DURATION,
+        };
+
+        require!(
+            self.start_point >= current_point
+                && self.end_point > self.start_point
+                && self.end_point - self.start_point <= max_duration,
+            PoolError::InvalidTradingRewardCampaign
+        );
+
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: TradingRewardCampaignParameters)]
+pub struct InitializeTradingRewardCampaignCtx<'info> {
+    /// CHECK: pool authority
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    #[account(
+        init,
+        seeds = [TRADING_REWARD_CAMPAIGN_PREFIX.as_ref(), pool.key().as_ref(), params.campaign_index.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + TradingRewardCampaign::INIT_SPACE
+    )]
+    pub campaign: AccountLoader<'info, TradingRewardCampaign>,
+
+    #[account(
+        init,
+        seeds = [TRADING_REWARD_VAULT_PREFIX.as_ref(), campaign.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = reward_mint,
+        token::authority = pool_authority
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_trading_reward_campaign<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeTradingRewardCampaignCtx<'info>>,
+    params: TradingRewardCampaignParameters,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    let current_point = ActivationHandler::get_current_point(pool.activation_type)?;
+    params.validate(pool.activation_type, current_point)?;
+
+    if !is_supported_mint(&ctx.accounts.reward_mint)? {
+        require!(
+            is_token_badge_initialized(
+                ctx.accounts.reward_mint.key(),
+                ctx.remaining_accounts
+                    .get(0)
+                    .ok_or(PoolError::InvalidTokenBadge)?
+            )?,
+            PoolError::InvalidTokenBadge
+        );
+    }
+
+    if ctx.accounts.signer.key() != pool.creator {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            1,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::InitializeReward,
+        )?;
+    }
+
+    let TradingRewardCampaignParameters {
+        campaign_index,
+        start_point,
+        end_point,
+        point_mode,
+        funder,
+    } = params;
+
+    pool.metrics.number_of_trading_reward_campaigns = pool
+        .metrics
+        .number_of_trading_reward_campaigns
+        .safe_add(1)?;
+
+    let mut campaign = ctx.accounts.campaign.load_init()?;
+    campaign.initialize(
+        ctx.accounts.pool.key(),
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_vault.key(),
+        funder,
+        campaign_index,
+        start_point,
+        end_point,
+        point_mode,
+        get_token_program_flags(&ctx.accounts.reward_mint).into(),
+    );
+
+    emit_cpi!(EvtInitializeTradingRewardCampaign {
+        pool: ctx.accounts.pool.key(),
+        campaign: ctx.accounts.campaign.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+        funder,
+        creator: ctx.accounts.signer.key(),
+        campaign_index,
+        start_point,
+        end_point,
+        point_mode,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event,
+    state::{Config, Operator},
+    StaticConfigParameters,
+};
+
+/// Update parameters of a static config, only pools created with the config afterward use the new parameters
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfigCtx<'info> {
+    #[account(mut)]
+    pub config: AccountLoader<'info, Config>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_config(
+    ctx: Context<UpdateConfigCtx>,
+    config_parameters: StaticConfigParameters,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    config_parameters.validate(
+        config.has_alpha_vault_with_vault_config_key(config_parameters.vault_config_key),
+    )?;
+
+    let old_config_parameters = config.to_static_config_parameters()?;
+    config.update_static_config(&config_parameters)?;
+
+    emit_cpi!(event::EvtUpdateConfig {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.signer.key(),
+        version: config.version,
+        old_config_parameters,
+        new_config_parameters: config_parameters,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    event,
+    state::{Config, Operator, PreActivationBuyerMode},
+    PoolError,
+};
+
+/// Set who can swap in the pre-activation window of pools created with the config afterward
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfigPreActivationBuyerCtx<'info> {
+    #[account(mut)]
+    pub config: AccountLoader<'info, Config>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_update_config_pre_activation_buyer(
+    ctx: Context<UpdateConfigPreActivationBuyerCtx>,
+    mode: u8,
+    pre_activation_buyer: Pubkey,
+) -> Result<()> {
+    let mode =
+        PreActivationBuyerMode::try_from(mode).map_err(|_| PoolError::InvalidPreActivationBuyer)?;
+
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.update_pre_activation_buyer(mode, pre_activation_buyer)?;
+
+    emit_cpi!(event::EvtUpdateConfigPreActivationBuyer {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.signer.key(),
+        mode: mode.into(),
+        pre_activation_buyer,
+    });
+
+    Ok(())
+}
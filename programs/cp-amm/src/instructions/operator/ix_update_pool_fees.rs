@@ -53,7 +53,7 @@ impl UpdatePoolFeesParameters {
             DynamicFeeUpdateMode::Skip
         }
     }
-    fn validate(&self) -> Result<()> {
+    pub fn validate(&self) -> Result<()> {
         // We don't need to validate `cliff_fee_numerator` in case we update it.
         // Because after update pool fee we will validate pool fee with new updated parameters
         require!(
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    event::EvtUpdatePoolPriceRange,
+    is_valid_remaining_operator_role,
+    state::{OperatorPermission, Pool, PoolType},
+    token::{calculate_transfer_fee_included_amount, transfer_from_user},
+    PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct UpdatePoolPriceRangeParameters {
+    /// new sqrt min price
+    pub sqrt_min_price: u128,
+    /// new sqrt max price
+    pub sqrt_max_price: u128,
+}
+
+/// Adjust price range of a customizable pool. Extra reserve needed by widened bounds is deposited by the signer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoolPriceRangeCtx<'info> {
+    #[account(mut, has_one = token_a_vault, has_one = token_b_vault, has_one = token_a_mint, has_one = token_b_mint)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    /// The signer token a account
+    #[account(mut)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The signer token b account
+    #[account(mut)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token a
+    #[account(mut, token::token_program = token_a_program, token::mint = token_a_mint)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token b
+    #[account(mut, token::token_program = token_b_program, token::mint = token_b_mint)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token a
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token b
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub signer: Signer<'info>,
+
+    /// Token a program
+    pub token_a_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_b_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_update_pool_price_range<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdatePoolPriceRangeCtx<'info>>,
+    params: UpdatePoolPriceRangeParameters,
+) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    // price range of config pools is set by the config
+    require!(
+        pool.pool_type != u8::from(PoolType::Permissionless),
+        PoolError::InvalidPoolType
+    );
+
+    if ctx.accounts.signer.key() != pool.creator {
+        is_valid_remaining_operator_role(
+            ctx.remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdatePoolFees,
+        )?;
+    }
+
+    let previous_sqrt_min_price = pool.sqrt_min_price;
+    let previous_sqrt_max_price = pool.sqrt_max_price;
+
+    let deposit_amounts = pool.update_price_range(params.sqrt_min_price, params.sqrt_max_price)?;
+
+    let total_amount_a = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_a_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        deposit_amounts.token_a_amount,
+    )?
+    .amount;
+
+    let total_amount_b = calculate_transfer_fee_included_amount(
+        &ctx.accounts
+            .token_b_mint
+            .to_account_info()
+            .try_borrow_data()?,
+        deposit_amounts.token_b_amount,
+    )?
+    .amount;
+
+    if total_amount_a > 0 {
+        transfer_from_user(
+            &ctx.accounts.signer,
+            &ctx.accounts.token_a_mint,
+            &ctx.accounts.token_a_account,
+            &ctx.accounts.token_a_vault,
+            &ctx.accounts.token_a_program,
+            total_amount_a,
+        )?;
+    }
+
+    if total_amount_b > 0 {
+        transfer_from_user(
+            &ctx.accounts.signer,
+            &ctx.accounts.token_b_mint,
+            &ctx.accounts.token_b_account,
+            &ctx.accounts.token_b_vault,
+            &ctx.accounts.token_b_program,
+            total_amount_b,
+        )?;
+    }
+
+    emit_cpi!(EvtUpdatePoolPriceRange {
+        pool: ctx.accounts.pool.key(),
+        signer: ctx.accounts.signer.key(),
+        previous_sqrt_min_price,
+        previous_sqrt_max_price,
+        sqrt_min_price: params.sqrt_min_price,
+        sqrt_max_price: params.sqrt_max_price,
+        sqrt_price: pool.sqrt_price,
+        liquidity: pool.liquidity,
+        token_a_amount: deposit_amounts.token_a_amount,
+        token_b_amount: deposit_amounts.token_b_amount,
+        total_amount_a,
+        total_amount_b,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        fee::MAX_BASIS_POINT, MAX_REWARD_BOOST_MULTIPLIER_BPS, NUM_EXTENDED_REWARDS, NUM_REWARDS,
+    },
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, OperatorPermission, Pool, RewardInfo},
+    EvtUpdateRewardBoost, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRewardBoostCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub signer: Signer<'info>,
+}
+
+impl<'info> UpdateRewardBoostCtx<'info> {
+    fn validate(&self, reward_index: usize, new_boost_multiplier_bps: u16) -> Result<()> {
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
+
+        // 0 disables boost, otherwise locked liquidity weight must be in [1x, max]
+        let new_boost_multiplier_bps = u64::from(new_boost_multiplier_bps);
+        require!(
+            new_boost_multiplier_bps == 0
+                || (new_boost_multiplier_bps >= MAX_BASIS_POINT
+                    && new_boost_multiplier_bps <= MAX_REWARD_BOOST_MULTIPLIER_BPS.into()),
+            PoolError::InvalidRewardBoostMultiplier
+        );
+
+        Ok(())
+    }
+
+    fn validate_reward_info(&self, reward_info: &RewardInfo) -> Result<()> {
+        require!(reward_info.initialized(), PoolError::RewardUninitialized);
+
+        // boosted supply is only tracked from the first funding, so boost can't change afterward
+        require!(
+            reward_info.reward_duration_end == 0 && reward_info.reward_rate == 0,
+            PoolError::RewardAlreadyFunded
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handle_update_reward_boost<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateRewardBoostCtx<'info>>,
+    reward_index: u8,
+    new_boost_multiplier_bps: u16,
+) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    ctx.accounts.validate(index, new_boost_multiplier_bps)?;
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::InitializeReward,
+        )?;
+    }
+
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts.validate_reward_info(reward_info)?;
+
+    let old_boost_multiplier_bps = reward_info.boost_multiplier_bps;
+    reward_info.boost_multiplier_bps = new_boost_multiplier_bps;
+
+    // locked liquidity changes must update extended rewards while one of them is boosted
+    if let Some(reward_extension) = reward_extension.as_ref() {
+        pool.set_boosted_extended_reward(reward_extension.has_boosted_reward());
+    }
+
+    emit_cpi!(EvtUpdateRewardBoost {
+        pool: ctx.accounts.pool.key(),
+        reward_index,
+        old_boost_multiplier_bps,
+        new_boost_multiplier_bps,
+    });
+
+    Ok(())
+}
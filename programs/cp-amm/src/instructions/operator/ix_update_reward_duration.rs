@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{MAX_REWARD_DURATION, MIN_REWARD_DURATION, NUM_REWARDS},
-    state::{Operator, OperatorPermission, Pool},
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, OperatorPermission, Pool, RewardInfo},
     EvtUpdateRewardDuration, PoolError,
 };
 
@@ -16,28 +18,35 @@ pub struct UpdateRewardDurationCtx<'info> {
 }
 
 impl<'info> UpdateRewardDurationCtx<'info> {
-    fn validate(&self, reward_index: usize, new_reward_duration: u64) -> Result<()> {
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
+    fn validate_reward_info(
+        &self,
+        reward_info: &RewardInfo,
+        activation_type: u8,
+        new_reward_duration: u64,
+        current_timestamp: u64,
+        current_slot: u64,
+    ) -> Result<()> {
+        require!(reward_info.initialized(), PoolError::RewardInitialized);
 
+        // reward duration is in slots for rewards following slot activated pools
+        let (min_reward_duration, max_reward_duration) =
+            reward_info.get_reward_duration_range(activation_type);
         require!(
-            new_reward_duration >= MIN_REWARD_DURATION
-                && new_reward_duration <= MAX_REWARD_DURATION,
+            new_reward_duration >= min_reward_duration
+                && new_reward_duration <= max_reward_duration,
             PoolError::InvalidRewardDuration
         );
 
-        let pool = self.pool.load()?;
-        let reward_info = &pool.reward_infos[reward_index];
-        require!(reward_info.initialized(), PoolError::RewardInitialized);
-
         require!(
             reward_info.reward_duration != new_reward_duration,
             PoolError::IdenticalRewardDuration
         );
 
-        let current_time = Clock::get()?.unix_timestamp;
+        let current_point =
+            reward_info.get_current_point(activation_type, current_timestamp, current_slot);
         // only allow update reward duration if previous reward has been finished
         require!(
-            reward_info.reward_duration_end < (current_time as u64),
+            reward_info.reward_duration_end < current_point,
             PoolError::RewardCampaignInProgress
         );
 
@@ -53,27 +62,57 @@ pub fn handle_update_reward_duration<'c: 'info, 'info>(
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-
-    ctx.accounts.validate(index, new_reward_duration)?;
+    require!(
+        index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+        PoolError::InvalidRewardIndex
+    );
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
 
     let mut pool = ctx.accounts.pool.load_mut()?;
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+
+    // finish accounting of previous reward duration before it changes, non constant emission schedules depend on it
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp as u64;
+    pool.update_rewards(current_timestamp, clock.slot)?;
+    if let Some(reward_extension) = reward_extension.as_mut() {
+        reward_extension.update_rewards(&pool, current_timestamp, clock.slot)?;
+    }
 
     if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
-        let operator_account = ctx
-            .remaining_accounts
-            .get(0)
-            .ok_or_else(|| PoolError::MissingOperatorAccount)?;
-        let operator_loader: AccountLoader<'info, Operator> =
-            AccountLoader::try_from(operator_account)?;
-        let operator = operator_loader.load()?;
-        require!(
-            operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::UpdateRewardDuration),
-            PoolError::InvalidAuthority
-        )
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdateRewardDuration,
+        )?;
     }
 
-    let reward_info = &mut pool.reward_infos[index];
+    let activation_type = pool.activation_type;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts.validate_reward_info(
+        reward_info,
+        activation_type,
+        new_reward_duration,
+        current_timestamp,
+        clock.slot,
+    )?;
 
     let old_reward_duration = reward_info.reward_duration;
     reward_info.reward_duration = new_reward_duration;
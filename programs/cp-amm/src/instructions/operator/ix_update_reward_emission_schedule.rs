@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        MAX_FRONT_LOADED_EMISSION_PERCENTAGE, MAX_REWARD_EMISSION_STEPS,
+        MIN_FRONT_LOADED_EMISSION_PERCENTAGE, MIN_REWARD_EMISSION_STEPS, NUM_EXTENDED_REWARDS,
+        NUM_REWARDS,
+    },
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{
+        is_extended_reward_index, OperatorPermission, Pool, RewardEmissionSchedule, RewardInfo,
+    },
+    EvtUpdateRewardEmissionSchedule, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRewardEmissionScheduleCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub signer: Signer<'info>,
+}
+
+impl<'info> UpdateRewardEmissionScheduleCtx<'info> {
+    fn validate(
+        &self,
+        reward_index: usize,
+        emission_schedule: u8,
+        emission_parameter: u8,
+    ) -> Result<()> {
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
+
+        let emission_schedule = RewardEmissionSchedule::try_from(emission_schedule)
+            .map_err(|_| PoolError::InvalidRewardEmissionSchedule)?;
+        let is_valid_parameter = match emission_schedule {
+            RewardEmissionSchedule::Constant | RewardEmissionSchedule::LinearDecay => {
+                emission_parameter == 0
+            }
+            RewardEmissionSchedule::Halving => {
+                emission_parameter >= MIN_REWARD_EMISSION_STEPS
+                    && emission_parameter <= MAX_REWARD_EMISSION_STEPS
+            }
+            RewardEmissionSchedule::FrontLoaded => {
+                emission_parameter >= MIN_FRONT_LOADED_EMISSION_PERCENTAGE
+                    && emission_parameter <= MAX_FRONT_LOADED_EMISSION_PERCENTAGE
+            }
+        };
+        require!(is_valid_parameter, PoolError::InvalidRewardEmissionSchedule);
+
+        Ok(())
+    }
+
+    fn validate_reward_info(
+        &self,
+        reward_info: &RewardInfo,
+        activation_type: u8,
+        current_timestamp: u64,
+        current_slot: u64,
+    ) -> Result<()> {
+        require!(reward_info.initialized(), PoolError::RewardUninitialized);
+
+        let current_point =
+            reward_info.get_current_point(activation_type, current_timestamp, current_slot);
+        // only allow update emission schedule if previous reward has been finished
+        require!(
+            reward_info.reward_duration_end < current_point,
+            PoolError::RewardCampaignInProgress
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handle_update_reward_emission_schedule<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateRewardEmissionScheduleCtx<'info>>,
+    reward_index: u8,
+    emission_schedule: u8,
+    emission_parameter: u8,
+) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    ctx.accounts
+        .validate(index, emission_schedule, emission_parameter)?;
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdateRewardDuration,
+        )?;
+    }
+
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+
+    // finish accounting of previous reward duration with the old emission schedule
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp as u64;
+    pool.update_rewards(current_timestamp, clock.slot)?;
+    if let Some(reward_extension) = reward_extension.as_mut() {
+        reward_extension.update_rewards(&pool, current_timestamp, clock.slot)?;
+    }
+
+    let activation_type = pool.activation_type;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts.validate_reward_info(
+        reward_info,
+        activation_type,
+        current_timestamp,
+        clock.slot,
+    )?;
+
+    let old_emission_schedule = reward_info.emission_schedule;
+    let old_emission_parameter = reward_info.emission_parameter;
+    reward_info.emission_schedule = emission_schedule;
+    reward_info.emission_parameter = emission_parameter;
+
+    emit_cpi!(EvtUpdateRewardEmissionSchedule {
+        pool: ctx.accounts.pool.key(),
+        reward_index,
+        old_emission_schedule,
+        old_emission_parameter,
+        new_emission_schedule: emission_schedule,
+        new_emission_parameter: emission_parameter,
+    });
+
+    Ok(())
+}
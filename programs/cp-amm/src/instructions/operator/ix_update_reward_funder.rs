@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::NUM_REWARDS,
-    state::{Operator, OperatorPermission, Pool},
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, OperatorPermission, Pool, RewardInfo},
     EvtUpdateRewardFunder, PoolError,
 };
 
@@ -16,12 +18,7 @@ pub struct UpdateRewardFunderCtx<'info> {
 }
 
 impl<'info> UpdateRewardFunderCtx<'info> {
-    fn validate(&self, reward_index: usize, new_funder: Pubkey) -> Result<()> {
-        require!(reward_index < NUM_REWARDS, PoolError::InvalidRewardIndex);
-
-        let pool = self.pool.load()?;
-        let reward_info = &pool.reward_infos[reward_index];
-
+    fn validate_reward_info(&self, reward_info: &RewardInfo, new_funder: Pubkey) -> Result<()> {
         require!(reward_info.initialized(), PoolError::RewardUninitialized);
 
         require!(reward_info.funder != new_funder, PoolError::IdenticalFunder);
@@ -38,26 +35,42 @@ pub fn handle_update_reward_funder<'c: 'info, 'info>(
     let index: usize = reward_index
         .try_into()
         .map_err(|_| PoolError::TypeCastFailed)?;
-    ctx.accounts.validate(index, new_funder)?;
+    require!(
+        index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+        PoolError::InvalidRewardIndex
+    );
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
 
     let mut pool = ctx.accounts.pool.load_mut()?;
 
     if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
-        let operator_account = ctx
-            .remaining_accounts
-            .get(0)
-            .ok_or_else(|| PoolError::MissingOperatorAccount)?;
-        let operator_loader: AccountLoader<'info, Operator> =
-            AccountLoader::try_from(operator_account)?;
-        let operator = operator_loader.load()?;
-        require!(
-            operator.whitelisted_address.eq(&ctx.accounts.signer.key())
-                && operator.is_permission_allow(OperatorPermission::UpdateRewardFunder),
-            PoolError::InvalidAuthority
-        )
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdateRewardFunder,
+        )?;
     }
 
-    let reward_info = &mut pool.reward_infos[index];
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts.validate_reward_info(reward_info, new_funder)?;
 
     let old_funder = reward_info.funder;
     reward_info.funder = new_funder;
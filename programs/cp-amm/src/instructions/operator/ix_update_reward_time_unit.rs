@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    is_valid_remaining_operator_role,
+    reward_extension::load_reward_extension,
+    state::{is_extended_reward_index, OperatorPermission, Pool, RewardInfo, RewardTimeUnit},
+    EvtUpdateRewardTimeUnit, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRewardTimeUnitCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub signer: Signer<'info>,
+}
+
+impl<'info> UpdateRewardTimeUnitCtx<'info> {
+    fn validate(&self, reward_index: usize, time_unit: u8) -> Result<()> {
+        require!(
+            reward_index < NUM_REWARDS + NUM_EXTENDED_REWARDS,
+            PoolError::InvalidRewardIndex
+        );
+
+        RewardTimeUnit::try_from(time_unit).map_err(|_| PoolError::InvalidRewardTimeUnit)?;
+
+        Ok(())
+    }
+
+    fn validate_reward_info(
+        &self,
+        reward_info: &RewardInfo,
+        activation_type: u8,
+        time_unit: u8,
+        reward_duration: u64,
+    ) -> Result<()> {
+        require!(reward_info.initialized(), PoolError::RewardUninitialized);
+
+        // reward duration end and last update time are in the time unit since the first funding
+        require!(
+            reward_info.reward_duration_end == 0 && reward_info.reward_rate == 0,
+            PoolError::RewardAlreadyFunded
+        );
+
+        // reward duration must be valid in the new time unit
+        let mut new_reward_info = *reward_info;
+        new_reward_info.time_unit = time_unit;
+        let (min_reward_duration, max_reward_duration) =
+            new_reward_info.get_reward_duration_range(activation_type);
+        require!(
+            reward_duration >= min_reward_duration && reward_duration <= max_reward_duration,
+            PoolError::InvalidRewardDuration
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handle_update_reward_time_unit<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateRewardTimeUnitCtx<'info>>,
+    reward_index: u8,
+    time_unit: u8,
+    reward_duration: u64,
+) -> Result<()> {
+    let index: usize = reward_index
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    ctx.accounts.validate(index, time_unit)?;
+
+    // extended reward index: pool reward extension goes first in remaining accounts
+    let mut remaining_accounts = &ctx.remaining_accounts[..];
+    let reward_extension_loader = if is_extended_reward_index(index) {
+        Some(load_reward_extension(
+            &mut remaining_accounts,
+            ctx.accounts.pool.key(),
+        )?)
+    } else {
+        None
+    };
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+
+    if !pool.check_pool_creator_to_edit_reward(index, ctx.accounts.signer.key()) {
+        is_valid_remaining_operator_role(
+            remaining_accounts,
+            0,
+            &ctx.accounts.signer.key(),
+            OperatorPermission::UpdateRewardDuration,
+        )?;
+    }
+
+    let mut reward_extension = reward_extension_loader
+        .as_ref()
+        .map(|loader| loader.load_mut())
+        .transpose()?;
+    let activation_type = pool.activation_type;
+    let reward_info = match reward_extension.as_mut() {
+        Some(reward_extension) => reward_extension.get_reward_info_mut(index)?,
+        None => &mut pool.reward_infos[index],
+    };
+    ctx.accounts
+        .validate_reward_info(reward_info, activation_type, time_unit, reward_duration)?;
+
+    let old_time_unit = reward_info.time_unit;
+    reward_info.time_unit = time_unit;
+    reward_info.reward_duration = reward_duration;
+
+    emit_cpi!(EvtUpdateRewardTimeUnit {
+        pool: ctx.accounts.pool.key(),
+        reward_index,
+        old_time_unit,
+        new_time_unit: time_unit,
+        reward_duration,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Operator, Pool},
+    EvtUpgradePoolVersion, UpdatePoolFeesParameters,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct UpgradePoolVersionParameters {
+    /// Version to migrate the pool to, must be newer than the pool version
+    pub target_version: u8,
+    /// Optional pool fees update, validated with max fee of the target version
+    pub pool_fees: Option<UpdatePoolFeesParameters>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpgradePoolVersionCtx<'info> {
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+
+    pub operator: AccountLoader<'info, Operator>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_upgrade_pool_version(
+    ctx: Context<UpgradePoolVersionCtx>,
+    params: UpgradePoolVersionParameters,
+) -> Result<()> {
+    let UpgradePoolVersionParameters {
+        target_version,
+        pool_fees,
+    } = params;
+
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let previous_version = pool.version;
+
+    pool.upgrade_version(target_version)?;
+
+    if let Some(pool_fees) = pool_fees.as_ref() {
+        pool_fees.validate()?;
+        pool.validate_and_update_pool_fees(pool_fees)?;
+    }
+
+    emit_cpi!(EvtUpgradePoolVersion {
+        pool: ctx.accounts.pool.key(),
+        operator: ctx.accounts.signer.key(),
+        previous_version,
+        version: pool.version,
+        pool_fees,
+    });
+
+    Ok(())
+}
@@ -4,6 +4,10 @@ pub mod ix_create_dynamic_config;
 pub use ix_create_dynamic_config::*;
 pub mod ix_close_config;
 pub use ix_close_config::*;
+pub mod ix_update_config_pre_activation_buyer;
+pub use ix_update_config_pre_activation_buyer::*;
+pub mod ix_update_config;
+pub use ix_update_config::*;
 pub mod ix_create_token_badge;
 pub use ix_create_token_badge::*;
 pub mod ix_claim_protocol_fee;
@@ -12,13 +16,33 @@ pub mod ix_set_pool_status;
 pub use ix_set_pool_status::*;
 pub mod ix_initialize_reward;
 pub use ix_initialize_reward::*;
+pub mod ix_initialize_reward_extension;
+pub use ix_initialize_reward_extension::*;
 pub mod ix_update_reward_funder;
 pub use ix_update_reward_funder::*;
+pub mod ix_enable_permissionless_reward_funding;
+pub use ix_enable_permissionless_reward_funding::*;
 pub mod ix_update_reward_duration;
 pub use ix_update_reward_duration::*;
+pub mod ix_update_reward_boost;
+pub use ix_update_reward_boost::*;
+pub mod ix_update_reward_emission_schedule;
+pub use ix_update_reward_emission_schedule::*;
+pub mod ix_update_reward_time_unit;
+pub use ix_update_reward_time_unit::*;
+pub mod ix_initialize_trading_reward_campaign;
+pub use ix_initialize_trading_reward_campaign::*;
+pub mod ix_close_trading_reward_campaign;
+pub use ix_close_trading_reward_campaign::*;
+pub mod ix_initialize_pool_allowlist;
+pub use ix_initialize_pool_allowlist::*;
 pub mod ix_close_token_badge;
 pub use ix_close_token_badge::*;
 pub mod ix_update_pool_fees;
 pub use ix_update_pool_fees::*;
+pub mod ix_upgrade_pool_version;
+pub use ix_upgrade_pool_version::*;
+pub mod ix_update_pool_price_range;
+pub use ix_update_pool_price_range::*;
 pub mod zap_protocol_fee;
 pub use zap_protocol_fee::*;
@@ -6,8 +6,9 @@ use crate::token::{get_token_program_from_flag, validate_ata_token};
 use crate::{
     const_pda,
     constants::treasury as TREASURY,
-    state::{Operator, Pool},
+    state::{Operator, PauseAction, Pool},
     token::transfer_from_pool,
+    validate_action_not_paused,
 };
 use crate::{get_zap_amm_processor, PoolError, RawZapOutAmmInfo};
 use anchor_lang::prelude::*;
@@ -93,6 +94,8 @@ fn validate_accounts_and_return_withdraw_direction<'info>(
 // 1. If the token mint is SOL or USDC, then must withdraw to treasury using `claim_protocol_fee` endpoint. No zap out allowed.
 // 2. If the token mint is not SOL or USDC, operator require to zap out to SOL or USDC or either one of the token of the pool
 pub fn handle_zap_protocol_fee(ctx: Context<ZapProtocolFee>, max_amount: u64) -> Result<()> {
+    validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let mut pool = ctx.accounts.pool.load_mut()?;
     let is_withdrawing_a = validate_accounts_and_return_withdraw_direction(
         &pool,
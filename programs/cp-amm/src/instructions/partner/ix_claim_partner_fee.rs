@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{const_pda, state::Pool, token::transfer_from_pool, EvtClaimPartnerFee};
+use crate::{
+    const_pda,
+    state::{PauseAction, Pool},
+    token::transfer_from_pool,
+    validate_action_not_paused, EvtClaimPartnerFee,
+};
 
 /// Accounts for partner to claim fees
 #[event_cpi]
@@ -58,6 +63,8 @@ pub fn handle_claim_partner_fee(
     max_amount_a: u64,
     max_amount_b: u64,
 ) -> Result<()> {
+    validate_action_not_paused(ctx.remaining_accounts, PauseAction::Claim)?;
+
     let mut pool = ctx.accounts.pool.load_mut()?;
     let (token_a_amount, token_b_amount) = pool.claim_partner_fee(max_amount_a, max_amount_b)?;
 
@@ -1,10 +1,12 @@
-use crate::const_pda::{EVENT_AUTHORITY_AND_BUMP, EVENT_AUTHORITY_SEEDS};
+use crate::const_pda::{self, EVENT_AUTHORITY_AND_BUMP, EVENT_AUTHORITY_SEEDS};
 use crate::constants::RATE_LIMITER_STACK_WHITELIST_PROGRAMS;
 use crate::p_helper::{
-    p_accessor_mint, p_get_number_of_accounts_in_instruction, p_load_mut_unchecked,
-    p_transfer_from_pool, p_transfer_from_user,
+    p_accessor_mint, p_get_number_of_accounts_in_instruction, p_load_checked, p_load_mut_checked,
+    p_load_mut_unchecked, p_transfer_from_pool, p_transfer_from_user,
+};
+use crate::state::{
+    AllowlistEntry, GlobalPause, PauseAction, SwapResult2, TraderReward, TradingRewardCampaign,
 };
-use crate::state::SwapResult2;
 use crate::{instruction::Swap as SwapInstruction, instruction::Swap2 as Swap2Instruction};
 use crate::{
     process_swap_exact_in, process_swap_exact_out, process_swap_partial_fill, EvtSwap2,
@@ -75,11 +77,45 @@ pub fn p_handle_swap(
         return Err(ProgramError::NotEnoughAccountKeys.into());
     };
 
+    // global pause is optional and goes first in remaining accounts, swap is open when it is not created yet
+    let remaining_accounts = match remaining_accounts.split_first() {
+        Some((global_pause, rest))
+            if global_pause.key() == const_pda::global_pause::ID.as_array() =>
+        {
+            if global_pause.owner() == crate::ID.as_array() {
+                let global_pause: pinocchio::account_info::Ref<'_, GlobalPause> =
+                    p_load_checked(global_pause)?;
+                require!(
+                    !global_pause.is_action_paused(PauseAction::Swap),
+                    PoolError::ActionPaused
+                );
+            }
+            rest
+        }
+        _ => remaining_accounts,
+    };
+
     let pool_key = pool.key();
     let mut pool: pinocchio::account_info::RefMut<'_, Pool> = p_load_mut_unchecked(pool)?;
 
+    // permissioned pools: allowlist entry of the payer goes first in remaining accounts
+    let (allowlist_entry, remaining_accounts) = if pool.is_permissioned() {
+        let (allowlist_entry, remaining_accounts) = remaining_accounts
+            .split_first()
+            .ok_or(PoolError::MissingAllowlistEntry)?;
+        let allowlist_entry: pinocchio::account_info::Ref<'_, AllowlistEntry> =
+            p_load_checked(allowlist_entry)?;
+        require!(
+            allowlist_entry.pool.as_array() == pool_key,
+            PoolError::InvalidAllowlistEntry
+        );
+        (Some(*allowlist_entry), remaining_accounts)
+    } else {
+        (None, remaining_accounts)
+    };
+
     {
-        let access_validator = get_pool_access_validator(&pool)?;
+        let access_validator = get_pool_access_validator(&pool, allowlist_entry.as_ref())?;
         require!(
             access_validator.can_swap(&Pubkey::new_from_array(*payer.key())),
             PoolError::PoolDisabled
@@ -143,6 +179,9 @@ pub fn p_handle_swap(
         }
     }
 
+    // move sqrt max price down the liquidity bootstrapping schedule before quoting
+    pool.update_lbp_price_bound(current_point)?;
+
     // update for dynamic fee reference
     let current_timestamp = Clock::get()?.unix_timestamp as u64;
     pool.update_pre_swap(current_timestamp)?;
@@ -173,6 +212,28 @@ pub fn p_handle_swap(
 
     pool.apply_swap_result(&swap_result, &fee_mode, current_timestamp)?;
 
+    // trading reward campaign and trader reward accounts follow the instruction sysvar account in remaining accounts
+    let trading_reward_accounts = match remaining_accounts.first() {
+        Some(account) if account.key() == &INSTRUCTIONS_ID => &remaining_accounts[1..],
+        _ => remaining_accounts,
+    };
+    // opt in, accounts passed there for other purposes are skipped
+    if let [campaign, trader_reward, ..] = trading_reward_accounts {
+        if p_is_trading_reward_campaign(campaign) {
+            p_accumulate_trading_reward_points(
+                pool_key,
+                payer,
+                campaign,
+                trader_reward,
+                &swap_result,
+                &fee_mode,
+                trade_direction,
+                pool.sqrt_price,
+                current_point,
+            )?;
+        }
+    }
+
     let SwapResult2 { referral_fee, .. } = swap_result;
 
     // send to reserve
@@ -244,6 +305,47 @@ pub fn p_handle_swap(
     Ok(())
 }
 
+fn p_is_trading_reward_campaign(account: &AccountInfo) -> bool {
+    account.owner() == crate::ID.as_array()
+        && account
+            .try_borrow_data()
+            .is_ok_and(|data| data.starts_with(TradingRewardCampaign::DISCRIMINATOR))
+}
+
+/// Accrue points of the swap to the trader reward account of the payer, while the campaign is active
+#[allow(clippy::too_many_arguments)]
+fn p_accumulate_trading_reward_points(
+    pool_key: &pinocchio::pubkey::Pubkey,
+    payer: &AccountInfo,
+    campaign: &AccountInfo,
+    trader_reward: &AccountInfo,
+    swap_result: &SwapResult2,
+    fee_mode: &FeeMode,
+    trade_direction: TradeDirection,
+    sqrt_price: u128,
+    current_point: u64,
+) -> Result<()> {
+    let campaign_key = campaign.key();
+    let mut campaign: pinocchio::account_info::RefMut<'_, TradingRewardCampaign> =
+        p_load_mut_checked(campaign)?;
+    let mut trader_reward: pinocchio::account_info::RefMut<'_, TraderReward> =
+        p_load_mut_checked(trader_reward)?;
+
+    require!(
+        campaign.pool.as_array() == pool_key
+            && trader_reward.campaign.as_array() == campaign_key
+            && trader_reward.trader.as_array() == payer.key(),
+        PoolError::InvalidTradingRewardAccounts
+    );
+
+    if !campaign.is_active(current_point) {
+        return Ok(());
+    }
+
+    let points = campaign.get_swap_points(swap_result, fee_mode, trade_direction, sqrt_price)?;
+    campaign.accumulate_points(&mut trader_reward, points)
+}
+
 fn p_emit_cpi(inner_data: Vec<u8>, authority_info: &AccountInfo) -> pinocchio::ProgramResult {
     let disc = anchor_lang::event::EVENT_IX_TAG_LE;
     let ix_data: Vec<u8> = disc
@@ -65,7 +65,34 @@ pub mod cp_amm {
     use super::*;
 
     /// ADMIN FUNCTIONS /////
-    #[access_control(is_admin(ctx.accounts.signer.key))]
+    #[access_control(is_predefined_admin(ctx.accounts.signer.key))]
+    pub fn initialize_admin_state(ctx: Context<InitializeAdminStateCtx>) -> Result<()> {
+        instructions::handle_initialize_admin_state(ctx)
+    }
+
+    // admin co-signers are passed as signer remaining accounts when admin threshold is more than 1
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn propose_admin_rotation(
+        ctx: Context<ProposeAdminRotationCtx>,
+        old_admin: Pubkey,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_propose_admin_rotation(ctx, old_admin, new_admin)
+    }
+
+    pub fn accept_admin_rotation(ctx: Context<AcceptAdminRotationCtx>) -> Result<()> {
+        instructions::handle_accept_admin_rotation(ctx)
+    }
+
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn update_admin_threshold(
+        ctx: Context<UpdateAdminThresholdCtx>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::handle_update_admin_threshold(ctx, threshold)
+    }
+
+    #[access_control(is_admin_or_predefined_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
     pub fn create_operator_account(
         ctx: Context<CreateOperatorAccountCtx>,
         permission: u128,
@@ -73,11 +100,38 @@ pub mod cp_amm {
         instructions::handle_create_operator(ctx, permission)
     }
 
-    #[access_control(is_admin(ctx.accounts.signer.key))]
+    #[access_control(is_admin_or_predefined_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
     pub fn close_operator_account(ctx: Context<CloseOperatorAccountCtx>) -> Result<()> {
         Ok(())
     }
 
+    /// update permission bitmask and expiry of the operator, the role never expires when expires_at is None
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn update_operator_permission(
+        ctx: Context<UpdateOperatorPermissionCtx>,
+        permission: u128,
+        expires_at: Option<u64>,
+    ) -> Result<()> {
+        instructions::handle_update_operator_permission(ctx, permission, expires_at)
+    }
+
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn create_global_pause(ctx: Context<CreateGlobalPauseCtx>) -> Result<()> {
+        instructions::handle_create_global_pause(ctx)
+    }
+
+    /// pause actions of all pools, bit index of actions refers to PauseAction
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn pause_actions(ctx: Context<UpdateGlobalPauseCtx>, actions: u8) -> Result<()> {
+        instructions::handle_pause_actions(ctx, actions)
+    }
+
+    /// unpause actions of all pools, bit index of actions refers to PauseAction
+    #[access_control(is_admin(&ctx.accounts.admin_state, ctx.accounts.signer.key, ctx.remaining_accounts))]
+    pub fn unpause_actions(ctx: Context<UpdateGlobalPauseCtx>, actions: u8) -> Result<()> {
+        instructions::handle_unpause_actions(ctx, actions)
+    }
+
     /// OPERATOR FUNCTIONS /////
     // create static config
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateConfigKey))]
@@ -109,6 +163,23 @@ pub mod cp_amm {
         instructions::handle_close_config(ctx)
     }
 
+    #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateConfigKey))]
+    pub fn update_config_pre_activation_buyer(
+        ctx: Context<UpdateConfigPreActivationBuyerCtx>,
+        mode: u8,
+        pre_activation_buyer: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_update_config_pre_activation_buyer(ctx, mode, pre_activation_buyer)
+    }
+
+    #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CreateConfigKey))]
+    pub fn update_config(
+        ctx: Context<UpdateConfigCtx>,
+        config_parameters: StaticConfigParameters,
+    ) -> Result<()> {
+        instructions::handle_update_config(ctx, config_parameters)
+    }
+
     pub fn initialize_reward<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, InitializeRewardCtx<'info>>,
         reward_index: u8,
@@ -118,8 +189,20 @@ pub mod cp_amm {
         instructions::handle_initialize_reward(ctx, reward_index, reward_duration, funder)
     }
 
-    pub fn fund_reward(
-        ctx: Context<FundRewardCtx>,
+    pub fn initialize_reward_extension<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeRewardExtensionCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_initialize_reward_extension(ctx)
+    }
+
+    pub fn initialize_position_reward_extension(
+        ctx: Context<InitializePositionRewardExtensionCtx>,
+    ) -> Result<()> {
+        instructions::handle_initialize_position_reward_extension(ctx)
+    }
+
+    pub fn fund_reward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FundRewardCtx<'info>>,
         reward_index: u8,
         amount: u64,
         carry_forward: bool,
@@ -127,13 +210,87 @@ pub mod cp_amm {
         instructions::handle_fund_reward(ctx, reward_index, amount, carry_forward)
     }
 
-    pub fn withdraw_ineligible_reward(
-        ctx: Context<WithdrawIneligibleRewardCtx>,
+    pub fn withdraw_ineligible_reward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawIneligibleRewardCtx<'info>>,
         reward_index: u8,
     ) -> Result<()> {
         instructions::handle_withdraw_ineligible_reward(ctx, reward_index)
     }
 
+    pub fn enable_permissionless_reward_funding<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, EnablePermissionlessRewardFundingCtx<'info>>,
+        reward_index: u8,
+    ) -> Result<()> {
+        instructions::handle_enable_permissionless_reward_funding(ctx, reward_index)
+    }
+
+    pub fn initialize_reward_funder_ledger(
+        ctx: Context<InitializeRewardFunderLedgerCtx>,
+    ) -> Result<()> {
+        instructions::handle_initialize_reward_funder_ledger(ctx)
+    }
+
+    pub fn close_reward_funder_ledger(ctx: Context<CloseRewardFunderLedgerCtx>) -> Result<()> {
+        instructions::handle_close_reward_funder_ledger(ctx)
+    }
+
+    pub fn top_up_reward(ctx: Context<TopUpRewardCtx>, amount: u64) -> Result<()> {
+        instructions::handle_top_up_reward(ctx, amount)
+    }
+
+    pub fn initialize_trading_reward_campaign<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeTradingRewardCampaignCtx<'info>>,
+        params: TradingRewardCampaignParameters,
+    ) -> Result<()> {
+        instructions::handle_initialize_trading_reward_campaign(ctx, params)
+    }
+
+    pub fn fund_trading_reward(ctx: Context<FundTradingRewardCtx>, amount: u64) -> Result<()> {
+        instructions::handle_fund_trading_reward(ctx, amount)
+    }
+
+    pub fn initialize_trader_reward(ctx: Context<InitializeTraderRewardCtx>) -> Result<()> {
+        instructions::handle_initialize_trader_reward(ctx)
+    }
+
+    pub fn claim_trading_reward(ctx: Context<ClaimTradingRewardCtx>) -> Result<()> {
+        instructions::handle_claim_trading_reward(ctx)
+    }
+
+    pub fn withdraw_ineligible_trading_reward(
+        ctx: Context<WithdrawIneligibleTradingRewardCtx>,
+    ) -> Result<()> {
+        instructions::handle_withdraw_ineligible_trading_reward(ctx)
+    }
+
+    pub fn close_trader_reward(ctx: Context<CloseTraderRewardCtx>) -> Result<()> {
+        instructions::handle_close_trader_reward(ctx)
+    }
+
+    pub fn close_trading_reward_campaign<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseTradingRewardCampaignCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_close_trading_reward_campaign(ctx)
+    }
+
+    pub fn initialize_pool_allowlist<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializePoolAllowlistCtx<'info>>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_initialize_pool_allowlist(ctx, authority)
+    }
+
+    pub fn create_allowlist_entry(
+        ctx: Context<CreateAllowlistEntryCtx>,
+        permission: u8,
+    ) -> Result<()> {
+        instructions::handle_create_allowlist_entry(ctx, permission)
+    }
+
+    pub fn close_allowlist_entry(ctx: Context<CloseAllowlistEntryCtx>) -> Result<()> {
+        instructions::handle_close_allowlist_entry(ctx)
+    }
+
     pub fn update_reward_funder<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateRewardFunderCtx<'info>>,
         reward_index: u8,
@@ -150,6 +307,37 @@ pub mod cp_amm {
         instructions::handle_update_reward_duration(ctx, reward_index, new_duration)
     }
 
+    pub fn update_reward_boost<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateRewardBoostCtx<'info>>,
+        reward_index: u8,
+        new_boost_multiplier_bps: u16,
+    ) -> Result<()> {
+        instructions::handle_update_reward_boost(ctx, reward_index, new_boost_multiplier_bps)
+    }
+
+    pub fn update_reward_emission_schedule<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateRewardEmissionScheduleCtx<'info>>,
+        reward_index: u8,
+        emission_schedule: u8,
+        emission_parameter: u8,
+    ) -> Result<()> {
+        instructions::handle_update_reward_emission_schedule(
+            ctx,
+            reward_index,
+            emission_schedule,
+            emission_parameter,
+        )
+    }
+
+    pub fn update_reward_time_unit<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateRewardTimeUnitCtx<'info>>,
+        reward_index: u8,
+        time_unit: u8,
+        reward_duration: u64,
+    ) -> Result<()> {
+        instructions::handle_update_reward_time_unit(ctx, reward_index, time_unit, reward_duration)
+    }
+
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::SetPoolStatus))]
     pub fn set_pool_status(ctx: Context<SetPoolStatusCtx>, status: u8) -> Result<()> {
         instructions::handle_set_pool_status(ctx, status)
@@ -178,6 +366,43 @@ pub mod cp_amm {
         instructions::handle_claim_partner_fee(ctx, max_amount_a, max_amount_b)
     }
 
+    /// POOL CREATOR FUNCTIONS ////
+    pub fn transfer_pool_creator(
+        ctx: Context<TransferPoolCreatorCtx>,
+        new_creator: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_transfer_pool_creator(ctx, new_creator)
+    }
+
+    pub fn accept_pool_creator(ctx: Context<AcceptPoolCreatorCtx>) -> Result<()> {
+        instructions::handle_accept_pool_creator(ctx)
+    }
+
+    pub fn cancel_pool_creator_transfer(ctx: Context<CancelPoolCreatorTransferCtx>) -> Result<()> {
+        instructions::handle_cancel_pool_creator_transfer(ctx)
+    }
+
+    pub fn update_pool_partner(
+        ctx: Context<UpdatePoolPartnerCtx>,
+        new_partner: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_update_pool_partner(ctx, new_partner)
+    }
+
+    pub fn lower_pool_base_fee(
+        ctx: Context<LowerPoolBaseFeeCtx>,
+        cliff_fee_numerator: u64,
+    ) -> Result<()> {
+        instructions::handle_lower_pool_base_fee(ctx, cliff_fee_numerator)
+    }
+
+    pub fn initialize_lbp(
+        ctx: Context<InitializeLbpCtx>,
+        params: InitializeLbpParameters,
+    ) -> Result<()> {
+        instructions::handle_initialize_lbp(ctx, params)
+    }
+
     #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::CloseTokenBadge))]
     pub fn close_token_badge(ctx: Context<CloseTokenBadgeCtx>) -> Result<()> {
         instructions::handle_close_token_badge(ctx)
@@ -191,6 +416,21 @@ pub mod cp_amm {
         instructions::handle_update_pool_fees(ctx, params)
     }
 
+    #[access_control(is_valid_operator_role(&ctx.accounts.operator, ctx.accounts.signer.key, OperatorPermission::UpdatePoolFees))]
+    pub fn upgrade_pool_version(
+        ctx: Context<UpgradePoolVersionCtx>,
+        params: UpgradePoolVersionParameters,
+    ) -> Result<()> {
+        instructions::handle_upgrade_pool_version(ctx, params)
+    }
+
+    pub fn update_pool_price_range<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdatePoolPriceRangeCtx<'info>>,
+        params: UpdatePoolPriceRangeParameters,
+    ) -> Result<()> {
+        instructions::handle_update_pool_price_range(ctx, params)
+    }
+
     /// USER FUNCTIONS ////
 
     pub fn initialize_pool<'c: 'info, 'info>(
@@ -214,19 +454,21 @@ pub mod cp_amm {
         instructions::handle_initialize_customizable_pool(ctx, params)
     }
 
-    pub fn create_position(ctx: Context<CreatePositionCtx>) -> Result<()> {
+    pub fn create_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CreatePositionCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_create_position(ctx)
     }
 
-    pub fn add_liquidity(
-        ctx: Context<AddLiquidityCtx>,
+    pub fn add_liquidity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AddLiquidityCtx<'info>>,
         params: AddLiquidityParameters,
     ) -> Result<()> {
         instructions::handle_add_liquidity(ctx, params)
     }
 
-    pub fn remove_liquidity(
-        ctx: Context<RemoveLiquidityCtx>,
+    pub fn remove_liquidity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RemoveLiquidityCtx<'info>>,
         params: RemoveLiquidityParameters,
     ) -> Result<()> {
         instructions::handle_remove_liquidity(
@@ -237,8 +479,8 @@ pub mod cp_amm {
         )
     }
 
-    pub fn remove_all_liquidity(
-        ctx: Context<RemoveLiquidityCtx>,
+    pub fn remove_all_liquidity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RemoveLiquidityCtx<'info>>,
         token_a_amount_threshold: u64,
         token_b_amount_threshold: u64,
     ) -> Result<()> {
@@ -250,10 +492,18 @@ pub mod cp_amm {
         )
     }
 
-    pub fn close_position(ctx: Context<ClosePositionCtx>) -> Result<()> {
+    pub fn close_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClosePositionCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_close_position(ctx)
     }
 
+    pub fn close_pool<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClosePoolCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_close_pool(ctx)
+    }
+
     pub fn swap(_ctx: Context<SwapCtx>, _params: SwapParameters) -> Result<()> {
         Ok(())
         // instructions::swap::handle_swap_wrapper(
@@ -275,39 +525,72 @@ pub mod cp_amm {
         instructions::handle_claim_position_fee(ctx)
     }
 
-    pub fn lock_position(ctx: Context<LockPositionCtx>, params: VestingParameters) -> Result<()> {
+    pub fn batch_claim<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BatchClaimCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_batch_claim(ctx)
+    }
+
+    pub fn lock_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LockPositionCtx<'info>>,
+        params: VestingParameters,
+    ) -> Result<()> {
         instructions::handle_lock_position(ctx, params)
     }
 
+    pub fn lock_position_with_extended_vesting<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LockPositionWithExtendedVestingCtx<'info>>,
+        params: ExtendedVestingParameters,
+    ) -> Result<()> {
+        instructions::handle_lock_position_with_extended_vesting(ctx, params)
+    }
+
+    pub fn lock_revocable_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LockRevocablePositionCtx<'info>>,
+        params: VestingParameters,
+        revoker: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_lock_revocable_position(ctx, params, revoker)
+    }
+
+    pub fn revoke_vesting<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RevokeVestingCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_revoke_vesting(ctx)
+    }
+
     pub fn refresh_vesting<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, RefreshVesting<'info>>,
     ) -> Result<()> {
         instructions::handle_refresh_vesting(ctx)
     }
 
-    pub fn permanent_lock_position(
-        ctx: Context<PermanentLockPositionCtx>,
+    pub fn permanent_lock_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PermanentLockPositionCtx<'info>>,
         permanent_lock_liquidity: u128,
     ) -> Result<()> {
         instructions::handle_permanent_lock_position(ctx, permanent_lock_liquidity)
     }
 
-    pub fn claim_reward(
-        ctx: Context<ClaimRewardCtx>,
+    pub fn claim_reward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimRewardCtx<'info>>,
         reward_index: u8,
         skip_reward: u8,
     ) -> Result<()> {
         instructions::handle_claim_reward(ctx, reward_index, skip_reward)
     }
 
-    pub fn split_position(
-        ctx: Context<SplitPositionCtx>,
+    pub fn split_position<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SplitPositionCtx<'info>>,
         params: SplitPositionParameters,
     ) -> Result<()> {
         instructions::handle_split_position2(ctx, params.get_split_position_parameters2()?)
     }
 
-    pub fn split_position2(ctx: Context<SplitPositionCtx>, numerator: u32) -> Result<()> {
+    pub fn split_position2<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SplitPositionCtx<'info>>,
+        numerator: u32,
+    ) -> Result<()> {
         instructions::handle_split_position2(
             ctx,
             SplitPositionParameters2 {
@@ -321,6 +604,12 @@ pub mod cp_amm {
         )
     }
 
+    pub fn refresh_position_nft_metadata(
+        ctx: Context<RefreshPositionNftMetadataCtx>,
+    ) -> Result<()> {
+        instructions::handle_refresh_position_nft_metadata(ctx)
+    }
+
     #[cfg(feature = "idl-build")]
     pub fn dummy_ix(
         _ctx: Context<ForIdlTypeGenerationDoNotCallThis>,
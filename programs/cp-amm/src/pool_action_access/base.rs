@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::Pubkey;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
-use crate::{state::Pool, PermissionlessActionAccess};
+use crate::{
+    state::{AllowlistEntry, Pool},
+    PermissionedActionAccess, PermissionlessActionAccess,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
@@ -13,15 +16,24 @@ pub enum ActivationType {
 }
 
 pub trait PoolActionAccess {
-    fn can_add_liquidity(&self) -> bool;
+    fn can_add_liquidity(&self, sender: &Pubkey) -> bool;
     fn can_remove_liquidity(&self) -> bool;
     fn can_swap(&self, sender: &Pubkey) -> bool;
-    fn can_create_position(&self) -> bool;
+    fn can_create_position(&self, sender: &Pubkey) -> bool;
     fn can_lock_position(&self) -> bool;
     fn can_split_position(&self) -> bool;
 }
 
-pub fn get_pool_access_validator<'a>(pool: &'a Pool) -> Result<Box<dyn PoolActionAccess + 'a>> {
+/// allowlist_entry is the entry of the acting wallet, only used by permissioned pools
+pub fn get_pool_access_validator<'a>(
+    pool: &'a Pool,
+    allowlist_entry: Option<&AllowlistEntry>,
+) -> Result<Box<dyn PoolActionAccess + 'a>> {
+    if pool.is_permissioned() {
+        let access_validator = PermissionedActionAccess::new(pool, allowlist_entry)?;
+        return Ok(Box::new(access_validator));
+    }
+
     let access_validator = PermissionlessActionAccess::new(pool)?;
     Ok(Box::new(access_validator))
 }
@@ -2,3 +2,5 @@ pub mod base;
 pub use base::*;
 pub mod permissionless;
 pub use permissionless::*;
+pub mod permissioned;
+pub use permissioned::*;
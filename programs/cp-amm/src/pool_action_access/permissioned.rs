@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AllowlistEntry, AllowlistPermission, Pool},
+    PermissionlessActionAccess, PoolActionAccess,
+};
+
+/// Access of permissioned pools, where only allowlisted wallets can swap or provide liquidity.
+/// Other actions follow permissionless access
+pub struct PermissionedActionAccess {
+    permissionless_access: PermissionlessActionAccess,
+    allowlist_entry: Option<AllowlistEntry>,
+}
+
+impl PermissionedActionAccess {
+    pub fn new(pool: &Pool, allowlist_entry: Option<&AllowlistEntry>) -> Result<Self> {
+        Ok(Self {
+            permissionless_access: PermissionlessActionAccess::new(pool)?,
+            allowlist_entry: allowlist_entry.copied(),
+        })
+    }
+
+    fn is_allowlisted(&self, sender: &Pubkey, permission: AllowlistPermission) -> bool {
+        self.allowlist_entry.is_some_and(|allowlist_entry| {
+            allowlist_entry.wallet.eq(sender) && allowlist_entry.is_permission_allow(permission)
+        })
+    }
+}
+
+impl PoolActionAccess for PermissionedActionAccess {
+    fn can_add_liquidity(&self, sender: &Pubkey) -> bool {
+        self.is_allowlisted(sender, AllowlistPermission::ProvideLiquidity)
+            && self.permissionless_access.can_add_liquidity(sender)
+    }
+
+    fn can_remove_liquidity(&self) -> bool {
+        self.permissionless_access.can_remove_liquidity()
+    }
+
+    fn can_swap(&self, sender: &Pubkey) -> bool {
+        self.is_allowlisted(sender, AllowlistPermission::Swap)
+            && self.permissionless_access.can_swap(sender)
+    }
+
+    fn can_create_position(&self, sender: &Pubkey) -> bool {
+        self.is_allowlisted(sender, AllowlistPermission::ProvideLiquidity)
+            && self.permissionless_access.can_create_position(sender)
+    }
+    fn can_lock_position(&self) -> bool {
+        self.permissionless_access.can_lock_position()
+    }
+    fn can_split_position(&self) -> bool {
+        self.permissionless_access.can_split_position()
+    }
+}
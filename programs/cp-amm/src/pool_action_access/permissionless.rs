@@ -8,7 +8,7 @@ use crate::{
 };
 
 pub struct PermissionlessActionAccess {
-    is_enabled: bool,
+    pool_status: PoolStatus,
     activation_point: u64,
     pre_activation_point: u64,
     current_point: u64,
@@ -28,8 +28,10 @@ impl PermissionlessActionAccess {
         } else {
             0
         };
+        let pool_status =
+            PoolStatus::try_from(pool.pool_status).map_err(|_| PoolError::InvalidPoolStatus)?;
         Ok(Self {
-            is_enabled: pool.pool_status == Into::<u8>::into(PoolStatus::Enable),
+            pool_status,
             current_point,
             activation_point: pool.activation_point,
             whitelisted_vault: pool.whitelisted_vault,
@@ -39,8 +41,8 @@ impl PermissionlessActionAccess {
 }
 
 impl PoolActionAccess for PermissionlessActionAccess {
-    fn can_add_liquidity(&self) -> bool {
-        self.is_enabled
+    fn can_add_liquidity(&self, _sender: &Pubkey) -> bool {
+        self.pool_status.is_add_liquidity_allowed()
     }
 
     fn can_remove_liquidity(&self) -> bool {
@@ -48,7 +50,7 @@ impl PoolActionAccess for PermissionlessActionAccess {
     }
 
     fn can_swap(&self, sender: &Pubkey) -> bool {
-        if self.is_enabled {
+        if self.pool_status.is_swap_allowed() {
             if sender.eq(&self.whitelisted_vault) {
                 self.current_point >= self.pre_activation_point
             } else {
@@ -59,13 +61,13 @@ impl PoolActionAccess for PermissionlessActionAccess {
         }
     }
 
-    fn can_create_position(&self) -> bool {
-        self.is_enabled
+    fn can_create_position(&self, _sender: &Pubkey) -> bool {
+        self.pool_status.is_add_liquidity_allowed()
     }
     fn can_lock_position(&self) -> bool {
-        self.is_enabled
+        self.pool_status.is_position_management_allowed()
     }
     fn can_split_position(&self) -> bool {
-        self.is_enabled
+        self.pool_status.is_position_management_allowed()
     }
 }
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::PoolError;
+
+pub const MAX_ADMINS: usize = 5;
+
+/// Singleton admin set, replacing the compile-time admin list once initialized
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct AdminState {
+    /// Admin keys, Pubkey::default for empty slots
+    pub admins: [Pubkey; MAX_ADMINS],
+    /// Admin replaced by the pending rotation, Pubkey::default when the pending rotation adds a new admin
+    pub pending_old_admin: Pubkey,
+    /// Admin proposed by the pending rotation, Pubkey::default when there is no pending rotation
+    pub pending_new_admin: Pubkey,
+    /// Number of admins that must sign admin instructions
+    pub threshold: u8,
+    pub padding_0: [u8; 15],
+    /// padding for future usage
+    pub padding: [u128; 4],
+}
+
+const_assert_eq!(AdminState::INIT_SPACE, 304);
+
+impl AdminState {
+    pub fn initialize(&mut self, admins: &[Pubkey]) -> Result<()> {
+        require!(
+            !admins.is_empty() && admins.len() <= MAX_ADMINS,
+            PoolError::InvalidAdminRotation
+        );
+        self.admins[..admins.len()].copy_from_slice(admins);
+        self.threshold = 1;
+        Ok(())
+    }
+
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        key.ne(&Pubkey::default()) && self.admins.contains(key)
+    }
+
+    pub fn get_number_of_admins(&self) -> usize {
+        self.admins
+            .iter()
+            .filter(|admin| admin.ne(&&Pubkey::default()))
+            .count()
+    }
+
+    /// Signer must be an admin, and together with co-signers reach the threshold
+    pub fn is_authorized(&self, signer: &Pubkey, co_signers: &[Pubkey]) -> bool {
+        if !self.is_admin(signer) {
+            return false;
+        }
+        // count each admin once, no matter how many times it is passed
+        let number_of_signed_admins = self
+            .admins
+            .iter()
+            .filter(|admin| {
+                admin.ne(&&Pubkey::default()) && (admin.eq(&signer) || co_signers.contains(admin))
+            })
+            .count();
+        number_of_signed_admins >= usize::from(self.threshold)
+    }
+
+    pub fn propose_rotation(&mut self, old_admin: Pubkey, new_admin: Pubkey) -> Result<()> {
+        require!(
+            new_admin.ne(&Pubkey::default()) && !self.is_admin(&new_admin),
+            PoolError::InvalidAdminRotation
+        );
+        if old_admin.eq(&Pubkey::default()) {
+            // adding a new admin needs an empty slot
+            require!(
+                self.get_number_of_admins() < MAX_ADMINS,
+                PoolError::InvalidAdminRotation
+            );
+        } else {
+            require!(self.is_admin(&old_admin), PoolError::InvalidAdminRotation);
+        }
+
+        self.pending_old_admin = old_admin;
+        self.pending_new_admin = new_admin;
+        Ok(())
+    }
+
+    pub fn accept_rotation(&mut self, new_admin: Pubkey) -> Result<()> {
+        require!(
+            new_admin.ne(&Pubkey::default()) && new_admin.eq(&self.pending_new_admin),
+            PoolError::InvalidAdminRotation
+        );
+        // admin set may have changed since the proposal
+        require!(!self.is_admin(&new_admin), PoolError::InvalidAdminRotation);
+
+        let old_admin = self.pending_old_admin;
+        let slot = self
+            .admins
+            .iter_mut()
+            .find(|admin| **admin == old_admin)
+            .ok_or(PoolError::InvalidAdminRotation)?;
+        *slot = new_admin;
+
+        self.pending_old_admin = Pubkey::default();
+        self.pending_new_admin = Pubkey::default();
+        Ok(())
+    }
+
+    pub fn update_threshold(&mut self, threshold: u8) -> Result<()> {
+        require!(
+            threshold > 0 && usize::from(threshold) <= self.get_number_of_admins(),
+            PoolError::InvalidAdminThreshold
+        );
+        self.threshold = threshold;
+        Ok(())
+    }
+}
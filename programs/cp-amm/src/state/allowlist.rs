@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+
+/// Actions an allowlisted wallet can do in a permissioned pool, bit index in AllowlistEntry permission
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum AllowlistPermission {
+    Swap,             // 0
+    ProvideLiquidity, // 1
+}
+
+/// Max value of AllowlistEntry permission, all permissions allowed
+pub const MAX_ALLOWLIST_PERMISSION: u8 = 0b11;
+
+/// Allowlist of a permissioned pool
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PoolAllowlist {
+    pub pool: Pubkey,
+    /// Who can add and remove wallets of the allowlist
+    pub authority: Pubkey,
+    /// Number of allowlisted wallets
+    pub number_of_entries: u64,
+    pub _padding_0: u64,
+    /// padding for future usage
+    pub padding: [u128; 2],
+}
+
+const_assert_eq!(PoolAllowlist::INIT_SPACE, 112);
+
+impl PoolAllowlist {
+    pub fn initialize(&mut self, pool: Pubkey, authority: Pubkey) {
+        self.pool = pool;
+        self.authority = authority;
+    }
+}
+
+/// Allowlisted wallet of a permissioned pool
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct AllowlistEntry {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    /// Bit flags of AllowlistPermission
+    pub permission: u8,
+    pub _padding_0: [u8; 15],
+    /// padding for future usage
+    pub padding: [u128; 1],
+}
+
+const_assert_eq!(AllowlistEntry::INIT_SPACE, 96);
+
+impl AllowlistEntry {
+    pub fn initialize(&mut self, pool: Pubkey, wallet: Pubkey, permission: u8) {
+        self.pool = pool;
+        self.wallet = wallet;
+        self.permission = permission;
+    }
+
+    pub fn is_permission_allow(&self, permission: AllowlistPermission) -> bool {
+        let permission: u8 = 1 << u8::from(permission);
+        self.permission & permission != 0
+    }
+}
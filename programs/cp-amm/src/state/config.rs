@@ -9,6 +9,7 @@ use crate::{
     },
     safe_math::SafeMath,
     state::fee::{BaseFeeStruct, DynamicFeeStruct, PoolFeesStruct},
+    StaticConfigParameters,
 };
 use anchor_lang::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -168,6 +169,29 @@ impl DynamicFeeConfig {
     }
 }
 
+/// How the pre-activation buyer of pools created with a config is found
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+    Default,
+)]
+pub enum PreActivationBuyerMode {
+    /// Vault of alpha vault program, derived from vault base and pool
+    #[default]
+    AlphaVault,
+    /// Vault of the pre-activation buyer program, derived from vault base and pool with the alpha vault seed scheme
+    ProgramDerived,
+    /// The pre-activation buyer address itself
+    Address,
+}
+
 #[account(zero_copy)]
 #[derive(InitSpace, Debug)]
 pub struct Config {
@@ -191,9 +215,16 @@ pub struct Config {
     pub sqrt_min_price: u128,
     /// sqrt max price
     pub sqrt_max_price: u128,
-    /// Fee curve point
+    /// Program id in ProgramDerived mode, or whitelisted address in Address mode
+    pub pre_activation_buyer: Pubkey,
+    /// Pre-activation buyer mode, see PreActivationBuyerMode
+    pub pre_activation_buyer_mode: u8,
+    /// padding 2
+    pub _padding_2: [u8; 7],
+    /// Config version, increased on every config update. Pools created before the update keep the previous parameters
+    pub version: u64,
     /// Padding for further use
-    pub _padding_1: [u64; 10],
+    pub _padding_1: [u64; 4],
 }
 
 const_assert_eq!(Config::INIT_SPACE, 320);
@@ -270,6 +301,39 @@ impl Config {
         Ok(())
     }
 
+    /// Parameters of static config, as they were given in create_static_config or update_config
+    pub fn to_static_config_parameters(&self) -> Result<StaticConfigParameters> {
+        Ok(StaticConfigParameters {
+            pool_fees: self.pool_fees.to_pool_fee_parameters()?,
+            sqrt_min_price: self.sqrt_min_price,
+            sqrt_max_price: self.sqrt_max_price,
+            vault_config_key: self.vault_config_key,
+            pool_creator_authority: self.pool_creator_authority,
+            activation_type: self.activation_type,
+            collect_fee_mode: self.collect_fee_mode,
+        })
+    }
+
+    pub fn update_static_config(&mut self, params: &StaticConfigParameters) -> Result<()> {
+        require!(
+            self.get_config_type()? == ConfigType::Static,
+            PoolError::InvalidConfigType
+        );
+        self.pool_fees = params.pool_fees.to_pool_fees_config()?;
+        self.vault_config_key = params.vault_config_key;
+        self.pool_creator_authority = params.pool_creator_authority;
+        self.activation_type = params.activation_type;
+        self.sqrt_min_price = params.sqrt_min_price;
+        self.sqrt_max_price = params.sqrt_max_price;
+        self.collect_fee_mode = params.collect_fee_mode;
+        self.increase_version()
+    }
+
+    pub fn increase_version(&mut self) -> Result<()> {
+        self.version = self.version.safe_add(1)?;
+        Ok(())
+    }
+
     pub fn get_config_type(&self) -> Result<ConfigType> {
         let config_type =
             ConfigType::try_from(self.config_type).map_err(|_| PoolError::TypeCastFailed)?;
@@ -290,18 +354,71 @@ impl Config {
         }
     }
 
+    pub fn get_pre_activation_buyer_mode(&self) -> Result<PreActivationBuyerMode> {
+        PreActivationBuyerMode::try_from(self.pre_activation_buyer_mode)
+            .map_err(|_| PoolError::InvalidPreActivationBuyer.into())
+    }
+
     pub fn has_alpha_vault(&self) -> bool {
-        self.vault_config_key.ne(&Pubkey::default())
+        self.has_alpha_vault_with_vault_config_key(self.vault_config_key)
     }
 
-    pub fn get_whitelisted_alpha_vault(&self, pool: Pubkey) -> Pubkey {
-        if self.vault_config_key.eq(&Pubkey::default()) {
-            Pubkey::default()
+    /// Whether pools of the config have a pre-activation buyer once `vault_config_key` is set, in the current
+    /// pre-activation buyer mode
+    pub fn has_alpha_vault_with_vault_config_key(&self, vault_config_key: Pubkey) -> bool {
+        if self.pre_activation_buyer_mode == PreActivationBuyerMode::Address as u8 {
+            self.pre_activation_buyer.ne(&Pubkey::default())
         } else {
-            alpha_vault::derive_vault_pubkey(self.vault_config_key, pool.key())
+            vault_config_key.ne(&Pubkey::default())
         }
     }
 
+    pub fn get_whitelisted_alpha_vault(&self, pool: Pubkey) -> Result<Pubkey> {
+        if self.has_alpha_vault() {
+            self.derive_pre_activation_buyer(self.vault_config_key, pool)
+        } else {
+            Ok(Pubkey::default())
+        }
+    }
+
+    /// Address allowed to swap in the pre-activation window of the pool
+    pub fn derive_pre_activation_buyer(&self, vault_base: Pubkey, pool: Pubkey) -> Result<Pubkey> {
+        let pre_activation_buyer = match self.get_pre_activation_buyer_mode()? {
+            PreActivationBuyerMode::AlphaVault => {
+                alpha_vault::derive_vault_pubkey(vault_base, pool)
+            }
+            PreActivationBuyerMode::ProgramDerived => {
+                alpha_vault::derive_vault_pubkey_with_program(
+                    self.pre_activation_buyer,
+                    vault_base,
+                    pool,
+                )
+            }
+            PreActivationBuyerMode::Address => self.pre_activation_buyer,
+        };
+        Ok(pre_activation_buyer)
+    }
+
+    pub fn update_pre_activation_buyer(
+        &mut self,
+        mode: PreActivationBuyerMode,
+        pre_activation_buyer: Pubkey,
+    ) -> Result<()> {
+        // alpha vault program is fixed, other modes need a program id or an address
+        let is_valid = match mode {
+            PreActivationBuyerMode::AlphaVault => pre_activation_buyer.eq(&Pubkey::default()),
+            PreActivationBuyerMode::ProgramDerived | PreActivationBuyerMode::Address => {
+                pre_activation_buyer.ne(&Pubkey::default())
+            }
+        };
+        require!(is_valid, PoolError::InvalidPreActivationBuyer);
+
+        self.pre_activation_buyer_mode = mode.into();
+        self.pre_activation_buyer = pre_activation_buyer;
+
+        self.increase_version()
+    }
+
     pub fn get_max_activation_point_from_current_time(&self, clock: &Clock) -> Result<u64> {
         let timing_contraints = get_timing_constraint_by_activation_type(
             self.activation_type
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+
+use crate::PoolError;
+
+/// Class of user actions that can be paused globally
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum PauseAction {
+    Swap,            // 0
+    AddLiquidity,    // 1
+    RemoveLiquidity, // 2
+    Claim,           // 3
+}
+
+pub const NUM_PAUSE_ACTIONS: u8 = 4;
+
+/// Singleton emergency switch for all pools, checked by swap, liquidity and claim instructions
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct GlobalPause {
+    /// Bitmask of paused actions, bit index refers to [PauseAction]
+    pub paused_actions: u8,
+    pub padding_0: [u8; 7],
+    /// Timestamp of the last pause or unpause
+    pub last_updated_at: u64,
+    /// padding for future usage
+    pub padding: [u128; 3],
+}
+
+const_assert_eq!(GlobalPause::INIT_SPACE, 64);
+
+impl GlobalPause {
+    pub fn validate_actions(actions: u8) -> Result<()> {
+        require!(
+            actions > 0 && actions < 1 << NUM_PAUSE_ACTIONS,
+            PoolError::InvalidPauseActions
+        );
+        Ok(())
+    }
+
+    pub fn is_action_paused(&self, action: PauseAction) -> bool {
+        self.paused_actions & (1 << Into::<u8>::into(action)) != 0
+    }
+
+    pub fn pause(&mut self, actions: u8, current_timestamp: u64) -> Result<()> {
+        Self::validate_actions(actions)?;
+        self.paused_actions |= actions;
+        self.last_updated_at = current_timestamp;
+        Ok(())
+    }
+
+    pub fn unpause(&mut self, actions: u8, current_timestamp: u64) -> Result<()> {
+        Self::validate_actions(actions)?;
+        self.paused_actions &= !actions;
+        self.last_updated_at = current_timestamp;
+        Ok(())
+    }
+}
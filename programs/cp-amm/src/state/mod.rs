@@ -11,3 +11,17 @@ pub mod vesting;
 pub use vesting::*;
 pub mod operator;
 pub use operator::*;
+pub mod reward_extension;
+pub use reward_extension::*;
+pub mod reward_funding;
+pub use reward_funding::*;
+pub mod trading_reward;
+pub use trading_reward::*;
+pub mod allowlist;
+pub use allowlist::*;
+pub mod pool_creator_transfer;
+pub use pool_creator_transfer::*;
+pub mod global_pause;
+pub use global_pause::*;
+pub mod admin_state;
+pub use admin_state::*;
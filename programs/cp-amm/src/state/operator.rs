@@ -33,8 +33,10 @@ pub enum OperatorPermission {
 #[derive(InitSpace, Debug, Default)]
 pub struct Operator {
     pub whitelisted_address: Pubkey,
-    pub permission: u128,  // max 128 actions?
-    pub padding: [u64; 2], // padding for future use
+    pub permission: u128, // max 128 actions?
+    /// Timestamp when the operator role expires, 0 means the role never expires
+    pub expires_at: u64,
+    pub padding: [u64; 1], // padding for future use
 }
 
 const_assert_eq!(Operator::INIT_SPACE, 64);
@@ -45,6 +47,27 @@ impl Operator {
         self.permission = permission;
     }
 
+    pub fn update_permission(&mut self, permission: u128, expires_at: u64) {
+        self.permission = permission;
+        self.expires_at = expires_at;
+    }
+
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        self.expires_at != 0 && current_timestamp >= self.expires_at
+    }
+
+    /// Signer is the whitelisted address, the permission is granted and the role is not expired
+    pub fn is_valid_role(
+        &self,
+        signer: &Pubkey,
+        permission: OperatorPermission,
+        current_timestamp: u64,
+    ) -> bool {
+        self.whitelisted_address.eq(signer)
+            && self.is_permission_allow(permission)
+            && !self.is_expired(current_timestamp)
+    }
+
     pub fn is_permission_allow(&self, permission: OperatorPermission) -> bool {
         let result: u128 = self
             .permission
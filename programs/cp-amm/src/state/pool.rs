@@ -1,6 +1,6 @@
 use alloy_primitives::U256;
 use static_assertions::const_assert_eq;
-use std::cmp::min;
+use std::cmp::{max, min};
 
 use anchor_lang::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -8,24 +8,28 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use crate::activation_handler::{ActivationHandler, ActivationType};
 use crate::base_fee::{BaseFeeHandlerBuilder, UpdateCliffFeeNumerator};
 use crate::constants::fee::{
-    get_max_fee_numerator, CURRENT_POOL_VERSION, MAX_FEE_NUMERATOR_POST_UPDATE,
+    get_max_fee_numerator, CURRENT_POOL_VERSION, MAX_BASIS_POINT, MAX_FEE_NUMERATOR_POST_UPDATE,
 };
 use crate::curve::{get_delta_amount_b_unsigned_unchecked, get_next_sqrt_price_from_output};
 use crate::state::fee::{FeeOnAmountResult, SplitFees};
 use crate::{
-    constants::{LIQUIDITY_SCALE, NUM_REWARDS, REWARD_INDEX_0, REWARD_INDEX_1, REWARD_RATE_SCALE},
+    constants::{
+        LIQUIDITY_SCALE, MAX_REWARD_DURATION, MAX_REWARD_EMISSION_STEPS, MAX_REWARD_SLOT_DURATION,
+        MAX_SQRT_PRICE, MIN_REWARD_DURATION, MIN_REWARD_SLOT_DURATION, MIN_SQRT_PRICE, NUM_REWARDS,
+        REWARD_INDEX_0, REWARD_INDEX_1, REWARD_RATE_SCALE,
+    },
     curve::{
         get_delta_amount_a_unsigned, get_delta_amount_a_unsigned_unchecked,
         get_delta_amount_b_unsigned, get_next_sqrt_price_from_input,
     },
     params::swap::TradeDirection,
-    safe_math::SafeMath,
+    safe_math::{SafeCast, SafeMath},
     state::{
         fee::{DynamicFeeStruct, PoolFeesStruct},
-        Position, SplitFeeAmount,
+        Position, SplitFeeAmount, REWARD_EXTENSION_BOOSTED_FLAG, REWARD_EXTENSION_ENABLED_FLAG,
     },
     u128x128_math::{shl_div_256, Rounding},
-    utils_math::{safe_mul_shr_cast, safe_shl_div_cast},
+    utils_math::{safe_mul_div_cast_u128, safe_mul_shr_cast, safe_shl_div_cast},
     PoolError,
 };
 use crate::{BaseFeeUpdateMode, DynamicFeeUpdateMode, UpdatePoolFeesParameters};
@@ -64,8 +68,93 @@ pub enum CollectFeeMode {
     AnchorSerialize,
 )]
 pub enum PoolStatus {
+    /// All actions are allowed
     Enable,
+    /// Only liquidity removal is allowed
     Disable,
+    /// Swaps are halted, liquidity and positions can be managed
+    SwapDisabled,
+    /// No swaps and no new liquidity, positions can still be split and locked
+    WithdrawOnly,
+    /// Liquidity can't grow, swaps are allowed and positions can still be split and locked
+    ReduceOnly,
+}
+
+impl PoolStatus {
+    pub fn is_swap_allowed(&self) -> bool {
+        matches!(self, PoolStatus::Enable | PoolStatus::ReduceOnly)
+    }
+
+    /// Add liquidity and create position
+    pub fn is_add_liquidity_allowed(&self) -> bool {
+        matches!(self, PoolStatus::Enable | PoolStatus::SwapDisabled)
+    }
+
+    /// Split and lock position
+    pub fn is_position_management_allowed(&self) -> bool {
+        !matches!(self, PoolStatus::Disable)
+    }
+}
+
+/// Emission curve of a reward over its reward duration
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum RewardEmissionSchedule {
+    /// Same rate over the whole duration
+    Constant,
+    /// Rate decreases linearly to zero at the end of the duration
+    LinearDecay,
+    /// Duration is split in `emission_parameter` steps, each step emits half of the previous one
+    Halving,
+    /// `emission_parameter` percent of the reward is emitted in the first half of the duration
+    FrontLoaded,
+}
+
+/// Unit of reward duration and reward timing
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum RewardTimeUnit {
+    /// Unix timestamp in seconds
+    Timestamp,
+    /// Same as pool activation type, slot or timestamp
+    ActivationPoint,
+}
+
+/// Who can fund a reward
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum RewardFundingMode {
+    /// Only the reward funder, with `fund_reward`
+    Funder,
+    /// Anyone, with `top_up_reward`. Contribution of each funder is tracked in a `RewardFunderLedger`
+    Permissionless,
 }
 
 #[repr(u8)]
@@ -151,8 +240,9 @@ pub struct Pool {
     pub pool_type: u8,
     /// pool version, 0: max_fee is still capped at 50%, 1: max_fee is capped at 99%
     pub version: u8,
-    /// padding
-    pub _padding_0: u8,
+    /// Bit 0 is set if pool has a reward extension account, liquidity changes must then update extended rewards.
+    /// Bit 1 is set while an extended reward is boosted, locked liquidity changes must then update extended rewards
+    pub reward_extension_flag: u8,
     /// cumulative
     pub fee_a_per_liquidity: [u8; 32], // U256
     /// cumulative
@@ -163,8 +253,14 @@ pub struct Pool {
     pub metrics: PoolMetrics,
     /// pool creator
     pub creator: Pubkey,
-    /// Padding for further use
-    pub _padding_1: [u64; 6],
+    /// Vested liquidity of positions counted in boosted liquidity supply
+    pub boosted_vested_liquidity: u128,
+    /// Liquidity bootstrapping: sqrt max price at lbp_end_point, 0 if the pool is not in liquidity bootstrapping mode
+    pub lbp_end_sqrt_max_price: u128,
+    /// Liquidity bootstrapping: last point sqrt max price was updated, sqrt max price declines linearly from there to lbp_end_point
+    pub lbp_last_update_point: u64,
+    /// Liquidity bootstrapping: point sqrt max price reaches lbp_end_sqrt_max_price
+    pub lbp_end_point: u64,
     /// Farming reward information
     pub reward_infos: [RewardInfo; NUM_REWARDS],
 }
@@ -181,7 +277,15 @@ pub struct PoolMetrics {
     pub total_partner_a_fee: u64,
     pub total_partner_b_fee: u64,
     pub total_position: u64,
-    pub padding: u64,
+    /// 1 if the pool has an allowlist, only allowlisted wallets can then swap or provide liquidity.
+    /// Kept apart from pool type, which still decides how the pool price range and fees can change
+    pub permissioned_flag: u8,
+    /// 1 while a pool creator transfer is pending
+    pub pending_creator_transfer_flag: u8,
+    /// Number of trading reward campaigns not closed yet
+    pub number_of_trading_reward_campaigns: u16,
+    /// padding
+    pub _padding_0: [u8; 4],
 }
 
 const_assert_eq!(PoolMetrics::INIT_SPACE, 80);
@@ -223,10 +327,19 @@ pub struct RewardInfo {
     pub initialized: u8,
     /// reward token flag
     pub reward_token_flag: u8,
-    /// padding
-    pub _padding_0: [u8; 6],
-    /// Padding to ensure `reward_rate: u128` is 16-byte aligned
-    pub _padding_1: [u8; 8], // 8 bytes
+    /// Reward multiplier of vested and permanent locked liquidity in bps, 0 means no boost
+    pub boost_multiplier_bps: u16,
+    /// Reward emission schedule, see RewardEmissionSchedule
+    pub emission_schedule: u8,
+    /// Parameter of reward emission schedule, 0 for constant and linear decay
+    pub emission_parameter: u8,
+    /// Reward funding mode, see RewardFundingMode
+    pub funding_mode: u8,
+    /// Unit of reward duration, reward duration end and last update time, see RewardTimeUnit
+    pub time_unit: u8,
+    /// Reward emitted while the pool had no liquidity, for non constant emission schedules.
+    /// Also ensures `reward_rate: u128` is 16-byte aligned
+    pub cumulative_ineligible_reward: u64,
     /// Reward token mint.
     pub mint: Pubkey,
     /// Reward vault token account.
@@ -237,7 +350,7 @@ pub struct RewardInfo {
     pub reward_duration: u64,
     /// reward duration end
     pub reward_duration_end: u64,
-    /// reward rate
+    /// reward rate, average emission rate of the reward duration for non constant emission schedules
     pub reward_rate: u128,
     /// Reward per token stored
     pub reward_per_token_stored: [u8; 32], // U256
@@ -257,10 +370,52 @@ impl RewardInfo {
         self.initialized != 0
     }
 
+    /// Returns true if the reward is uninitialized, or ended with no ineligible reward left to withdraw.
+    /// Rewards must be updated to current point before.
+    pub fn is_finished(&self, current_point: u64) -> bool {
+        !self.initialized()
+            || (current_point > self.reward_duration_end
+                && self.cumulative_seconds_with_empty_liquidity_reward == 0
+                && self.cumulative_ineligible_reward == 0)
+    }
+
     pub fn is_valid_funder(&self, funder: Pubkey) -> bool {
         funder.eq(&self.funder)
     }
 
+    /// Whether reward timing is in slots, when the reward follows a slot activated pool
+    pub fn is_slot_time_unit(&self, activation_type: u8) -> bool {
+        self.time_unit == RewardTimeUnit::ActivationPoint as u8
+            && activation_type == ActivationType::Slot as u8
+    }
+
+    /// Current point of the reward, slot if the reward follows a slot activated pool, otherwise timestamp
+    pub fn get_current_point(
+        &self,
+        activation_type: u8,
+        current_timestamp: u64,
+        current_slot: u64,
+    ) -> u64 {
+        if self.is_slot_time_unit(activation_type) {
+            current_slot
+        } else {
+            current_timestamp
+        }
+    }
+
+    /// Min and max reward duration in the reward time unit
+    pub fn get_reward_duration_range(&self, activation_type: u8) -> (u64, u64) {
+        if self.is_slot_time_unit(activation_type) {
+            (MIN_REWARD_SLOT_DURATION, MAX_REWARD_SLOT_DURATION)
+        } else {
+            (MIN_REWARD_DURATION, MAX_REWARD_DURATION)
+        }
+    }
+
+    pub fn is_permissionless_funding(&self) -> bool {
+        self.funding_mode == RewardFundingMode::Permissionless as u8
+    }
+
     pub fn init_reward(
         &mut self,
         mint: Pubkey,
@@ -277,6 +432,26 @@ impl RewardInfo {
         self.reward_token_flag = reward_token_flag;
     }
 
+    pub fn is_boosted(&self) -> bool {
+        u64::from(self.boost_multiplier_bps) > MAX_BASIS_POINT
+    }
+
+    /// liquidity + locked_liquidity * (boost_multiplier - 1), locked liquidity is part of liquidity
+    pub fn get_boosted_liquidity(&self, liquidity: u128, locked_liquidity: u128) -> Result<u128> {
+        if !self.is_boosted() {
+            return Ok(liquidity);
+        }
+
+        let boost_liquidity = safe_mul_div_cast_u128(
+            locked_liquidity,
+            u128::from(self.boost_multiplier_bps).safe_sub(MAX_BASIS_POINT.into())?,
+            MAX_BASIS_POINT.into(),
+            Rounding::Down,
+        )?;
+
+        Ok(liquidity.safe_add(boost_liquidity)?)
+    }
+
     pub fn update_rewards(&mut self, liquidity_supply: u128, current_time: u64) -> Result<()> {
         // Update reward if it initialized
         if self.initialized() {
@@ -288,7 +463,7 @@ impl RewardInfo {
                     )?;
 
                 self.accumulate_reward_per_token_stored(reward_per_token_stored_delta)?;
-            } else {
+            } else if self.is_constant_emission() {
                 // Time period which the reward was distributed to empty
                 let time_period = self.get_seconds_elapsed_since_last_update(current_time)?;
 
@@ -296,6 +471,12 @@ impl RewardInfo {
                 self.cumulative_seconds_with_empty_liquidity_reward = self
                     .cumulative_seconds_with_empty_liquidity_reward
                     .safe_add(time_period)?;
+            } else {
+                // Emission rate changes over time, so save the emitted amount instead of seconds
+                let ineligible_reward = self.get_emission(self.last_update_time, current_time)?;
+                self.cumulative_ineligible_reward = self
+                    .cumulative_ineligible_reward
+                    .safe_add(ineligible_reward)?;
             }
 
             self.update_last_update_time(current_time);
@@ -321,10 +502,7 @@ impl RewardInfo {
         current_time: u64,
         liquidity_supply: u128,
     ) -> Result<U256> {
-        let time_period: u128 = self
-            .get_seconds_elapsed_since_last_update(current_time)?
-            .into();
-        let total_reward = time_period.safe_mul(self.reward_rate.into())?;
+        let total_reward = self.get_scaled_emission(self.last_update_time, current_time)?;
 
         let reward_per_token_stored = shl_div_256(total_reward, liquidity_supply, LIQUIDITY_SCALE)
             .ok_or_else(|| PoolError::MathOverflow)?;
@@ -343,6 +521,135 @@ impl RewardInfo {
         U256::from_le_bytes(self.reward_per_token_stored)
     }
 
+    pub fn claim_ineligible_reward(&mut self) -> Result<u64> {
+        // calculate ineligible reward
+        let ineligible_reward: u64 = safe_mul_shr_cast(
+            self.cumulative_seconds_with_empty_liquidity_reward.into(),
+            self.reward_rate,
+            REWARD_RATE_SCALE,
+        )?;
+
+        self.cumulative_seconds_with_empty_liquidity_reward = 0;
+
+        let ineligible_reward = ineligible_reward.safe_add(self.cumulative_ineligible_reward)?;
+        self.cumulative_ineligible_reward = 0;
+
+        Ok(ineligible_reward)
+    }
+
+    pub fn get_emission_schedule(&self) -> Result<RewardEmissionSchedule> {
+        let emission_schedule = RewardEmissionSchedule::try_from(self.emission_schedule)
+            .map_err(|_| PoolError::InvalidRewardEmissionSchedule)?;
+        Ok(emission_schedule)
+    }
+
+    pub fn is_constant_emission(&self) -> bool {
+        self.emission_schedule == RewardEmissionSchedule::Constant as u8
+    }
+
+    /// Reward emitted between from_time and to_time of the current reward duration
+    pub fn get_emission(&self, from_time: u64, to_time: u64) -> Result<u64> {
+        let emission = self
+            .get_scaled_emission(from_time, to_time)?
+            .safe_shr(REWARD_RATE_SCALE.into())?;
+        Ok(emission.safe_cast()?)
+    }
+
+    /// Reward emitted between from_time and to_time of the current reward duration, scaled by REWARD_RATE_SCALE.
+    /// Emission rate at each time is derived from the average reward_rate and the emission schedule
+    pub fn get_scaled_emission(&self, from_time: u64, to_time: u64) -> Result<u128> {
+        let to_time = min(to_time, self.reward_duration_end);
+        if to_time <= from_time {
+            return Ok(0);
+        }
+
+        let duration = self.reward_duration;
+        match self.get_emission_schedule()? {
+            RewardEmissionSchedule::Constant => {
+                let time_period = u128::from(to_time.safe_sub(from_time)?);
+                Ok(time_period.safe_mul(self.reward_rate)?)
+            }
+            RewardEmissionSchedule::LinearDecay => {
+                let (from, to) = self.get_elapsed_range(from_time, to_time)?;
+                // rate(t) = 2 * reward_rate * (duration - t) / duration
+                // integral over [from, to] = reward_rate * (to - from) * (2 * duration - from - to) / duration
+                let weighted_seconds = u128::from(to.safe_sub(from)?).safe_mul(u128::from(
+                    duration.safe_mul(2)?.safe_sub(from)?.safe_sub(to)?,
+                ))?;
+                safe_mul_div_cast_u128(
+                    self.reward_rate,
+                    weighted_seconds,
+                    duration.into(),
+                    Rounding::Down,
+                )
+            }
+            RewardEmissionSchedule::Halving => {
+                let (from, to) = self.get_elapsed_range(from_time, to_time)?;
+                let number_of_steps = usize::from(self.emission_parameter);
+                let mut weights = [0u64; MAX_REWARD_EMISSION_STEPS as usize];
+                let weights = weights
+                    .get_mut(..number_of_steps)
+                    .ok_or_else(|| PoolError::InvalidRewardEmissionSchedule)?;
+                for (step, weight) in weights.iter_mut().enumerate() {
+                    *weight = 1u64.safe_shl((number_of_steps - 1 - step) as u32)?;
+                }
+                self.get_stepwise_scaled_emission(from, to, weights)
+            }
+            RewardEmissionSchedule::FrontLoaded => {
+                let (from, to) = self.get_elapsed_range(from_time, to_time)?;
+                let first_half_percentage = u64::from(self.emission_parameter);
+                let weights = [
+                    first_half_percentage,
+                    100u64.safe_sub(first_half_percentage)?,
+                ];
+                self.get_stepwise_scaled_emission(from, to, &weights)
+            }
+        }
+    }
+
+    /// Seconds elapsed since start of the current reward duration, at from_time and to_time
+    fn get_elapsed_range(&self, from_time: u64, to_time: u64) -> Result<(u64, u64)> {
+        let start_time = self.reward_duration_end.safe_sub(self.reward_duration)?;
+        let from = max(from_time, start_time).safe_sub(start_time)?;
+        let to = max(to_time, start_time).safe_sub(start_time)?;
+        Ok((from, to))
+    }
+
+    /// Duration is split into steps of equal length, step i emits weights[i] / sum(weights) of the total reward
+    fn get_stepwise_scaled_emission(&self, from: u64, to: u64, weights: &[u64]) -> Result<u128> {
+        let duration = self.reward_duration;
+        let number_of_steps = weights.len() as u64;
+        let total_weight = weights
+            .iter()
+            .try_fold(0u64, |acc, weight| acc.safe_add(*weight))?;
+
+        let mut emission = 0u128;
+        for (step, weight) in weights.iter().enumerate() {
+            let step = step as u64;
+            let step_start = duration.safe_mul(step)?.safe_div(number_of_steps)?;
+            let step_end = duration
+                .safe_mul(step.safe_add(1)?)?
+                .safe_div(number_of_steps)?;
+            let overlap = min(to, step_end).saturating_sub(max(from, step_start));
+            if overlap == 0 {
+                continue;
+            }
+
+            // reward of the step is reward_rate * duration * weight / total_weight, emitted evenly over the step
+            let step_emission = safe_mul_div_cast_u128(
+                self.reward_rate,
+                u128::from(duration)
+                    .safe_mul((*weight).into())?
+                    .safe_mul(overlap.into())?,
+                u128::from(total_weight).safe_mul(step_end.safe_sub(step_start)?.into())?,
+                Rounding::Down,
+            )?;
+            emission = emission.safe_add(step_emission)?;
+        }
+
+        Ok(emission)
+    }
+
     /// Farming rate after funding
     pub fn update_rate_after_funding(
         &mut self,
@@ -354,12 +661,8 @@ impl RewardInfo {
         let total_amount = if current_time >= reward_duration_end {
             funding_amount
         } else {
-            let remaining_seconds = reward_duration_end.safe_sub(current_time)?;
-            let leftover: u64 = safe_mul_shr_cast(
-                self.reward_rate,
-                remaining_seconds.into(),
-                REWARD_RATE_SCALE,
-            )?;
+            // leftover is emitted again from the start of the new reward duration
+            let leftover = self.get_emission(current_time, reward_duration_end)?;
 
             funding_amount.safe_add(leftover)?
         };
@@ -1041,6 +1344,31 @@ impl Pool {
         Ok(())
     }
 
+    pub fn apply_revoke_vesting(
+        &self,
+        position: &mut Position,
+        revoke_position: &mut Position,
+        revoked_liquidity: u128,
+    ) -> Result<()> {
+        // update current fee for both positions before moving liquidity
+        position.update_fee(self.fee_a_per_liquidity(), self.fee_b_per_liquidity())?;
+        revoke_position.update_fee(self.fee_a_per_liquidity(), self.fee_b_per_liquidity())?;
+
+        let position_total_liquidity = position.get_total_liquidity()?;
+
+        position.remove_vested_liquidity(revoked_liquidity)?;
+        revoke_position.add_liquidity(revoked_liquidity)?;
+
+        // move cost basis along with revoked liquidity
+        position.cost_basis.split(
+            &mut revoke_position.cost_basis,
+            revoked_liquidity,
+            position_total_liquidity,
+        )?;
+
+        Ok(())
+    }
+
     pub fn apply_split_position(
         &self,
         first_position: &mut Position,
@@ -1057,6 +1385,8 @@ impl Pool {
         // update current fee for second position
         second_position.update_fee(self.fee_a_per_liquidity(), self.fee_b_per_liquidity())?;
 
+        let first_position_total_liquidity = first_position.get_total_liquidity()?;
+
         let mut unlocked_liquidity_split = 0;
         let mut permanent_locked_liquidity_split = 0;
         let mut fee_a_split = 0;
@@ -1131,6 +1461,13 @@ impl Pool {
             }
         }
 
+        // move cost basis along with liquidity
+        first_position.cost_basis.split(
+            &mut second_position.cost_basis,
+            unlocked_liquidity_split.safe_add(permanent_locked_liquidity_split)?,
+            first_position_total_liquidity,
+        )?;
+
         Ok(SplitAmountInfo {
             unlocked_liquidity: unlocked_liquidity_split,
             permanent_locked_liquidity: permanent_locked_liquidity_split,
@@ -1227,30 +1564,68 @@ impl Pool {
         Ok((token_a_amount, token_b_amount))
     }
 
-    /// Update the rewards per token stored.
-    pub fn update_rewards(&mut self, current_time: u64) -> Result<()> {
+    /// Update the rewards per token stored. Each reward is updated with the current point of its time unit
+    pub fn update_rewards(&mut self, current_timestamp: u64, current_slot: u64) -> Result<()> {
+        let locked_liquidity = self.get_boosted_locked_liquidity()?;
         for reward_idx in 0..NUM_REWARDS {
             let reward_info = &mut self.reward_infos[reward_idx];
-            reward_info.update_rewards(self.liquidity, current_time)?;
+            let liquidity_supply =
+                reward_info.get_boosted_liquidity(self.liquidity, locked_liquidity)?;
+            let current_point = reward_info.get_current_point(
+                self.activation_type,
+                current_timestamp,
+                current_slot,
+            );
+            reward_info.update_rewards(liquidity_supply, current_point)?;
         }
 
         Ok(())
     }
 
-    pub fn claim_ineligible_reward(&mut self, reward_index: usize) -> Result<u64> {
-        // calculate ineligible reward
-        let reward_info = &mut self.reward_infos[reward_index];
-        let ineligible_reward: u64 = safe_mul_shr_cast(
-            reward_info
-                .cumulative_seconds_with_empty_liquidity_reward
-                .into(),
-            reward_info.reward_rate,
-            REWARD_RATE_SCALE,
-        )?;
+    /// Whether a reward of the pool or of its reward extension is boosted
+    pub fn has_boosted_reward(&self) -> bool {
+        self.has_boosted_extended_reward()
+            || self
+                .reward_infos
+                .iter()
+                .any(|reward_info| reward_info.initialized() && reward_info.is_boosted())
+    }
 
-        reward_info.cumulative_seconds_with_empty_liquidity_reward = 0;
+    /// Locked liquidity eligible for reward boost
+    pub fn get_boosted_locked_liquidity(&self) -> Result<u128> {
+        Ok(self
+            .permanent_lock_liquidity
+            .safe_add(self.boosted_vested_liquidity)?)
+    }
 
-        Ok(ineligible_reward)
+    /// Count newly vested liquidity of position in boosted liquidity supply. Rewards must be updated before
+    pub fn lock_boosted_vested_liquidity(
+        &mut self,
+        position: &mut Position,
+        liquidity: u128,
+    ) -> Result<()> {
+        position.boosted_vested_liquidity =
+            position.boosted_vested_liquidity.safe_add(liquidity)?;
+        self.boosted_vested_liquidity = self.boosted_vested_liquidity.safe_add(liquidity)?;
+        Ok(())
+    }
+
+    /// Remove released or revoked vested liquidity of position from boosted liquidity supply. Rewards must be updated before.
+    /// Vested liquidity locked before the pool has boosted rewards is never counted, so only the counted part is removed
+    pub fn unlock_boosted_vested_liquidity(
+        &mut self,
+        position: &mut Position,
+        liquidity: u128,
+    ) -> Result<()> {
+        let liquidity = liquidity.min(position.boosted_vested_liquidity);
+        position.boosted_vested_liquidity =
+            position.boosted_vested_liquidity.safe_sub(liquidity)?;
+        self.boosted_vested_liquidity = self.boosted_vested_liquidity.safe_sub(liquidity)?;
+        Ok(())
+    }
+
+    pub fn claim_ineligible_reward(&mut self, reward_index: usize) -> Result<u64> {
+        self.reward_infos[reward_index].claim_ineligible_reward()
     }
 
     pub fn fee_a_per_liquidity(&self) -> U256 {
@@ -1265,10 +1640,158 @@ impl Pool {
         signer == self.creator && reward_index == 0
     }
 
+    pub fn is_lbp_enabled(&self) -> bool {
+        self.lbp_end_sqrt_max_price != 0
+    }
+
+    /// Start liquidity bootstrapping: sqrt max price declines linearly from activation point to end point
+    pub fn initialize_lbp(&mut self, end_sqrt_max_price: u128, end_point: u64) -> Result<()> {
+        require!(!self.is_lbp_enabled(), PoolError::InvalidLbpParameters);
+        require!(
+            end_sqrt_max_price > self.sqrt_min_price && end_sqrt_max_price < self.sqrt_max_price,
+            PoolError::InvalidLbpParameters
+        );
+        require!(
+            end_point > self.activation_point,
+            PoolError::InvalidLbpParameters
+        );
+
+        self.lbp_end_sqrt_max_price = end_sqrt_max_price;
+        self.lbp_last_update_point = self.activation_point;
+        self.lbp_end_point = end_point;
+
+        Ok(())
+    }
+
+    /// Liquidity bootstrapping sqrt max price at current point
+    pub fn get_lbp_sqrt_max_price(&self, current_point: u64) -> Result<u128> {
+        if !self.is_lbp_enabled() || current_point <= self.lbp_last_update_point {
+            return Ok(self.sqrt_max_price);
+        }
+        if current_point >= self.lbp_end_point {
+            return Ok(self.lbp_end_sqrt_max_price);
+        }
+
+        let elapsed = current_point.safe_sub(self.lbp_last_update_point)?;
+        let duration = self.lbp_end_point.safe_sub(self.lbp_last_update_point)?;
+        let total_decline = self.sqrt_max_price.safe_sub(self.lbp_end_sqrt_max_price)?;
+        // round down decline, so sqrt max price never goes below the schedule
+        let decline = safe_mul_div_cast_u128(
+            total_decline,
+            elapsed.into(),
+            duration.into(),
+            Rounding::Down,
+        )?;
+
+        Ok(self.sqrt_max_price.safe_sub(decline)?)
+    }
+
+    /// Move sqrt max price down the liquidity bootstrapping schedule. Positions keep their liquidity:
+    /// price above the new sqrt max price is moved down to it, and reserve of the range
+    /// leaving the curve is shared to liquidity providers as fees.
+    /// Must be called before swaps and liquidity changes
+    pub fn update_lbp_price_bound(&mut self, current_point: u64) -> Result<()> {
+        let sqrt_max_price = self.get_lbp_sqrt_max_price(current_point)?;
+        if sqrt_max_price == self.sqrt_max_price {
+            return Ok(());
+        }
+
+        let sqrt_price = min(self.sqrt_price, sqrt_max_price);
+        if self.liquidity > 0 {
+            // token a between max(sqrt_price, new sqrt max price) and old sqrt max price
+            let released_a_amount = get_delta_amount_a_unsigned(
+                max(self.sqrt_price, sqrt_max_price),
+                self.sqrt_max_price,
+                self.liquidity,
+                Rounding::Down,
+            )?;
+            // token b between new sqrt price and old sqrt price
+            let released_b_amount = get_delta_amount_b_unsigned(
+                sqrt_price,
+                self.sqrt_price,
+                self.liquidity,
+                Rounding::Down,
+            )?;
+
+            let fee_a_per_token_stored =
+                shl_div_256(released_a_amount.into(), self.liquidity, LIQUIDITY_SCALE)
+                    .ok_or_else(|| PoolError::MathOverflow)?;
+            let fee_b_per_token_stored =
+                shl_div_256(released_b_amount.into(), self.liquidity, LIQUIDITY_SCALE)
+                    .ok_or_else(|| PoolError::MathOverflow)?;
+            self.fee_a_per_liquidity = self
+                .fee_a_per_liquidity()
+                .safe_add(fee_a_per_token_stored)?
+                .to_le_bytes();
+            self.fee_b_per_liquidity = self
+                .fee_b_per_liquidity()
+                .safe_add(fee_b_per_token_stored)?
+                .to_le_bytes();
+        }
+
+        self.sqrt_price = sqrt_price;
+        self.sqrt_max_price = sqrt_max_price;
+        self.lbp_last_update_point = min(current_point, self.lbp_end_point);
+
+        Ok(())
+    }
+
+    pub fn is_permissioned(&self) -> bool {
+        self.metrics.permissioned_flag == 1
+    }
+
+    /// Returns true if the pool has no liquidity, no position and no unclaimed protocol or partner fee
+    pub fn is_empty(&self) -> bool {
+        self.liquidity == 0
+            && self.metrics.total_position == 0
+            && self.protocol_a_fee == 0
+            && self.protocol_b_fee == 0
+            && self.partner_a_fee == 0
+            && self.partner_b_fee == 0
+    }
+
+    pub fn has_reward_extension(&self) -> bool {
+        self.reward_extension_flag & REWARD_EXTENSION_ENABLED_FLAG != 0
+    }
+
+    pub fn has_boosted_extended_reward(&self) -> bool {
+        self.reward_extension_flag & REWARD_EXTENSION_BOOSTED_FLAG != 0
+    }
+
+    pub fn set_boosted_extended_reward(&mut self, is_boosted: bool) {
+        if is_boosted {
+            self.reward_extension_flag |= REWARD_EXTENSION_BOOSTED_FLAG;
+        } else {
+            self.reward_extension_flag &= !REWARD_EXTENSION_BOOSTED_FLAG;
+        }
+    }
+
     pub fn has_partner(&self) -> bool {
         self.partner != Pubkey::default()
     }
 
+    /// Move partner fee share to a new address, allowed when the pool creator is the partner.
+    /// Fee of the previous partner address must be claimed first
+    pub fn update_partner(&mut self, new_partner: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.has_partner() && self.partner == self.creator,
+            PoolError::InvalidAuthority
+        );
+        require!(
+            new_partner != Pubkey::default() && new_partner != self.partner,
+            PoolError::InvalidPartnerAccount
+        );
+        require!(
+            self.partner_a_fee == 0 && self.partner_b_fee == 0,
+            PoolError::PartnerFeeNotClaimed
+        );
+
+        let previous_partner = self.partner;
+        self.partner = new_partner;
+
+        Ok(previous_partner)
+    }
+
     pub fn get_reserves_amount(&self) -> Result<(u64, u64)> {
         let reserve_b_amount = get_delta_amount_b_unsigned(
             self.sqrt_min_price,
@@ -1287,6 +1810,99 @@ impl Pool {
         Ok((reserve_a_amount, reserve_b_amount))
     }
 
+    /// Set a new price range around the current price. Positions keep their liquidity, so
+    /// widening a bound needs more reserve at current liquidity, returned as amounts to deposit.
+    /// Narrowing a bound would leave reserve not owned by any position, so it requires the pool to have no liquidity.
+    pub fn update_price_range(
+        &mut self,
+        sqrt_min_price: u128,
+        sqrt_max_price: u128,
+    ) -> Result<ModifyLiquidityResult> {
+        require!(
+            sqrt_min_price >= MIN_SQRT_PRICE
+                && sqrt_max_price <= MAX_SQRT_PRICE
+                && sqrt_min_price < sqrt_max_price,
+            PoolError::InvalidPriceRange
+        );
+        require!(
+            self.sqrt_price >= sqrt_min_price && self.sqrt_price <= sqrt_max_price,
+            PoolError::InvalidPriceRange
+        );
+        require!(
+            sqrt_min_price != self.sqrt_min_price || sqrt_max_price != self.sqrt_max_price,
+            PoolError::InvalidPriceRange
+        );
+
+        // liquidity bootstrapping schedule owns sqrt max price until it ends
+        require!(
+            !self.is_lbp_enabled() || self.sqrt_max_price == self.lbp_end_sqrt_max_price,
+            PoolError::InvalidLbpParameters
+        );
+
+        let is_narrowing =
+            sqrt_min_price > self.sqrt_min_price || sqrt_max_price < self.sqrt_max_price;
+        require!(
+            !is_narrowing || self.liquidity == 0,
+            PoolError::PriceRangeNarrowingRequiresNoLiquidity
+        );
+
+        let token_a_amount = if sqrt_max_price > self.sqrt_max_price {
+            get_delta_amount_a_unsigned(
+                self.sqrt_max_price,
+                sqrt_max_price,
+                self.liquidity,
+                Rounding::Up,
+            )?
+        } else {
+            0
+        };
+        let token_b_amount = if sqrt_min_price < self.sqrt_min_price {
+            get_delta_amount_b_unsigned(
+                sqrt_min_price,
+                self.sqrt_min_price,
+                self.liquidity,
+                Rounding::Up,
+            )?
+        } else {
+            0
+        };
+
+        self.sqrt_min_price = sqrt_min_price;
+        self.sqrt_max_price = sqrt_max_price;
+
+        Ok(ModifyLiquidityResult {
+            token_a_amount,
+            token_b_amount,
+        })
+    }
+
+    /// Migrate the pool one version at a time up to the target version.
+    /// Each step migrates the fee validation domain and any field introduced by the next version.
+    pub fn upgrade_version(&mut self, target_version: u8) -> Result<()> {
+        require!(
+            target_version > self.version && target_version <= CURRENT_POOL_VERSION,
+            PoolError::InvalidPoolVersion
+        );
+
+        while self.version < target_version {
+            let version =
+                PoolVersion::try_from(self.version).map_err(|_| PoolError::InvalidPoolVersion)?;
+            match version {
+                PoolVersion::V0 => self.migrate_v0_to_v1()?,
+                // latest version, nothing to migrate to
+                PoolVersion::V1 => return Err(PoolError::InvalidPoolVersion.into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// V1 raises max fee from 50% to 99%, fees valid in V0 stay valid in V1. No new field
+    fn migrate_v0_to_v1(&mut self) -> Result<()> {
+        self.version = PoolVersion::V1.into();
+        Ok(())
+    }
+
     pub fn validate_and_update_pool_fees(
         &mut self,
         params: &UpdatePoolFeesParameters,
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+/// Pending transfer of the pool creator authority, accepted by the new creator
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PoolCreatorTransfer {
+    pub pool: Pubkey,
+    /// Creator who started the transfer, receives rent when the transfer is accepted or cancelled
+    pub creator: Pubkey,
+    /// Creator after the transfer is accepted
+    pub new_creator: Pubkey,
+    /// padding for future usage
+    pub padding: [u128; 2],
+}
+
+const_assert_eq!(PoolCreatorTransfer::INIT_SPACE, 128);
+
+impl PoolCreatorTransfer {
+    pub fn initialize(&mut self, pool: Pubkey, creator: Pubkey, new_creator: Pubkey) {
+        self.pool = pool;
+        self.creator = creator;
+        self.new_creator = new_creator;
+    }
+}
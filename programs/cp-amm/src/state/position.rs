@@ -47,6 +47,13 @@ impl UserRewardInfo {
     pub fn reward_per_token_checkpoint(&self) -> U256 {
         U256::from_le_bytes(self.reward_per_token_checkpoint)
     }
+
+    pub fn claim_reward(&mut self) -> u64 {
+        let total_reward = self.reward_pendings;
+        self.total_claimed_rewards = self.total_claimed_rewards.wrapping_add(total_reward);
+        self.reward_pendings = 0;
+        total_reward
+    }
 }
 
 #[account(zero_copy)]
@@ -73,8 +80,10 @@ pub struct Position {
     pub metrics: PositionMetrics,
     /// Farming reward information
     pub reward_infos: [UserRewardInfo; NUM_REWARDS],
-    /// padding for future usage
-    pub padding: [u128; 6],
+    /// Deposit and withdrawal history of the position
+    pub cost_basis: PositionCostBasis,
+    /// Vested liquidity counted in boosted liquidity supply of the pool
+    pub boosted_vested_liquidity: u128,
 }
 
 const_assert_eq!(Position::INIT_SPACE, 400);
@@ -88,6 +97,123 @@ pub struct PositionMetrics {
 
 const_assert_eq!(PositionMetrics::INIT_SPACE, 16);
 
+#[zero_copy]
+#[derive(Debug, InitSpace, Default, PartialEq)]
+pub struct PositionCostBasis {
+    /// Pool sqrt price at the first deposit
+    pub first_deposit_sqrt_price: u128,
+    /// Pool sqrt price at the latest deposit or withdrawal
+    pub last_event_sqrt_price: u128,
+    /// Total token a deposited to the pool
+    pub total_deposited_a_amount: u64,
+    /// Total token b deposited to the pool
+    pub total_deposited_b_amount: u64,
+    /// Total token a withdrawn from the pool
+    pub total_withdrawn_a_amount: u64,
+    /// Total token b withdrawn from the pool
+    pub total_withdrawn_b_amount: u64,
+    /// Timestamp of the first deposit
+    pub first_deposit_timestamp: u64,
+    /// Timestamp of the latest deposit or withdrawal
+    pub last_event_timestamp: u64,
+}
+
+const_assert_eq!(PositionCostBasis::INIT_SPACE, 80);
+
+impl PositionCostBasis {
+    fn update_event(&mut self, sqrt_price: u128, current_time: u64) {
+        if self.first_deposit_timestamp == 0 {
+            self.first_deposit_sqrt_price = sqrt_price;
+            self.first_deposit_timestamp = current_time;
+        }
+        self.last_event_sqrt_price = sqrt_price;
+        self.last_event_timestamp = current_time;
+    }
+
+    pub fn accumulate_deposit(
+        &mut self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+        sqrt_price: u128,
+        current_time: u64,
+    ) -> Result<()> {
+        self.total_deposited_a_amount = self.total_deposited_a_amount.safe_add(token_a_amount)?;
+        self.total_deposited_b_amount = self.total_deposited_b_amount.safe_add(token_b_amount)?;
+        self.update_event(sqrt_price, current_time);
+        Ok(())
+    }
+
+    pub fn accumulate_withdrawal(
+        &mut self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+        sqrt_price: u128,
+        current_time: u64,
+    ) -> Result<()> {
+        self.total_withdrawn_a_amount = self.total_withdrawn_a_amount.safe_add(token_a_amount)?;
+        self.total_withdrawn_b_amount = self.total_withdrawn_b_amount.safe_add(token_b_amount)?;
+        self.update_event(sqrt_price, current_time);
+        Ok(())
+    }
+
+    /// Move cost basis to another position, in proportion of liquidity moved over total liquidity before the move
+    pub fn split(
+        &mut self,
+        other: &mut PositionCostBasis,
+        liquidity_delta: u128,
+        total_liquidity: u128,
+    ) -> Result<()> {
+        if liquidity_delta == 0 || total_liquidity == 0 {
+            return Ok(());
+        }
+
+        let split_amount = |amount: u64| -> Result<u64> {
+            let amount = safe_mul_div_cast_u128(
+                amount.into(),
+                liquidity_delta,
+                total_liquidity,
+                Rounding::Down,
+            )?;
+            Ok(u64::try_from(amount).map_err(|_| PoolError::TypeCastFailed)?)
+        };
+
+        let deposited_a_delta = split_amount(self.total_deposited_a_amount)?;
+        let deposited_b_delta = split_amount(self.total_deposited_b_amount)?;
+        let withdrawn_a_delta = split_amount(self.total_withdrawn_a_amount)?;
+        let withdrawn_b_delta = split_amount(self.total_withdrawn_b_amount)?;
+
+        self.total_deposited_a_amount =
+            self.total_deposited_a_amount.safe_sub(deposited_a_delta)?;
+        self.total_deposited_b_amount =
+            self.total_deposited_b_amount.safe_sub(deposited_b_delta)?;
+        self.total_withdrawn_a_amount =
+            self.total_withdrawn_a_amount.safe_sub(withdrawn_a_delta)?;
+        self.total_withdrawn_b_amount =
+            self.total_withdrawn_b_amount.safe_sub(withdrawn_b_delta)?;
+
+        other.total_deposited_a_amount =
+            other.total_deposited_a_amount.safe_add(deposited_a_delta)?;
+        other.total_deposited_b_amount =
+            other.total_deposited_b_amount.safe_add(deposited_b_delta)?;
+        other.total_withdrawn_a_amount =
+            other.total_withdrawn_a_amount.safe_add(withdrawn_a_delta)?;
+        other.total_withdrawn_b_amount =
+            other.total_withdrawn_b_amount.safe_add(withdrawn_b_delta)?;
+
+        // moved liquidity keeps its entry price and time
+        if other.first_deposit_timestamp == 0 {
+            other.first_deposit_sqrt_price = self.first_deposit_sqrt_price;
+            other.first_deposit_timestamp = self.first_deposit_timestamp;
+        }
+        if other.last_event_timestamp <= self.last_event_timestamp {
+            other.last_event_sqrt_price = self.last_event_sqrt_price;
+            other.last_event_timestamp = self.last_event_timestamp;
+        }
+
+        Ok(())
+    }
+}
+
 impl PositionMetrics {
     pub fn accumulate_claimed_fee(
         &mut self,
@@ -125,6 +251,13 @@ impl Position {
             .safe_add(self.permanent_locked_liquidity)?)
     }
 
+    /// Locked liquidity eligible for reward boost
+    pub fn get_boosted_locked_liquidity(&self) -> Result<u128> {
+        Ok(self
+            .permanent_locked_liquidity
+            .safe_add(self.boosted_vested_liquidity)?)
+    }
+
     pub fn lock(&mut self, total_lock_liquidity: u128) -> Result<()> {
         require!(
             self.has_sufficient_liquidity(total_lock_liquidity),
@@ -234,6 +367,11 @@ impl Position {
         Ok(())
     }
 
+    pub fn remove_vested_liquidity(&mut self, liquidity_delta: u128) -> Result<()> {
+        self.vested_liquidity = self.vested_liquidity.safe_sub(liquidity_delta)?;
+        Ok(())
+    }
+
     pub fn release_vested_liquidity(&mut self, released_liquidity: u128) -> Result<()> {
         self.vested_liquidity = self.vested_liquidity.safe_sub(released_liquidity)?;
         self.add_liquidity(released_liquidity)?;
@@ -255,11 +393,16 @@ impl Position {
         self.fee_b_pending = 0;
     }
 
-    pub fn update_rewards(&mut self, pool: &mut RefMut<'_, Pool>, current_time: u64) -> Result<()> {
+    pub fn update_rewards(
+        &mut self,
+        pool: &mut RefMut<'_, Pool>,
+        current_timestamp: u64,
+        current_slot: u64,
+    ) -> Result<()> {
         // update if reward has been initialized
         if pool.pool_reward_initialized() {
             // update pool reward before any update about position reward
-            pool.update_rewards(current_time)?;
+            pool.update_rewards(current_timestamp, current_slot)?;
             // update position reward
             self.update_position_reward(pool)?;
         }
@@ -269,6 +412,7 @@ impl Position {
 
     pub fn update_position_reward(&mut self, pool: &Pool) -> Result<()> {
         let position_liquidity = self.get_total_liquidity()?;
+        let locked_liquidity = self.get_boosted_locked_liquidity()?;
         let position_reward_infos = &mut self.reward_infos;
         for reward_idx in 0..NUM_REWARDS {
             let pool_reward_info = pool.reward_infos[reward_idx];
@@ -276,8 +420,10 @@ impl Position {
             if pool_reward_info.initialized() {
                 let reward_per_token_stored =
                     U256::from_le_bytes(pool_reward_info.reward_per_token_stored);
+                let boosted_liquidity =
+                    pool_reward_info.get_boosted_liquidity(position_liquidity, locked_liquidity)?;
                 position_reward_infos[reward_idx]
-                    .update_rewards(position_liquidity, reward_per_token_stored)?;
+                    .update_rewards(boosted_liquidity, reward_per_token_stored)?;
             }
         }
 
@@ -315,6 +461,7 @@ impl Position {
         U256::from_le_bytes(self.fee_b_per_token_checkpoint)
     }
 
+    /// Rewards of the pool reward extension are tracked in the position reward extension, checked separately on close
     pub fn is_empty(&self) -> Result<bool> {
         // check reward
         for i in 0..NUM_REWARDS {
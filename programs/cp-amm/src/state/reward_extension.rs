@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use alloy_primitives::U256;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    state::{Pool, Position, RewardInfo, UserRewardInfo},
+    PoolError,
+};
+
+/// Bit of Pool::reward_extension_flag set once the pool has a reward extension account
+pub const REWARD_EXTENSION_ENABLED_FLAG: u8 = 1;
+/// Bit of Pool::reward_extension_flag set while a reward of the extension is boosted
+pub const REWARD_EXTENSION_BOOSTED_FLAG: u8 = 1 << 1;
+
+/// Additional rewards of a pool, addressed by reward index from NUM_REWARDS
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct RewardExtension {
+    pub pool: Pubkey,
+    /// padding for future usage
+    pub padding: [u128; 4],
+    /// Farming reward information
+    pub reward_infos: [RewardInfo; NUM_EXTENDED_REWARDS],
+}
+
+const_assert_eq!(RewardExtension::INIT_SPACE, 1632);
+
+impl RewardExtension {
+    pub fn initialize(&mut self, pool: Pubkey) {
+        self.pool = pool;
+    }
+
+    /// Update the rewards per token stored the same way as pool rewards, with boosted liquidity supply and reward time unit.
+    /// Must be called before any change of pool liquidity, or of locked liquidity when a reward is boosted
+    pub fn update_rewards(
+        &mut self,
+        pool: &Pool,
+        current_timestamp: u64,
+        current_slot: u64,
+    ) -> Result<()> {
+        let locked_liquidity = pool.get_boosted_locked_liquidity()?;
+        for reward_info in self.reward_infos.iter_mut() {
+            let liquidity_supply =
+                reward_info.get_boosted_liquidity(pool.liquidity, locked_liquidity)?;
+            let current_point = reward_info.get_current_point(
+                pool.activation_type,
+                current_timestamp,
+                current_slot,
+            );
+            reward_info.update_rewards(liquidity_supply, current_point)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn has_boosted_reward(&self) -> bool {
+        self.reward_infos
+            .iter()
+            .any(|reward_info| reward_info.initialized() && reward_info.is_boosted())
+    }
+
+    pub fn get_reward_info(&self, reward_index: usize) -> Result<&RewardInfo> {
+        let index = get_extended_reward_index(reward_index)?;
+        Ok(&self.reward_infos[index])
+    }
+
+    pub fn get_reward_info_mut(&mut self, reward_index: usize) -> Result<&mut RewardInfo> {
+        let index = get_extended_reward_index(reward_index)?;
+        Ok(&mut self.reward_infos[index])
+    }
+}
+
+/// Rewards of a position for the pool reward extension
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct PositionRewardExtension {
+    pub position: Pubkey,
+    /// padding for future usage
+    pub padding: [u128; 2],
+    /// Farming reward information
+    pub reward_infos: [UserRewardInfo; NUM_EXTENDED_REWARDS],
+}
+
+const_assert_eq!(PositionRewardExtension::INIT_SPACE, 448);
+
+impl PositionRewardExtension {
+    /// Liquidity of a position without reward extension can change without updating extended rewards,
+    /// so the position earns extended rewards from the creation of its reward extension.
+    pub fn initialize(&mut self, position: Pubkey, reward_extension: &RewardExtension) {
+        self.position = position;
+
+        for (user_reward_info, reward_info) in self
+            .reward_infos
+            .iter_mut()
+            .zip(reward_extension.reward_infos.iter())
+        {
+            user_reward_info.reward_per_token_checkpoint = reward_info.reward_per_token_stored;
+        }
+    }
+
+    /// Update position rewards with boosted liquidity of the position, must be called before any change of
+    /// position liquidity, or of locked liquidity when a reward is boosted
+    pub fn update_position_reward(
+        &mut self,
+        position: &Position,
+        reward_extension: &RewardExtension,
+    ) -> Result<()> {
+        let position_liquidity = position.get_total_liquidity()?;
+        let locked_liquidity = position.get_boosted_locked_liquidity()?;
+        for (user_reward_info, reward_info) in self
+            .reward_infos
+            .iter_mut()
+            .zip(reward_extension.reward_infos.iter())
+        {
+            if reward_info.initialized() {
+                let boosted_liquidity =
+                    reward_info.get_boosted_liquidity(position_liquidity, locked_liquidity)?;
+                user_reward_info.update_rewards(
+                    boosted_liquidity,
+                    U256::from_le_bytes(reward_info.reward_per_token_stored),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn claim_reward(&mut self, reward_index: usize) -> Result<u64> {
+        let index = get_extended_reward_index(reward_index)?;
+        Ok(self.reward_infos[index].claim_reward())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reward_infos
+            .iter()
+            .all(|reward_info| reward_info.reward_pendings == 0)
+    }
+}
+
+pub fn is_extended_reward_index(reward_index: usize) -> bool {
+    reward_index >= NUM_REWARDS
+}
+
+/// Index in the reward extension of a reward index
+pub fn get_extended_reward_index(reward_index: usize) -> Result<usize> {
+    let index = reward_index
+        .checked_sub(NUM_REWARDS)
+        .ok_or(PoolError::InvalidRewardIndex)?;
+    require!(index < NUM_EXTENDED_REWARDS, PoolError::InvalidRewardIndex);
+    Ok(index)
+}
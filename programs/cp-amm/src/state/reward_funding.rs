@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::REWARD_RATE_SCALE,
+    safe_math::SafeMath,
+    u128x128_math::Rounding,
+    utils_math::{safe_mul_shr_cast, safe_shl_div_cast},
+    PoolError,
+};
+
+/// Funding of a reward in permissionless funding mode, shared by all funders of the reward
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct RewardFunding {
+    pub pool: Pubkey,
+    pub reward_index: u8,
+    pub _padding_0: [u8; 15],
+    /// Ineligible reward distributed per funded amount, scaled by REWARD_RATE_SCALE
+    pub ineligible_reward_per_funded_amount: u128,
+    /// Total amount funded by all funders
+    pub total_funded_amount: u128,
+    /// Number of funder ledgers not closed yet
+    pub number_of_funder_ledgers: u64,
+    pub _padding_1: u64,
+    /// padding for future usage
+    pub padding: [u128; 1],
+}
+
+const_assert_eq!(RewardFunding::INIT_SPACE, 112);
+
+impl RewardFunding {
+    pub fn initialize(&mut self, pool: Pubkey, reward_index: u8) {
+        self.pool = pool;
+        self.reward_index = reward_index;
+    }
+
+    /// Distribute ineligible reward to funders in proportion of their funded amount.
+    /// Returns the amount left undistributed when nothing is funded
+    pub fn distribute_ineligible_reward(&mut self, ineligible_reward: u64) -> Result<u64> {
+        if ineligible_reward == 0 {
+            return Ok(0);
+        }
+
+        if self.total_funded_amount == 0 {
+            return Ok(ineligible_reward);
+        }
+
+        let delta: u128 = safe_shl_div_cast(
+            ineligible_reward.into(),
+            self.total_funded_amount,
+            REWARD_RATE_SCALE,
+            Rounding::Down,
+        )?;
+        self.ineligible_reward_per_funded_amount =
+            self.ineligible_reward_per_funded_amount.safe_add(delta)?;
+
+        Ok(0)
+    }
+}
+
+/// Contribution of a funder to a reward in permissionless funding mode
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct RewardFunderLedger {
+    pub reward_funding: Pubkey,
+    pub funder: Pubkey,
+    /// Ineligible reward per funded amount of reward funding at last update
+    pub ineligible_reward_checkpoint: u128,
+    /// Total amount funded by the funder
+    pub funded_amount: u64,
+    /// Share of ineligible reward not withdrawn yet
+    pub pending_ineligible_reward: u64,
+    /// Total share of ineligible reward withdrawn
+    pub total_withdrawn_ineligible_reward: u64,
+    pub _padding_0: u64,
+    /// padding for future usage
+    pub padding: [u128; 2],
+}
+
+const_assert_eq!(RewardFunderLedger::INIT_SPACE, 144);
+
+impl RewardFunderLedger {
+    pub fn initialize(
+        &mut self,
+        reward_funding_key: Pubkey,
+        funder: Pubkey,
+        reward_funding: &RewardFunding,
+    ) {
+        self.reward_funding = reward_funding_key;
+        self.funder = funder;
+        self.ineligible_reward_checkpoint = reward_funding.ineligible_reward_per_funded_amount;
+    }
+
+    pub fn update_ineligible_reward(&mut self, reward_funding: &RewardFunding) -> Result<()> {
+        let new_ineligible_reward: u64 = safe_mul_shr_cast(
+            self.funded_amount.into(),
+            reward_funding
+                .ineligible_reward_per_funded_amount
+                .safe_sub(self.ineligible_reward_checkpoint)?,
+            REWARD_RATE_SCALE,
+        )?;
+        self.pending_ineligible_reward = self
+            .pending_ineligible_reward
+            .safe_add(new_ineligible_reward)?;
+        self.ineligible_reward_checkpoint = reward_funding.ineligible_reward_per_funded_amount;
+
+        Ok(())
+    }
+
+    /// Record new funding, ineligible reward must be distributed before
+    pub fn accumulate_funded_amount(
+        &mut self,
+        reward_funding: &mut RewardFunding,
+        amount: u64,
+    ) -> Result<()> {
+        self.update_ineligible_reward(reward_funding)?;
+        self.funded_amount = self.funded_amount.safe_add(amount)?;
+        reward_funding.total_funded_amount =
+            reward_funding.total_funded_amount.safe_add(amount.into())?;
+
+        Ok(())
+    }
+
+    pub fn claim_ineligible_reward(&mut self, reward_funding: &RewardFunding) -> Result<u64> {
+        self.update_ineligible_reward(reward_funding)?;
+
+        let ineligible_reward = self.pending_ineligible_reward;
+        self.total_withdrawn_ineligible_reward = self
+            .total_withdrawn_ineligible_reward
+            .safe_add(ineligible_reward)?;
+        self.pending_ineligible_reward = 0;
+
+        Ok(ineligible_reward)
+    }
+
+    /// Remove the funded amount of the ledger from reward funding before the ledger is closed.
+    /// Share of ineligible reward must be withdrawn before
+    pub fn close(&mut self, reward_funding: &mut RewardFunding) -> Result<()> {
+        self.update_ineligible_reward(reward_funding)?;
+        require!(
+            self.pending_ineligible_reward == 0,
+            PoolError::PendingIneligibleReward
+        );
+
+        reward_funding.total_funded_amount = reward_funding
+            .total_funded_amount
+            .safe_sub(self.funded_amount.into())?;
+        reward_funding.number_of_funder_ledgers =
+            reward_funding.number_of_funder_ledgers.safe_sub(1)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+
+use crate::{
+    params::swap::TradeDirection,
+    safe_math::SafeMath,
+    state::{fee::FeeMode, SwapResult2},
+    u128x128_math::Rounding,
+    utils_math::{safe_mul_div_cast_u128, safe_mul_shr_cast},
+    PoolError,
+};
+
+/// How swaps earn points in a trading reward campaign. Points are measured in token b
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum TradingRewardPointMode {
+    /// Total fee paid by the swap, fee in token a is valued at pool price
+    Fee,
+    /// Token b amount swapped
+    Volume,
+}
+
+/// Reward for swappers of a pool, shared in proportion of their points earned between start point and end point
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct TradingRewardCampaign {
+    pub pool: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    /// Who can fund the campaign
+    pub funder: Pubkey,
+    /// Total points earned by all traders
+    pub total_points: u128,
+    /// Index of the campaign in the pool
+    pub campaign_index: u64,
+    /// Campaign start, in pool activation type
+    pub start_point: u64,
+    /// Campaign end, in pool activation type
+    pub end_point: u64,
+    /// Total reward funded
+    pub total_reward: u64,
+    /// Total reward claimed by traders or withdrawn by funder
+    pub total_claimed_reward: u64,
+    /// Point mode, see TradingRewardPointMode
+    pub point_mode: u8,
+    /// Token program flag of reward mint
+    pub reward_token_flag: u8,
+    pub _padding_0: [u8; 6],
+    /// Number of trader reward accounts not closed yet
+    pub number_of_trader_rewards: u64,
+    pub _padding_1: u64,
+    /// padding for future usage
+    pub padding: [u128; 1],
+}
+
+const_assert_eq!(TradingRewardCampaign::INIT_SPACE, 224);
+
+impl TradingRewardCampaign {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        pool: Pubkey,
+        reward_mint: Pubkey,
+        reward_vault: Pubkey,
+        funder: Pubkey,
+        campaign_index: u64,
+        start_point: u64,
+        end_point: u64,
+        point_mode: u8,
+        reward_token_flag: u8,
+    ) {
+        self.pool = pool;
+        self.reward_mint = reward_mint;
+        self.reward_vault = reward_vault;
+        self.funder = funder;
+        self.campaign_index = campaign_index;
+        self.start_point = start_point;
+        self.end_point = end_point;
+        self.point_mode = point_mode;
+        self.reward_token_flag = reward_token_flag;
+    }
+
+    pub fn is_active(&self, current_point: u64) -> bool {
+        current_point >= self.start_point && current_point < self.end_point
+    }
+
+    pub fn is_ended(&self, current_point: u64) -> bool {
+        current_point >= self.end_point
+    }
+
+    pub fn accumulate_reward(&mut self, amount: u64) -> Result<()> {
+        self.total_reward = self.total_reward.safe_add(amount)?;
+        Ok(())
+    }
+
+    /// Points earned by a swap, in token b
+    pub fn get_swap_points(
+        &self,
+        swap_result: &SwapResult2,
+        fee_mode: &FeeMode,
+        trade_direction: TradeDirection,
+        sqrt_price: u128,
+    ) -> Result<u128> {
+        let point_mode = TradingRewardPointMode::try_from(self.point_mode)
+            .map_err(|_| PoolError::TypeCastFailed)?;
+        let points = match point_mode {
+            TradingRewardPointMode::Fee => {
+                let total_fee = u128::from(swap_result.trading_fee)
+                    .safe_add(swap_result.protocol_fee.into())?
+                    .safe_add(swap_result.partner_fee.into())?
+                    .safe_add(swap_result.referral_fee.into())?;
+                if fee_mode.fees_on_token_a {
+                    get_token_a_value_in_token_b(total_fee, sqrt_price)?
+                } else {
+                    total_fee
+                }
+            }
+            TradingRewardPointMode::Volume => match trade_direction {
+                TradeDirection::AtoB => swap_result.output_amount.into(),
+                TradeDirection::BtoA => swap_result.included_fee_input_amount.into(),
+            },
+        };
+        Ok(points)
+    }
+
+    pub fn accumulate_points(
+        &mut self,
+        trader_reward: &mut TraderReward,
+        points: u128,
+    ) -> Result<()> {
+        self.total_points = self.total_points.safe_add(points)?;
+        trader_reward.points = trader_reward.points.safe_add(points)?;
+        Ok(())
+    }
+
+    /// Share of the reward of the trader, claimed or not. Campaign must be ended
+    fn get_trader_total_reward(&self, trader_reward: &TraderReward) -> Result<u64> {
+        if self.total_points == 0 {
+            return Ok(0);
+        }
+
+        let trader_total_reward = safe_mul_div_cast_u128(
+            self.total_reward.into(),
+            trader_reward.points,
+            self.total_points,
+            Rounding::Down,
+        )?
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+        Ok(trader_total_reward)
+    }
+
+    /// Claim reward of the trader not claimed yet. Campaign must be ended
+    pub fn claim_reward(&mut self, trader_reward: &mut TraderReward) -> Result<u64> {
+        if self.total_points == 0 {
+            return Ok(0);
+        }
+
+        let trader_total_reward = self.get_trader_total_reward(trader_reward)?;
+        let claimable_reward = trader_total_reward.safe_sub(trader_reward.total_claimed_reward)?;
+        trader_reward.total_claimed_reward = trader_total_reward;
+        self.total_claimed_reward = self.total_claimed_reward.safe_add(claimable_reward)?;
+
+        Ok(claimable_reward)
+    }
+
+    /// Remove the trader reward account from the campaign before it is closed.
+    /// Campaign must be ended and the reward of the trader claimed
+    pub fn close_trader_reward(&mut self, trader_reward: &TraderReward) -> Result<()> {
+        require!(
+            self.get_trader_total_reward(trader_reward)? == trader_reward.total_claimed_reward,
+            PoolError::TraderRewardNotClaimed
+        );
+        self.number_of_trader_rewards = self.number_of_trader_rewards.safe_sub(1)?;
+
+        Ok(())
+    }
+
+    /// Withdraw all reward when no trader earned points. Campaign must be ended
+    pub fn withdraw_ineligible_reward(&mut self) -> Result<u64> {
+        if self.total_points > 0 {
+            return Ok(0);
+        }
+
+        let ineligible_reward = self.total_reward.safe_sub(self.total_claimed_reward)?;
+        self.total_claimed_reward = self.total_reward;
+
+        Ok(ineligible_reward)
+    }
+}
+
+/// token_a_amount * sqrt_price^2 >> 128, rounding down
+fn get_token_a_value_in_token_b(token_a_amount: u128, sqrt_price: u128) -> Result<u128> {
+    let value: u128 = safe_mul_shr_cast(token_a_amount, sqrt_price, 64)?;
+    safe_mul_shr_cast(value, sqrt_price, 64)
+}
+
+/// Points of a trader in a trading reward campaign
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct TraderReward {
+    pub campaign: Pubkey,
+    pub trader: Pubkey,
+    /// Points earned by the trader
+    pub points: u128,
+    /// Total reward claimed by the trader
+    pub total_claimed_reward: u64,
+    pub _padding_0: u64,
+    /// padding for future usage
+    pub padding: [u128; 2],
+}
+
+const_assert_eq!(TraderReward::INIT_SPACE, 128);
+
+impl TraderReward {
+    pub fn initialize(&mut self, campaign: Pubkey, trader: Pubkey) {
+        self.campaign = campaign;
+        self.trader = trader;
+    }
+}
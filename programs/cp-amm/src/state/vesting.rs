@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
 
-use crate::safe_math::SafeMath;
+use crate::{
+    constants::{fee::MAX_BASIS_POINT, MAX_VESTING_SCHEDULE_LENGTH, ONE_Q64},
+    fee_math::pow,
+    safe_math::SafeMath,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u128,
+    PoolError,
+};
 
 #[account(zero_copy)]
 #[derive(InitSpace, Debug, Default)]
@@ -14,7 +22,10 @@ pub struct Vesting {
     pub total_released_liquidity: u128,
     pub number_of_period: u16,
     pub padding: [u8; 14],
-    pub padding2: [u128; 4],
+    /// Authority that can revoke locked liquidity, default pubkey if vesting is not revocable
+    pub revoker: Pubkey,
+    /// Position receiving locked liquidity when vesting is revoked
+    pub revoke_position: Pubkey,
 }
 
 const_assert_eq!(Vesting::INIT_SPACE, 176);
@@ -37,6 +48,15 @@ impl Vesting {
         self.number_of_period = number_of_period;
     }
 
+    pub fn set_revoker(&mut self, revoker: Pubkey, revoke_position: Pubkey) {
+        self.revoker = revoker;
+        self.revoke_position = revoke_position;
+    }
+
+    pub fn is_revocable(&self) -> bool {
+        self.revoker != Pubkey::default()
+    }
+
     pub fn get_total_lock_amount(&self) -> Result<u128> {
         let total_amount = self.cliff_unlock_liquidity.safe_add(
             self.liquidity_per_period
@@ -46,6 +66,14 @@ impl Vesting {
         Ok(total_amount)
     }
 
+    /// Liquidity which is not released yet
+    pub fn get_locked_liquidity(&self) -> Result<u128> {
+        let locked_liquidity = self
+            .get_total_lock_amount()?
+            .safe_sub(self.total_released_liquidity)?;
+        Ok(locked_liquidity)
+    }
+
     pub fn get_max_unlocked_liquidity(&self, current_point: u64) -> Result<u128> {
         if current_point < self.cliff_point {
             return Ok(0);
@@ -84,3 +112,187 @@ impl Vesting {
         Ok(self.total_released_liquidity == self.get_total_lock_amount()?)
     }
 }
+
+/// Curve of liquidity unlocked by periods after the cliff, for extended vesting
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum VestingCurveType {
+    /// Equal amount each period
+    Linear,
+    /// unlocked = periodic_unlock_liquidity * (period / number_of_period) ^ curve_parameter, back-loaded
+    Polynomial,
+    /// Each period unlocks (1 + curve_parameter / 10_000) times the previous one, back-loaded
+    Exponential,
+    /// Explicit amount per period stored in schedule table
+    Custom,
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug, Default)]
+pub struct ExtendedVesting {
+    pub position: Pubkey,
+    pub cliff_point: u64,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    /// Total liquidity unlocked by periods after the cliff
+    pub periodic_unlock_liquidity: u128,
+    pub total_released_liquidity: u128,
+    pub number_of_period: u16,
+    /// VestingCurveType
+    pub curve_type: u8,
+    pub padding_0: u8,
+    /// Exponent for polynomial curve, growth rate in bps for exponential curve
+    pub curve_parameter: u32,
+    pub padding_1: [u8; 8],
+    pub padding_2: [u128; 4],
+    /// Liquidity unlocked in each period, only used by custom curve
+    pub schedule: [u128; MAX_VESTING_SCHEDULE_LENGTH],
+}
+
+const_assert_eq!(ExtendedVesting::INIT_SPACE, 688);
+
+impl ExtendedVesting {
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        cliff_point: u64,
+        period_frequency: u64,
+        cliff_unlock_liquidity: u128,
+        periodic_unlock_liquidity: u128,
+        number_of_period: u16,
+        curve_type: u8,
+        curve_parameter: u32,
+        schedule: &[u128],
+    ) {
+        self.position = position;
+        self.cliff_point = cliff_point;
+        self.period_frequency = period_frequency;
+        self.cliff_unlock_liquidity = cliff_unlock_liquidity;
+        self.periodic_unlock_liquidity = periodic_unlock_liquidity;
+        self.number_of_period = number_of_period;
+        self.curve_type = curve_type;
+        self.curve_parameter = curve_parameter;
+        self.schedule[..schedule.len()].copy_from_slice(schedule);
+    }
+
+    pub fn get_total_lock_amount(&self) -> Result<u128> {
+        let total_amount = self
+            .cliff_unlock_liquidity
+            .safe_add(self.periodic_unlock_liquidity)?;
+        Ok(total_amount)
+    }
+
+    /// Liquidity unlocked by periods after `passed_period` periods since the cliff
+    pub fn get_periodic_unlocked_liquidity(&self, passed_period: u16) -> Result<u128> {
+        if passed_period >= self.number_of_period {
+            return Ok(self.periodic_unlock_liquidity);
+        }
+
+        let curve_type = VestingCurveType::try_from(self.curve_type)
+            .map_err(|_| PoolError::InvalidVestingInfo)?;
+
+        let unlocked_liquidity = match curve_type {
+            VestingCurveType::Linear => safe_mul_div_cast_u128(
+                self.periodic_unlock_liquidity,
+                passed_period.into(),
+                self.number_of_period.into(),
+                Rounding::Down,
+            )?,
+            VestingCurveType::Polynomial => {
+                let numerator = u128::from(passed_period)
+                    .checked_pow(self.curve_parameter)
+                    .ok_or(PoolError::MathOverflow)?;
+                let denominator = u128::from(self.number_of_period)
+                    .checked_pow(self.curve_parameter)
+                    .ok_or(PoolError::MathOverflow)?;
+                safe_mul_div_cast_u128(
+                    self.periodic_unlock_liquidity,
+                    numerator,
+                    denominator,
+                    Rounding::Down,
+                )?
+            }
+            VestingCurveType::Exponential => {
+                // periodic_unlock_liquidity * (r ^ passed_period - 1) / (r ^ number_of_period - 1)
+                let growth_rate = get_vesting_growth_rate(self.curve_parameter)?;
+                let numerator = pow(growth_rate, passed_period.into())
+                    .ok_or(PoolError::MathOverflow)?
+                    .safe_sub(ONE_Q64)?;
+                let denominator = pow(growth_rate, self.number_of_period.into())
+                    .ok_or(PoolError::MathOverflow)?
+                    .safe_sub(ONE_Q64)?;
+                safe_mul_div_cast_u128(
+                    self.periodic_unlock_liquidity,
+                    numerator,
+                    denominator,
+                    Rounding::Down,
+                )?
+            }
+            VestingCurveType::Custom => {
+                let mut unlocked_liquidity: u128 = 0;
+                for amount in self.schedule.iter().take(passed_period.into()) {
+                    unlocked_liquidity = unlocked_liquidity.safe_add(*amount)?;
+                }
+                unlocked_liquidity
+            }
+        };
+
+        Ok(unlocked_liquidity)
+    }
+
+    pub fn get_max_unlocked_liquidity(&self, current_point: u64) -> Result<u128> {
+        if current_point < self.cliff_point {
+            return Ok(0);
+        }
+
+        if self.period_frequency == 0 {
+            return Ok(self.cliff_unlock_liquidity);
+        }
+
+        let period = current_point
+            .safe_sub(self.cliff_point)?
+            .safe_div(self.period_frequency)?;
+
+        let period = u16::try_from(period.min(self.number_of_period.into()))
+            .map_err(|_| PoolError::TypeCastFailed)?;
+
+        let unlocked_liquidity = self
+            .cliff_unlock_liquidity
+            .safe_add(self.get_periodic_unlocked_liquidity(period)?)?;
+
+        Ok(unlocked_liquidity)
+    }
+
+    pub fn get_new_release_liquidity(&self, current_point: u64) -> Result<u128> {
+        let unlocked_liquidity = self.get_max_unlocked_liquidity(current_point)?;
+        let new_releasing_liquidity = unlocked_liquidity.safe_sub(self.total_released_liquidity)?;
+        Ok(new_releasing_liquidity)
+    }
+
+    pub fn accumulate_released_liquidity(&mut self, released_liquidity: u128) -> Result<()> {
+        self.total_released_liquidity =
+            self.total_released_liquidity.safe_add(released_liquidity)?;
+        Ok(())
+    }
+
+    pub fn done(&self) -> Result<bool> {
+        Ok(self.total_released_liquidity == self.get_total_lock_amount()?)
+    }
+}
+
+/// 1 + growth_bps / 10_000 in Q64.64
+pub fn get_vesting_growth_rate(growth_bps: u32) -> Result<u128> {
+    let growth = u128::from(growth_bps)
+        .safe_shl(64)?
+        .safe_div(MAX_BASIS_POINT.into())?;
+    Ok(ONE_Q64.safe_add(growth)?)
+}
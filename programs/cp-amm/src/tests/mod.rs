@@ -39,3 +39,66 @@ mod test_operator_permission;
 
 #[cfg(test)]
 mod test_base_fee_serde;
+
+#[cfg(test)]
+mod test_position_nft_metadata;
+
+#[cfg(test)]
+mod test_extended_vesting;
+
+#[cfg(test)]
+mod test_revoke_vesting;
+
+#[cfg(test)]
+mod test_position_cost_basis;
+
+#[cfg(test)]
+mod test_reward_extension;
+
+#[cfg(test)]
+mod test_reward_boost;
+
+#[cfg(test)]
+mod test_reward_emission;
+
+#[cfg(test)]
+mod test_reward_funding;
+
+#[cfg(test)]
+mod test_reward_time_unit;
+
+#[cfg(test)]
+mod test_trading_reward;
+
+#[cfg(test)]
+mod test_pool_status;
+
+#[cfg(test)]
+mod test_allowlist;
+
+#[cfg(test)]
+mod test_close_pool;
+
+#[cfg(test)]
+mod test_upgrade_pool_version;
+
+#[cfg(test)]
+mod test_update_pool_price_range;
+
+#[cfg(test)]
+mod test_pool_creator;
+
+#[cfg(test)]
+mod test_pre_activation_buyer;
+
+#[cfg(test)]
+mod test_lbp;
+
+#[cfg(test)]
+mod test_global_pause;
+
+#[cfg(test)]
+mod test_update_config;
+
+#[cfg(test)]
+mod test_admin_state;
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{AdminState, MAX_ADMINS};
+
+fn get_admin_state(admins: &[Pubkey]) -> AdminState {
+    let mut admin_state = AdminState::default();
+    admin_state.initialize(admins).unwrap();
+    admin_state
+}
+
+#[test]
+fn test_admin_threshold() {
+    let admins = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let mut admin_state = get_admin_state(&admins);
+    assert_eq!(admin_state.threshold, 1);
+    assert!(admin_state.is_authorized(&admins[0], &[]));
+    assert!(!admin_state.is_authorized(&Pubkey::new_unique(), &admins));
+    assert!(!admin_state.is_authorized(&Pubkey::default(), &[]));
+
+    admin_state.update_threshold(2).unwrap();
+    assert!(!admin_state.is_authorized(&admins[0], &[]));
+    // same admin passed again is counted once
+    assert!(!admin_state.is_authorized(&admins[0], &[admins[0], admins[0]]));
+    assert!(!admin_state.is_authorized(&admins[0], &[Pubkey::new_unique()]));
+    assert!(admin_state.is_authorized(&admins[0], &[admins[2]]));
+
+    assert!(admin_state.update_threshold(0).is_err());
+    assert!(admin_state.update_threshold(4).is_err());
+    admin_state.update_threshold(3).unwrap();
+    assert!(!admin_state.is_authorized(&admins[1], &[admins[2]]));
+    assert!(admin_state.is_authorized(&admins[1], &[admins[2], admins[0]]));
+}
+
+#[test]
+fn test_admin_rotation() {
+    let admins = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut admin_state = get_admin_state(&admins);
+    let new_admin = Pubkey::new_unique();
+
+    // invalid proposals
+    assert!(admin_state
+        .propose_rotation(Pubkey::new_unique(), new_admin)
+        .is_err());
+    assert!(admin_state
+        .propose_rotation(admins[0], Pubkey::default())
+        .is_err());
+    assert!(admin_state.propose_rotation(admins[0], admins[1]).is_err());
+
+    admin_state.propose_rotation(admins[0], new_admin).unwrap();
+    // only proposed admin can accept
+    assert!(admin_state.accept_rotation(Pubkey::new_unique()).is_err());
+    assert!(admin_state.is_admin(&admins[0]));
+
+    admin_state.accept_rotation(new_admin).unwrap();
+    assert!(!admin_state.is_admin(&admins[0]));
+    assert!(admin_state.is_admin(&new_admin));
+    assert!(admin_state.is_admin(&admins[1]));
+    assert_eq!(admin_state.pending_new_admin, Pubkey::default());
+    // rotation can not be accepted twice
+    assert!(admin_state.accept_rotation(new_admin).is_err());
+}
+
+#[test]
+fn test_add_admin() {
+    let mut admin_state = get_admin_state(&[Pubkey::new_unique()]);
+
+    for number_of_admins in 2..=MAX_ADMINS {
+        let new_admin = Pubkey::new_unique();
+        admin_state
+            .propose_rotation(Pubkey::default(), new_admin)
+            .unwrap();
+        admin_state.accept_rotation(new_admin).unwrap();
+        assert_eq!(admin_state.get_number_of_admins(), number_of_admins);
+    }
+
+    // no empty slot left
+    assert!(admin_state
+        .propose_rotation(Pubkey::default(), Pubkey::new_unique())
+        .is_err());
+}
+
+#[cfg(not(feature = "local"))]
+#[test]
+fn test_assert_admin_signers() {
+    use crate::assert_admin_signers;
+
+    let admins = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let mut admin_state = get_admin_state(&admins);
+    let outsider = Pubkey::new_unique();
+
+    assert!(assert_admin_signers(&admin_state, admins[1], &[]));
+    assert!(!assert_admin_signers(&admin_state, outsider, &[admins[0]]));
+
+    // threshold counts the signer and admin co-signers once each
+    admin_state.update_threshold(2).unwrap();
+    assert!(!assert_admin_signers(&admin_state, admins[0], &[]));
+    assert!(!assert_admin_signers(
+        &admin_state,
+        admins[0],
+        &[admins[0], outsider]
+    ));
+    assert!(assert_admin_signers(&admin_state, admins[0], &[admins[1]]));
+    assert!(!assert_admin_signers(
+        &admin_state,
+        outsider,
+        &[admins[0], admins[1]]
+    ));
+
+    // rotated out admin no longer counts, new admin does
+    let new_admin = Pubkey::new_unique();
+    admin_state.propose_rotation(admins[2], new_admin).unwrap();
+    assert!(!assert_admin_signers(&admin_state, new_admin, &[admins[0]]));
+    admin_state.accept_rotation(new_admin).unwrap();
+    assert!(assert_admin_signers(&admin_state, new_admin, &[admins[0]]));
+    assert!(!assert_admin_signers(&admin_state, admins[2], &[admins[0]]));
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{AllowlistEntry, AllowlistPermission, Pool, PoolType, MAX_ALLOWLIST_PERMISSION};
+
+#[test]
+fn test_allowlist_entry_permission() {
+    let mut allowlist_entry = AllowlistEntry::default();
+    allowlist_entry.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0b01);
+    assert!(allowlist_entry.is_permission_allow(AllowlistPermission::Swap));
+    assert!(!allowlist_entry.is_permission_allow(AllowlistPermission::ProvideLiquidity));
+
+    allowlist_entry.permission = 0b10;
+    assert!(!allowlist_entry.is_permission_allow(AllowlistPermission::Swap));
+    assert!(allowlist_entry.is_permission_allow(AllowlistPermission::ProvideLiquidity));
+
+    allowlist_entry.permission = MAX_ALLOWLIST_PERMISSION;
+    assert!(allowlist_entry.is_permission_allow(AllowlistPermission::Swap));
+    assert!(allowlist_entry.is_permission_allow(AllowlistPermission::ProvideLiquidity));
+}
+
+#[test]
+fn test_permissioned_pool() {
+    let mut pool = Pool::default();
+    assert!(!pool.is_permissioned());
+
+    pool.pool_type = PoolType::Permissionless.into();
+    assert!(!pool.is_permissioned());
+
+    // allowlist keeps the pool type, config pools stay out of customizable pool endpoints
+    pool.metrics.permissioned_flag = 1;
+    assert!(pool.is_permissioned());
+    assert_eq!(pool.pool_type, u8::from(PoolType::Permissionless));
+}
@@ -0,0 +1,55 @@
+use crate::state::{Pool, RewardInfo};
+
+#[test]
+fn test_pool_is_empty() {
+    let pool = Pool::default();
+    assert!(pool.is_empty());
+
+    let mut pool = Pool::default();
+    pool.liquidity = 1;
+    assert!(!pool.is_empty());
+
+    let mut pool = Pool::default();
+    pool.metrics.increase_position();
+    assert!(!pool.is_empty());
+
+    let mut pool = Pool::default();
+    pool.protocol_b_fee = 1;
+    assert!(!pool.is_empty());
+
+    let mut pool = Pool::default();
+    pool.partner_a_fee = 1;
+    assert!(!pool.is_empty());
+}
+
+#[test]
+fn test_reward_is_finished() {
+    let reward_info = RewardInfo::default();
+    assert!(reward_info.is_finished(0));
+
+    let reward_info = RewardInfo {
+        initialized: 1,
+        reward_duration_end: 1_000,
+        ..Default::default()
+    };
+    assert!(!reward_info.is_finished(999));
+    assert!(!reward_info.is_finished(1_000));
+    assert!(reward_info.is_finished(1_001));
+
+    // ineligible reward must be withdrawn first
+    let reward_info = RewardInfo {
+        initialized: 1,
+        reward_duration_end: 1_000,
+        cumulative_seconds_with_empty_liquidity_reward: 10,
+        ..Default::default()
+    };
+    assert!(!reward_info.is_finished(1_001));
+
+    let reward_info = RewardInfo {
+        initialized: 1,
+        reward_duration_end: 1_000,
+        cumulative_ineligible_reward: 10,
+        ..Default::default()
+    };
+    assert!(!reward_info.is_finished(1_001));
+}
@@ -0,0 +1,139 @@
+use crate::{
+    state::{ExtendedVesting, VestingCurveType},
+    ExtendedVestingParameters,
+};
+
+fn get_vesting(
+    curve_type: VestingCurveType,
+    curve_parameter: u32,
+    number_of_period: u16,
+    periodic_unlock_liquidity: u128,
+    schedule: &[u128],
+) -> ExtendedVesting {
+    let mut vesting = ExtendedVesting::default();
+    vesting.initialize(
+        Default::default(),
+        100,
+        10,
+        1_000,
+        periodic_unlock_liquidity,
+        number_of_period,
+        curve_type.into(),
+        curve_parameter,
+        schedule,
+    );
+    vesting
+}
+
+fn assert_monotonic_and_complete(vesting: &ExtendedVesting) {
+    let mut previous_unlocked_liquidity = 0;
+    for period in 0..=vesting.number_of_period {
+        let unlocked_liquidity = vesting.get_periodic_unlocked_liquidity(period).unwrap();
+        assert!(unlocked_liquidity >= previous_unlocked_liquidity);
+        previous_unlocked_liquidity = unlocked_liquidity;
+    }
+    assert_eq!(
+        previous_unlocked_liquidity,
+        vesting.periodic_unlock_liquidity
+    );
+}
+
+#[test]
+fn test_extended_vesting_before_cliff() {
+    let vesting = get_vesting(VestingCurveType::Linear, 0, 10, 10_000, &[]);
+    assert_eq!(vesting.get_max_unlocked_liquidity(99).unwrap(), 0);
+    assert_eq!(vesting.get_max_unlocked_liquidity(100).unwrap(), 1_000);
+    assert_eq!(
+        vesting.get_max_unlocked_liquidity(u64::MAX).unwrap(),
+        11_000
+    );
+}
+
+#[test]
+fn test_extended_vesting_linear() {
+    let vesting = get_vesting(VestingCurveType::Linear, 0, 10, 10_000, &[]);
+    assert_eq!(vesting.get_max_unlocked_liquidity(110).unwrap(), 2_000);
+    assert_eq!(vesting.get_max_unlocked_liquidity(155).unwrap(), 6_000);
+    assert_monotonic_and_complete(&vesting);
+}
+
+#[test]
+fn test_extended_vesting_polynomial_is_back_loaded() {
+    let vesting = get_vesting(VestingCurveType::Polynomial, 2, 10, 10_000, &[]);
+    // (5 / 10) ^ 2 = 25%
+    assert_eq!(vesting.get_periodic_unlocked_liquidity(5).unwrap(), 2_500);
+    assert_monotonic_and_complete(&vesting);
+}
+
+#[test]
+fn test_extended_vesting_exponential_is_back_loaded() {
+    let vesting = get_vesting(VestingCurveType::Exponential, 5_000, 12, 1_000_000, &[]);
+    let first_half = vesting.get_periodic_unlocked_liquidity(6).unwrap();
+    assert!(first_half < vesting.periodic_unlock_liquidity / 2);
+    assert_monotonic_and_complete(&vesting);
+}
+
+#[test]
+fn test_extended_vesting_custom_schedule() {
+    let schedule = [0, 100, 0, 900];
+    let vesting = get_vesting(VestingCurveType::Custom, 0, 4, 1_000, &schedule);
+    assert_eq!(vesting.get_periodic_unlocked_liquidity(1).unwrap(), 0);
+    assert_eq!(vesting.get_periodic_unlocked_liquidity(2).unwrap(), 100);
+    assert_eq!(vesting.get_periodic_unlocked_liquidity(3).unwrap(), 100);
+    assert_monotonic_and_complete(&vesting);
+}
+
+#[test]
+fn test_extended_vesting_release() {
+    let mut vesting = get_vesting(VestingCurveType::Linear, 0, 2, 2_000, &[]);
+    let released_liquidity = vesting.get_new_release_liquidity(110).unwrap();
+    assert_eq!(released_liquidity, 2_000);
+    vesting
+        .accumulate_released_liquidity(released_liquidity)
+        .unwrap();
+    assert!(!vesting.done().unwrap());
+
+    let released_liquidity = vesting.get_new_release_liquidity(120).unwrap();
+    assert_eq!(released_liquidity, 1_000);
+    vesting
+        .accumulate_released_liquidity(released_liquidity)
+        .unwrap();
+    assert!(vesting.done().unwrap());
+}
+
+#[test]
+fn test_extended_vesting_parameters_validation() {
+    let params = ExtendedVestingParameters {
+        cliff_point: None,
+        period_frequency: 10,
+        cliff_unlock_liquidity: 0,
+        periodic_unlock_liquidity: 1_000,
+        number_of_period: 2,
+        curve_type: VestingCurveType::Custom.into(),
+        curve_parameter: 0,
+        schedule: vec![400, 600],
+    };
+    assert!(params.validate(0, 1_000).is_ok());
+
+    // schedule must add up to periodic unlock liquidity
+    let invalid_params = ExtendedVestingParameters {
+        schedule: vec![400, 500],
+        ..params.clone()
+    };
+    assert!(invalid_params.validate(0, 1_000).is_err());
+
+    // schedule is only allowed for custom curve
+    let invalid_params = ExtendedVestingParameters {
+        curve_type: VestingCurveType::Linear.into(),
+        ..params.clone()
+    };
+    assert!(invalid_params.validate(0, 1_000).is_err());
+
+    let invalid_params = ExtendedVestingParameters {
+        curve_type: VestingCurveType::Polynomial.into(),
+        curve_parameter: 5,
+        schedule: vec![],
+        ..params
+    };
+    assert!(invalid_params.validate(0, 1_000).is_err());
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    const_pda,
+    state::{GlobalPause, PauseAction},
+    validate_action_not_paused,
+};
+
+#[test]
+fn test_pause_and_unpause_actions() {
+    let mut global_pause = GlobalPause::default();
+    assert!(!global_pause.is_action_paused(PauseAction::Swap));
+
+    let swap_and_claim = 1 << u8::from(PauseAction::Swap) | 1 << u8::from(PauseAction::Claim);
+    global_pause.pause(swap_and_claim, 100).unwrap();
+    assert!(global_pause.is_action_paused(PauseAction::Swap));
+    assert!(global_pause.is_action_paused(PauseAction::Claim));
+    assert!(!global_pause.is_action_paused(PauseAction::AddLiquidity));
+    assert!(!global_pause.is_action_paused(PauseAction::RemoveLiquidity));
+    assert_eq!(global_pause.last_updated_at, 100);
+
+    // pausing is additive
+    global_pause
+        .pause(1 << u8::from(PauseAction::AddLiquidity), 200)
+        .unwrap();
+    assert!(global_pause.is_action_paused(PauseAction::Swap));
+    assert!(global_pause.is_action_paused(PauseAction::AddLiquidity));
+
+    global_pause
+        .unpause(1 << u8::from(PauseAction::Swap), 300)
+        .unwrap();
+    assert!(!global_pause.is_action_paused(PauseAction::Swap));
+    assert!(global_pause.is_action_paused(PauseAction::AddLiquidity));
+    assert!(global_pause.is_action_paused(PauseAction::Claim));
+    assert_eq!(global_pause.last_updated_at, 300);
+
+    global_pause.unpause(0b1111, 400).unwrap();
+    assert_eq!(global_pause.paused_actions, 0);
+}
+
+#[test]
+fn test_invalid_pause_actions() {
+    let mut global_pause = GlobalPause::default();
+    assert!(global_pause.pause(0, 0).is_err());
+    assert!(global_pause.pause(1 << 4, 0).is_err());
+    assert!(global_pause.unpause(0, 0).is_err());
+    assert!(global_pause.unpause(u8::MAX, 0).is_err());
+    assert_eq!(global_pause.paused_actions, 0);
+}
+
+fn global_pause_data(paused_actions: u8) -> Vec<u8> {
+    let global_pause = GlobalPause {
+        paused_actions,
+        ..Default::default()
+    };
+    let mut data = GlobalPause::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&global_pause));
+    data
+}
+
+#[test]
+fn test_validate_action_not_paused() {
+    let global_pause_key = const_pda::global_pause::ID;
+    let other_key = Pubkey::new_unique();
+    let system_program = anchor_lang::system_program::ID;
+    let mut lamports = [0u64; 3];
+    let [global_pause_lamports, uninitialized_lamports, other_lamports] = &mut lamports;
+    let mut paused_data = global_pause_data(1 << u8::from(PauseAction::Swap));
+    let mut uninitialized_data = vec![];
+    let mut other_data = vec![];
+
+    let global_pause = AccountInfo::new(
+        &global_pause_key,
+        false,
+        false,
+        global_pause_lamports,
+        &mut paused_data,
+        &crate::ID,
+        false,
+        0,
+    );
+    let uninitialized_global_pause = AccountInfo::new(
+        &global_pause_key,
+        false,
+        false,
+        uninitialized_lamports,
+        &mut uninitialized_data,
+        &system_program,
+        false,
+        0,
+    );
+    let other = AccountInfo::new(
+        &other_key,
+        false,
+        false,
+        other_lamports,
+        &mut other_data,
+        &system_program,
+        false,
+        0,
+    );
+
+    // not passed
+    let accounts = [other.clone()];
+    let rest = validate_action_not_paused(&accounts, PauseAction::Swap).unwrap();
+    assert_eq!(rest.len(), 1);
+    assert!(validate_action_not_paused(&[], PauseAction::Swap)
+        .unwrap()
+        .is_empty());
+
+    // not created yet
+    let accounts = [uninitialized_global_pause, other.clone()];
+    let rest = validate_action_not_paused(&accounts, PauseAction::Swap).unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].key(), other_key);
+
+    // paused action fails, other actions are open
+    let accounts = [global_pause, other];
+    assert!(validate_action_not_paused(&accounts, PauseAction::Swap).is_err());
+    let rest = validate_action_not_paused(&accounts, PauseAction::Claim).unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].key(), other_key);
+}
@@ -0,0 +1,131 @@
+use alloy_primitives::U256;
+
+use crate::{
+    curve::get_initialize_amounts,
+    state::{Pool, Position},
+};
+
+const SQRT_MIN_PRICE: u128 = 1 << 63;
+const SQRT_MAX_PRICE: u128 = 1 << 66;
+const END_SQRT_MAX_PRICE: u128 = 1 << 64;
+const ACTIVATION_POINT: u64 = 1_000;
+const END_POINT: u64 = 2_000;
+const LIQUIDITY: u128 = 1_000_000_000u128 << 64;
+
+fn get_lbp_pool(sqrt_price: u128, liquidity: u128) -> Pool {
+    let mut pool = Pool {
+        sqrt_price,
+        sqrt_min_price: SQRT_MIN_PRICE,
+        sqrt_max_price: SQRT_MAX_PRICE,
+        liquidity,
+        activation_point: ACTIVATION_POINT,
+        ..Default::default()
+    };
+    pool.initialize_lbp(END_SQRT_MAX_PRICE, END_POINT).unwrap();
+    pool
+}
+
+#[test]
+fn test_initialize_lbp_validation() {
+    let pool = Pool {
+        sqrt_price: SQRT_MIN_PRICE,
+        sqrt_min_price: SQRT_MIN_PRICE,
+        sqrt_max_price: SQRT_MAX_PRICE,
+        activation_point: ACTIVATION_POINT,
+        ..Default::default()
+    };
+
+    // end sqrt max price must be inside the price range
+    assert!({ pool }.initialize_lbp(SQRT_MAX_PRICE, END_POINT).is_err());
+    assert!({ pool }.initialize_lbp(SQRT_MIN_PRICE, END_POINT).is_err());
+    // end point must be after activation point
+    assert!({ pool }
+        .initialize_lbp(END_SQRT_MAX_PRICE, ACTIVATION_POINT)
+        .is_err());
+
+    let mut lbp_pool = pool;
+    lbp_pool
+        .initialize_lbp(END_SQRT_MAX_PRICE, END_POINT)
+        .unwrap();
+    assert!(lbp_pool.is_lbp_enabled());
+    // can not be initialized twice
+    assert!(lbp_pool
+        .initialize_lbp(END_SQRT_MAX_PRICE, END_POINT)
+        .is_err());
+}
+
+#[test]
+fn test_lbp_sqrt_max_price_schedule() {
+    let pool = get_lbp_pool(SQRT_MIN_PRICE, 0);
+
+    assert_eq!(pool.get_lbp_sqrt_max_price(0).unwrap(), SQRT_MAX_PRICE);
+    assert_eq!(
+        pool.get_lbp_sqrt_max_price(ACTIVATION_POINT).unwrap(),
+        SQRT_MAX_PRICE
+    );
+    assert_eq!(
+        pool.get_lbp_sqrt_max_price((ACTIVATION_POINT + END_POINT) / 2)
+            .unwrap(),
+        (SQRT_MAX_PRICE + END_SQRT_MAX_PRICE) / 2
+    );
+    assert_eq!(
+        pool.get_lbp_sqrt_max_price(END_POINT * 2).unwrap(),
+        END_SQRT_MAX_PRICE
+    );
+
+    // updating in steps follows the same line
+    let mut stepped_pool = pool;
+    for point in (ACTIVATION_POINT..=END_POINT).step_by(100) {
+        stepped_pool.update_lbp_price_bound(point).unwrap();
+        let expected = pool.get_lbp_sqrt_max_price(point).unwrap();
+        assert!(stepped_pool.sqrt_max_price.abs_diff(expected) <= 10);
+    }
+    assert_eq!(stepped_pool.sqrt_max_price, END_SQRT_MAX_PRICE);
+}
+
+#[test]
+fn test_lbp_pushes_price_down_and_releases_reserve_as_fee() {
+    // price above end sqrt max price
+    let sqrt_price = 1 << 65;
+    let mut pool = get_lbp_pool(sqrt_price, LIQUIDITY);
+    let mut position = Position {
+        unlocked_liquidity: LIQUIDITY,
+        ..Default::default()
+    };
+
+    let (reserve_a, reserve_b) =
+        get_initialize_amounts(SQRT_MIN_PRICE, SQRT_MAX_PRICE, sqrt_price, LIQUIDITY).unwrap();
+
+    pool.update_lbp_price_bound(END_POINT).unwrap();
+    assert_eq!(pool.sqrt_max_price, END_SQRT_MAX_PRICE);
+    assert_eq!(pool.sqrt_price, END_SQRT_MAX_PRICE);
+    assert_eq!(pool.lbp_last_update_point, END_POINT);
+
+    let (new_reserve_a, new_reserve_b) = get_initialize_amounts(
+        SQRT_MIN_PRICE,
+        END_SQRT_MAX_PRICE,
+        END_SQRT_MAX_PRICE,
+        LIQUIDITY,
+    )
+    .unwrap();
+    assert_eq!(new_reserve_a, 0);
+
+    position
+        .update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())
+        .unwrap();
+    // released reserve goes to liquidity providers, never more than the reserve leaving the curve
+    assert!(position.fee_a_pending > 0 && position.fee_a_pending <= reserve_a);
+    assert!(position.fee_b_pending > 0);
+    assert!(new_reserve_b + position.fee_b_pending <= reserve_b);
+}
+
+#[test]
+fn test_lbp_price_below_sqrt_max_price_unchanged() {
+    let mut pool = get_lbp_pool(SQRT_MIN_PRICE, LIQUIDITY);
+
+    pool.update_lbp_price_bound(END_POINT).unwrap();
+    assert_eq!(pool.sqrt_price, SQRT_MIN_PRICE);
+    assert_eq!(pool.sqrt_max_price, END_SQRT_MAX_PRICE);
+    // no token b reserve at min price
+    assert_eq!(pool.fee_b_per_liquidity(), U256::ZERO);
+}
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::Pubkey;
+
 use crate::{
     constants::MAX_OPERATION,
     state::{Operator, OperatorPermission},
@@ -92,3 +94,48 @@ fn test_is_permission_allow() {
         true
     );
 }
+
+#[test]
+fn test_operator_role_expiry() {
+    let whitelisted_address = Pubkey::new_unique();
+    let mut operator = Operator {
+        whitelisted_address,
+        permission: 0b1,
+        ..Default::default()
+    };
+
+    // role without expiry never expires
+    assert!(!operator.is_expired(u64::MAX));
+    assert!(operator.is_valid_role(
+        &whitelisted_address,
+        OperatorPermission::CreateConfigKey,
+        u64::MAX
+    ));
+    assert!(!operator.is_valid_role(
+        &Pubkey::new_unique(),
+        OperatorPermission::CreateConfigKey,
+        0
+    ));
+
+    operator.update_permission(0b10, 1_000);
+    assert!(!operator.is_valid_role(&whitelisted_address, OperatorPermission::CreateConfigKey, 0));
+    assert!(operator.is_valid_role(
+        &whitelisted_address,
+        OperatorPermission::RemoveConfigKey,
+        999
+    ));
+    assert!(operator.is_expired(1_000));
+    assert!(!operator.is_valid_role(
+        &whitelisted_address,
+        OperatorPermission::RemoveConfigKey,
+        1_000
+    ));
+
+    // removing expiry
+    operator.update_permission(0b10, 0);
+    assert!(operator.is_valid_role(
+        &whitelisted_address,
+        OperatorPermission::RemoveConfigKey,
+        1_000
+    ));
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::Pool;
+
+#[test]
+fn test_update_partner_when_creator_is_partner() {
+    let creator = Pubkey::new_unique();
+    let new_partner = Pubkey::new_unique();
+    let mut pool = Pool {
+        creator,
+        partner: creator,
+        ..Default::default()
+    };
+
+    let previous_partner = pool.update_partner(new_partner).unwrap();
+    assert_eq!(previous_partner, creator);
+    assert_eq!(pool.partner, new_partner);
+
+    // partner is no longer the creator
+    assert!(pool.update_partner(Pubkey::new_unique()).is_err());
+}
+
+#[test]
+fn test_update_partner_rejected() {
+    let creator = Pubkey::new_unique();
+
+    // no partner
+    let mut pool = Pool {
+        creator,
+        ..Default::default()
+    };
+    assert!(pool.update_partner(Pubkey::new_unique()).is_err());
+
+    // partner is another account
+    let mut pool = Pool {
+        creator,
+        partner: Pubkey::new_unique(),
+        ..Default::default()
+    };
+    assert!(pool.update_partner(Pubkey::new_unique()).is_err());
+
+    let mut pool = Pool {
+        creator,
+        partner: creator,
+        ..Default::default()
+    };
+    // partner can not be removed
+    assert!(pool.update_partner(Pubkey::default()).is_err());
+
+    // pending partner fee
+    pool.partner_b_fee = 1;
+    assert!(pool.update_partner(Pubkey::new_unique()).is_err());
+}
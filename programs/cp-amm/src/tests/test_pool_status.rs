@@ -0,0 +1,27 @@
+use crate::state::PoolStatus;
+
+#[test]
+fn test_pool_status_allowed_actions() {
+    // (status, swap, add liquidity, position management)
+    let cases = [
+        (PoolStatus::Enable, true, true, true),
+        (PoolStatus::Disable, false, false, false),
+        (PoolStatus::SwapDisabled, false, true, true),
+        (PoolStatus::WithdrawOnly, false, false, true),
+        (PoolStatus::ReduceOnly, true, false, true),
+    ];
+
+    for (status, swap, add_liquidity, position_management) in cases {
+        assert_eq!(status.is_swap_allowed(), swap);
+        assert_eq!(status.is_add_liquidity_allowed(), add_liquidity);
+        assert_eq!(status.is_position_management_allowed(), position_management);
+    }
+}
+
+#[test]
+fn test_pool_status_serde() {
+    assert_eq!(u8::from(PoolStatus::Enable), 0);
+    assert_eq!(u8::from(PoolStatus::Disable), 1);
+    assert_eq!(PoolStatus::try_from(4).unwrap(), PoolStatus::ReduceOnly);
+    assert!(PoolStatus::try_from(5).is_err());
+}
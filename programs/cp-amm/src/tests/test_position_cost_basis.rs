@@ -0,0 +1,56 @@
+use crate::state::PositionCostBasis;
+
+#[test]
+fn test_accumulate_cost_basis() {
+    let mut cost_basis = PositionCostBasis::default();
+
+    cost_basis.accumulate_deposit(100, 200, 1_000, 10).unwrap();
+    cost_basis.accumulate_deposit(50, 100, 2_000, 20).unwrap();
+    cost_basis.accumulate_withdrawal(30, 60, 3_000, 30).unwrap();
+
+    assert_eq!(cost_basis.total_deposited_a_amount, 150);
+    assert_eq!(cost_basis.total_deposited_b_amount, 300);
+    assert_eq!(cost_basis.total_withdrawn_a_amount, 30);
+    assert_eq!(cost_basis.total_withdrawn_b_amount, 60);
+    assert_eq!(cost_basis.first_deposit_sqrt_price, 1_000);
+    assert_eq!(cost_basis.first_deposit_timestamp, 10);
+    assert_eq!(cost_basis.last_event_sqrt_price, 3_000);
+    assert_eq!(cost_basis.last_event_timestamp, 30);
+}
+
+#[test]
+fn test_split_cost_basis() {
+    let mut first_cost_basis = PositionCostBasis::default();
+    first_cost_basis
+        .accumulate_deposit(100, 201, 1_000, 10)
+        .unwrap();
+    first_cost_basis
+        .accumulate_withdrawal(10, 20, 2_000, 20)
+        .unwrap();
+
+    let mut second_cost_basis = PositionCostBasis::default();
+    first_cost_basis
+        .split(&mut second_cost_basis, 1_000, 4_000)
+        .unwrap();
+
+    assert_eq!(second_cost_basis.total_deposited_a_amount, 25);
+    assert_eq!(second_cost_basis.total_deposited_b_amount, 50);
+    assert_eq!(second_cost_basis.total_withdrawn_a_amount, 2);
+    assert_eq!(second_cost_basis.total_withdrawn_b_amount, 5);
+    assert_eq!(first_cost_basis.total_deposited_a_amount, 75);
+    assert_eq!(first_cost_basis.total_deposited_b_amount, 151);
+    assert_eq!(first_cost_basis.total_withdrawn_a_amount, 8);
+    assert_eq!(first_cost_basis.total_withdrawn_b_amount, 15);
+
+    // moved liquidity keeps entry price
+    assert_eq!(second_cost_basis.first_deposit_sqrt_price, 1_000);
+    assert_eq!(second_cost_basis.first_deposit_timestamp, 10);
+    assert_eq!(second_cost_basis.last_event_sqrt_price, 2_000);
+
+    // nothing moved
+    let mut third_cost_basis = PositionCostBasis::default();
+    first_cost_basis
+        .split(&mut third_cost_basis, 0, 3_000)
+        .unwrap();
+    assert_eq!(third_cost_basis, PositionCostBasis::default());
+}
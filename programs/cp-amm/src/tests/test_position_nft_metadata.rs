@@ -0,0 +1,60 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{
+    constants::position_nft_metadata::{MAX_LIQUIDITY_LENGTH, MAX_SYMBOL_LENGTH},
+    position_nft_metadata::{
+        get_position_nft_liquidity_attributes, parse_metaplex_symbol, sanitize_symbol,
+    },
+    state::Position,
+};
+
+fn build_metaplex_metadata(mint: &Pubkey, name: &str, symbol: &str) -> Vec<u8> {
+    let mut data = vec![4u8];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+    data.extend_from_slice(symbol.as_bytes());
+    data.extend_from_slice(&[0u8; 4]);
+    data
+}
+
+#[test]
+fn test_parse_metaplex_symbol() {
+    let mint = Pubkey::new_unique();
+    let data = build_metaplex_metadata(&mint, "USD Coin\0\0\0\0", "USDC\0\0\0\0\0\0");
+    assert_eq!(
+        parse_metaplex_symbol(&data, &mint),
+        Some(String::from("USDC"))
+    );
+
+    // metadata of another mint
+    assert_eq!(parse_metaplex_symbol(&data, &Pubkey::new_unique()), None);
+
+    // truncated data
+    assert_eq!(parse_metaplex_symbol(&data[..70], &mint), None);
+}
+
+#[test]
+fn test_sanitize_symbol() {
+    assert_eq!(sanitize_symbol(" SOL "), "SOL");
+    assert_eq!(sanitize_symbol("ABCDEFGHIJKLMNOP").len(), MAX_SYMBOL_LENGTH);
+    // never cut in the middle of a multi bytes character
+    let symbol = sanitize_symbol("ABCDEFGHI\u{1F600}");
+    assert_eq!(symbol, "ABCDEFGHI");
+}
+
+#[test]
+fn test_liquidity_attributes_fit_reserved_space() {
+    let position = Position {
+        unlocked_liquidity: u128::MAX,
+        vested_liquidity: u128::MAX,
+        permanent_locked_liquidity: u128::MAX,
+        ..Default::default()
+    };
+
+    for (_, value) in get_position_nft_liquidity_attributes(&position) {
+        assert_eq!(value.len(), MAX_LIQUIDITY_LENGTH);
+    }
+}
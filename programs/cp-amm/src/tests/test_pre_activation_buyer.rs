@@ -0,0 +1,88 @@
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use std::fs;
+
+use crate::{
+    alpha_vault::alpha_vault,
+    state::{Config, PreActivationBuyerMode},
+};
+
+fn get_config(vault_config_key: Pubkey) -> Config {
+    let mut config: Config = bytemuck::Zeroable::zeroed();
+    config.vault_config_key = vault_config_key;
+    config
+}
+
+#[test]
+fn test_existing_config_uses_alpha_vault() {
+    let config_account_data =
+        fs::read("./src/tests/fixtures/config_account.bin").expect("Failed to read account data");
+    let config: Config =
+        bytemuck::pod_read_unaligned(&config_account_data[Config::DISCRIMINATOR.len()..]);
+
+    assert_eq!(
+        config.get_pre_activation_buyer_mode().unwrap(),
+        PreActivationBuyerMode::AlphaVault
+    );
+    assert_eq!(config.pre_activation_buyer, Pubkey::default());
+}
+
+#[test]
+fn test_pre_activation_buyer_modes() {
+    let vault_config_key = Pubkey::new_unique();
+    let pool = Pubkey::new_unique();
+    let mut config = get_config(vault_config_key);
+
+    assert!(config.has_alpha_vault());
+    assert_eq!(
+        config.get_whitelisted_alpha_vault(pool).unwrap(),
+        alpha_vault::derive_vault_pubkey(vault_config_key, pool)
+    );
+
+    let launchpad_program = Pubkey::new_unique();
+    config
+        .update_pre_activation_buyer(PreActivationBuyerMode::ProgramDerived, launchpad_program)
+        .unwrap();
+    assert_eq!(
+        config.get_whitelisted_alpha_vault(pool).unwrap(),
+        alpha_vault::derive_vault_pubkey_with_program(launchpad_program, vault_config_key, pool)
+    );
+
+    let buyer = Pubkey::new_unique();
+    let mut config = get_config(Pubkey::default());
+    assert!(!config.has_alpha_vault());
+    config
+        .update_pre_activation_buyer(PreActivationBuyerMode::Address, buyer)
+        .unwrap();
+    assert!(config.has_alpha_vault());
+    assert_eq!(config.get_whitelisted_alpha_vault(pool).unwrap(), buyer);
+}
+
+#[test]
+fn test_invalid_pre_activation_buyer() {
+    let mut config = get_config(Pubkey::new_unique());
+    assert!(config
+        .update_pre_activation_buyer(PreActivationBuyerMode::AlphaVault, Pubkey::new_unique())
+        .is_err());
+    assert!(config
+        .update_pre_activation_buyer(PreActivationBuyerMode::ProgramDerived, Pubkey::default())
+        .is_err());
+    assert!(config
+        .update_pre_activation_buyer(PreActivationBuyerMode::Address, Pubkey::default())
+        .is_err());
+}
+
+#[test]
+fn test_has_alpha_vault_with_vault_config_key() {
+    let vault_config_key = Pubkey::new_unique();
+    let mut config = get_config(Pubkey::default());
+
+    assert!(config.has_alpha_vault_with_vault_config_key(vault_config_key));
+    assert!(!config.has_alpha_vault_with_vault_config_key(Pubkey::default()));
+
+    // address mode ignores vault config key
+    config
+        .update_pre_activation_buyer(PreActivationBuyerMode::Address, Pubkey::new_unique())
+        .unwrap();
+    assert!(config.has_alpha_vault_with_vault_config_key(Pubkey::default()));
+    assert!(config.has_alpha_vault_with_vault_config_key(vault_config_key));
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{Pool, Position, Vesting};
+
+fn get_vesting() -> Vesting {
+    let mut vesting = Vesting::default();
+    vesting.initialize(Default::default(), 100, 10, 1_000, 500, 4);
+    vesting
+}
+
+#[test]
+fn test_vesting_revoker() {
+    let mut vesting = get_vesting();
+    assert!(!vesting.is_revocable());
+
+    vesting.set_revoker(Pubkey::new_unique(), Pubkey::new_unique());
+    assert!(vesting.is_revocable());
+}
+
+#[test]
+fn test_locked_liquidity() {
+    let mut vesting = get_vesting();
+    assert_eq!(vesting.get_locked_liquidity().unwrap(), 3_000);
+
+    // cliff and 2 periods passed
+    let released_liquidity = vesting.get_new_release_liquidity(120).unwrap();
+    assert_eq!(released_liquidity, 2_000);
+    vesting
+        .accumulate_released_liquidity(released_liquidity)
+        .unwrap();
+    assert_eq!(vesting.get_locked_liquidity().unwrap(), 1_000);
+
+    // all periods passed
+    let released_liquidity = vesting.get_new_release_liquidity(1_000).unwrap();
+    vesting
+        .accumulate_released_liquidity(released_liquidity)
+        .unwrap();
+    assert_eq!(vesting.get_locked_liquidity().unwrap(), 0);
+}
+
+#[test]
+fn test_apply_revoke_vesting() {
+    let pool = Pool::default();
+    let mut position = Position::default();
+    position.add_liquidity(5_000).unwrap();
+    position.lock(3_000).unwrap();
+
+    let mut revoke_position = Position::default();
+
+    let mut vesting = get_vesting();
+    let released_liquidity = vesting.get_new_release_liquidity(110).unwrap();
+    position
+        .release_vested_liquidity(released_liquidity)
+        .unwrap();
+    vesting
+        .accumulate_released_liquidity(released_liquidity)
+        .unwrap();
+
+    let revoked_liquidity = vesting.get_locked_liquidity().unwrap();
+    pool.apply_revoke_vesting(&mut position, &mut revoke_position, revoked_liquidity)
+        .unwrap();
+
+    assert_eq!(position.vested_liquidity, 0);
+    assert_eq!(position.unlocked_liquidity, 2_000 + released_liquidity);
+    assert_eq!(revoke_position.unlocked_liquidity, revoked_liquidity);
+    assert_eq!(
+        position.get_total_liquidity().unwrap() + revoke_position.get_total_liquidity().unwrap(),
+        5_000
+    );
+
+    // revoke more than vested liquidity must fail
+    assert!(pool
+        .apply_revoke_vesting(&mut position, &mut revoke_position, 1)
+        .is_err());
+}
+
+#[test]
+fn test_apply_revoke_vesting_moves_cost_basis() {
+    let pool = Pool::default();
+    let mut position = Position::default();
+    position.add_liquidity(5_000).unwrap();
+    position.lock(3_000).unwrap();
+    position
+        .cost_basis
+        .accumulate_deposit(10_000, 20_000, 1 << 64, 100)
+        .unwrap();
+
+    let mut revoke_position = Position::default();
+
+    // revoke 30% of position liquidity
+    pool.apply_revoke_vesting(&mut position, &mut revoke_position, 1_500)
+        .unwrap();
+
+    assert_eq!(position.cost_basis.total_deposited_a_amount, 7_000);
+    assert_eq!(position.cost_basis.total_deposited_b_amount, 14_000);
+    assert_eq!(revoke_position.cost_basis.total_deposited_a_amount, 3_000);
+    assert_eq!(revoke_position.cost_basis.total_deposited_b_amount, 6_000);
+}
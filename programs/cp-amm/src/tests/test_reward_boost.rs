@@ -0,0 +1,93 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{Pool, Position, RewardInfo};
+
+const ONE_DAY: u64 = 60 * 60 * 24;
+const LIQUIDITY: u128 = 1 << 64;
+
+fn get_funded_pool(boost_multiplier_bps: u16, amount: u64) -> Pool {
+    let mut pool = Pool::default();
+    let reward_info = &mut pool.reward_infos[0];
+    reward_info.init_reward(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        ONE_DAY,
+        0,
+    );
+    reward_info.boost_multiplier_bps = boost_multiplier_bps;
+    reward_info.update_rate_after_funding(0, amount).unwrap();
+    pool
+}
+
+#[test]
+fn test_get_boosted_liquidity() {
+    let mut reward_info = RewardInfo::default();
+    assert_eq!(reward_info.get_boosted_liquidity(100, 50).unwrap(), 100);
+
+    // 1x weight is not a boost
+    reward_info.boost_multiplier_bps = 10_000;
+    assert!(!reward_info.is_boosted());
+    assert_eq!(reward_info.get_boosted_liquidity(100, 50).unwrap(), 100);
+
+    // 2.5x weight on locked liquidity
+    reward_info.boost_multiplier_bps = 25_000;
+    assert!(reward_info.is_boosted());
+    assert_eq!(reward_info.get_boosted_liquidity(100, 50).unwrap(), 175);
+}
+
+#[test]
+fn test_boosted_reward_distribution() {
+    let amount = 1_000_000;
+    // locked liquidity weights 3x
+    let mut pool = get_funded_pool(30_000, amount);
+    assert!(pool.has_boosted_reward());
+
+    let mut unlocked_position = Position::default();
+    unlocked_position.unlocked_liquidity = LIQUIDITY;
+
+    let mut permanent_locked_position = Position::default();
+    permanent_locked_position.unlocked_liquidity = LIQUIDITY;
+    permanent_locked_position
+        .permanent_lock_liquidity(LIQUIDITY)
+        .unwrap();
+    pool.liquidity = LIQUIDITY * 2;
+    pool.accumulate_permanent_locked_liquidity(LIQUIDITY)
+        .unwrap();
+
+    pool.update_rewards(ONE_DAY, 0).unwrap();
+    unlocked_position.update_position_reward(&pool).unwrap();
+    permanent_locked_position
+        .update_position_reward(&pool)
+        .unwrap();
+
+    // boosted supply is 4 liquidity, locked position gets 3 of them
+    let unlocked_reward = unlocked_position.reward_infos[0].reward_pendings;
+    let locked_reward = permanent_locked_position.reward_infos[0].reward_pendings;
+    assert!(unlocked_reward + locked_reward <= amount);
+    assert!(unlocked_reward + locked_reward >= amount - 2);
+    assert!(locked_reward.abs_diff(unlocked_reward * 3) <= 3);
+}
+
+#[test]
+fn test_lock_unlock_boosted_vested_liquidity() {
+    let mut pool = get_funded_pool(20_000, 1_000_000);
+    let mut position = Position::default();
+
+    // vested liquidity locked before boost is not counted
+    position.unlocked_liquidity = LIQUIDITY * 3;
+    position.lock(LIQUIDITY).unwrap();
+
+    position.lock(LIQUIDITY).unwrap();
+    pool.lock_boosted_vested_liquidity(&mut position, LIQUIDITY)
+        .unwrap();
+    assert_eq!(position.boosted_vested_liquidity, LIQUIDITY);
+    assert_eq!(pool.get_boosted_locked_liquidity().unwrap(), LIQUIDITY);
+
+    // release more than counted only remove counted part
+    position.release_vested_liquidity(LIQUIDITY * 2).unwrap();
+    pool.unlock_boosted_vested_liquidity(&mut position, LIQUIDITY * 2)
+        .unwrap();
+    assert_eq!(position.boosted_vested_liquidity, 0);
+    assert_eq!(pool.boosted_vested_liquidity, 0);
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{RewardEmissionSchedule, RewardInfo};
+
+const ONE_DAY: u64 = 60 * 60 * 24;
+const LIQUIDITY: u128 = 1 << 64;
+const AMOUNT: u64 = 1_000_000_000;
+
+fn get_funded_reward_info(
+    emission_schedule: RewardEmissionSchedule,
+    emission_parameter: u8,
+) -> RewardInfo {
+    let mut reward_info = RewardInfo::default();
+    reward_info.init_reward(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        ONE_DAY,
+        0,
+    );
+    reward_info.emission_schedule = emission_schedule.into();
+    reward_info.emission_parameter = emission_parameter;
+    reward_info.update_rate_after_funding(0, AMOUNT).unwrap();
+    reward_info
+}
+
+fn assert_approx_eq(actual: u64, expected: u64, tolerance: u64) {
+    assert!(
+        actual.abs_diff(expected) <= tolerance,
+        "actual {} expected {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_total_emission_of_schedules() {
+    for (emission_schedule, emission_parameter) in [
+        (RewardEmissionSchedule::Constant, 0),
+        (RewardEmissionSchedule::LinearDecay, 0),
+        (RewardEmissionSchedule::Halving, 2),
+        (RewardEmissionSchedule::Halving, 8),
+        (RewardEmissionSchedule::FrontLoaded, 80),
+        (RewardEmissionSchedule::FrontLoaded, 100),
+    ] {
+        let reward_info = get_funded_reward_info(emission_schedule, emission_parameter);
+        let total_emission = reward_info.get_emission(0, ONE_DAY * 2).unwrap();
+        assert!(total_emission <= AMOUNT);
+        assert_approx_eq(total_emission, AMOUNT, 10);
+    }
+}
+
+#[test]
+fn test_emission_curves() {
+    // linear decay emits 3/4 in the first half
+    let reward_info = get_funded_reward_info(RewardEmissionSchedule::LinearDecay, 0);
+    assert_approx_eq(
+        reward_info.get_emission(0, ONE_DAY / 2).unwrap(),
+        AMOUNT / 4 * 3,
+        2,
+    );
+
+    // 3 halving steps emit 4/7, 2/7 and 1/7
+    let reward_info = get_funded_reward_info(RewardEmissionSchedule::Halving, 3);
+    let step = ONE_DAY / 3;
+    assert_approx_eq(
+        reward_info.get_emission(0, step).unwrap(),
+        AMOUNT / 7 * 4,
+        4,
+    );
+    assert_approx_eq(
+        reward_info.get_emission(step, step * 2).unwrap(),
+        AMOUNT / 7 * 2,
+        4,
+    );
+    assert_approx_eq(
+        reward_info.get_emission(step * 2, ONE_DAY).unwrap(),
+        AMOUNT / 7,
+        4,
+    );
+
+    // front loaded emits 80% in the first half
+    let reward_info = get_funded_reward_info(RewardEmissionSchedule::FrontLoaded, 80);
+    assert_approx_eq(
+        reward_info.get_emission(0, ONE_DAY / 2).unwrap(),
+        AMOUNT / 10 * 8,
+        2,
+    );
+}
+
+#[test]
+fn test_accumulated_rewards_follow_emission_schedule() {
+    let mut incremental_reward_info = get_funded_reward_info(RewardEmissionSchedule::Halving, 4);
+    let mut reward_info = incremental_reward_info;
+
+    for hour in 1..=24 {
+        incremental_reward_info
+            .update_rewards(LIQUIDITY, hour * 60 * 60)
+            .unwrap();
+    }
+    reward_info.update_rewards(LIQUIDITY, ONE_DAY).unwrap();
+
+    let accumulated = |reward_info: &RewardInfo| -> u64 {
+        (reward_info.reward_per_token_stored() >> 128)
+            .try_into()
+            .unwrap()
+    };
+    assert_approx_eq(accumulated(&incremental_reward_info), AMOUNT, 24 * 4);
+    assert_approx_eq(accumulated(&reward_info), AMOUNT, 4);
+}
+
+#[test]
+fn test_ineligible_reward_with_emission_schedule() {
+    let mut reward_info = get_funded_reward_info(RewardEmissionSchedule::LinearDecay, 0);
+
+    // no liquidity in the first half
+    reward_info.update_rewards(0, ONE_DAY / 2).unwrap();
+    assert_eq!(
+        reward_info.cumulative_seconds_with_empty_liquidity_reward,
+        0
+    );
+
+    reward_info.update_rewards(LIQUIDITY, ONE_DAY).unwrap();
+    let ineligible_reward = reward_info.claim_ineligible_reward().unwrap();
+    assert_approx_eq(ineligible_reward, AMOUNT / 4 * 3, 2);
+    assert_eq!(reward_info.cumulative_ineligible_reward, 0);
+}
+
+#[test]
+fn test_refund_leftover_with_emission_schedule() {
+    let mut reward_info = get_funded_reward_info(RewardEmissionSchedule::LinearDecay, 0);
+
+    // leftover of the second half is emitted again with the new funding
+    reward_info
+        .update_rate_after_funding(ONE_DAY / 2, AMOUNT)
+        .unwrap();
+    assert_eq!(reward_info.reward_duration_end, ONE_DAY / 2 + ONE_DAY);
+    assert_approx_eq(
+        reward_info.get_emission(ONE_DAY / 2, ONE_DAY * 2).unwrap(),
+        AMOUNT + AMOUNT / 4,
+        4,
+    );
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{
+    constants::{NUM_EXTENDED_REWARDS, NUM_REWARDS},
+    state::{
+        get_extended_reward_index, is_extended_reward_index, Pool, Position,
+        PositionRewardExtension, RewardExtension, REWARD_EXTENSION_BOOSTED_FLAG,
+        REWARD_EXTENSION_ENABLED_FLAG,
+    },
+};
+
+const ONE_DAY: u64 = 60 * 60 * 24;
+const LIQUIDITY: u128 = 1 << 64;
+
+fn get_pool_with_liquidity(liquidity: u128) -> Pool {
+    let mut pool = Pool::default();
+    pool.liquidity = liquidity;
+    pool.reward_extension_flag = REWARD_EXTENSION_ENABLED_FLAG;
+    pool
+}
+
+fn get_position_with_liquidity(liquidity: u128) -> Position {
+    let mut position = Position::default();
+    position.unlocked_liquidity = liquidity;
+    position
+}
+
+fn get_funded_reward_extension(reward_index: usize, amount: u64) -> RewardExtension {
+    let mut reward_extension = RewardExtension::default();
+    reward_extension.initialize(Pubkey::new_unique());
+    let reward_info = reward_extension.get_reward_info_mut(reward_index).unwrap();
+    reward_info.init_reward(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        ONE_DAY,
+        0,
+    );
+    reward_info.update_rate_after_funding(0, amount).unwrap();
+    reward_extension
+}
+
+#[test]
+fn test_extended_reward_index() {
+    assert!(!is_extended_reward_index(NUM_REWARDS - 1));
+    assert!(is_extended_reward_index(NUM_REWARDS));
+
+    assert!(get_extended_reward_index(NUM_REWARDS - 1).is_err());
+    assert_eq!(get_extended_reward_index(NUM_REWARDS).unwrap(), 0);
+    assert_eq!(
+        get_extended_reward_index(NUM_REWARDS + NUM_EXTENDED_REWARDS - 1).unwrap(),
+        NUM_EXTENDED_REWARDS - 1
+    );
+    assert!(get_extended_reward_index(NUM_REWARDS + NUM_EXTENDED_REWARDS).is_err());
+}
+
+#[test]
+fn test_position_reward_extension() {
+    let reward_index = NUM_REWARDS + 1;
+    let amount = 1_000_000;
+    let mut reward_extension = get_funded_reward_extension(reward_index, amount);
+    let pool = get_pool_with_liquidity(LIQUIDITY);
+    let first_position = get_position_with_liquidity(LIQUIDITY);
+    let second_position = get_position_with_liquidity(0);
+
+    // position holding the whole liquidity since the extension is created
+    let mut first_position_reward_extension = PositionRewardExtension::default();
+    first_position_reward_extension.initialize(Pubkey::new_unique(), &reward_extension);
+
+    // half the reward duration passed
+    reward_extension
+        .update_rewards(&pool, ONE_DAY / 2, 0)
+        .unwrap();
+
+    // position without liquidity joins in the middle
+    let mut second_position_reward_extension = PositionRewardExtension::default();
+    second_position_reward_extension.initialize(Pubkey::new_unique(), &reward_extension);
+
+    reward_extension.update_rewards(&pool, ONE_DAY, 0).unwrap();
+
+    first_position_reward_extension
+        .update_position_reward(&first_position, &reward_extension)
+        .unwrap();
+    second_position_reward_extension
+        .update_position_reward(&second_position, &reward_extension)
+        .unwrap();
+
+    let first_reward = first_position_reward_extension
+        .claim_reward(reward_index)
+        .unwrap();
+    let second_reward = second_position_reward_extension
+        .claim_reward(reward_index)
+        .unwrap();
+
+    // rounding down
+    assert!(first_reward <= amount && first_reward >= amount - 1);
+    assert_eq!(second_reward, 0);
+    assert!(first_position_reward_extension.is_empty());
+    assert_eq!(
+        first_position_reward_extension.reward_infos[1].total_claimed_rewards,
+        first_reward
+    );
+
+    // reward not initialized is never accumulated
+    assert!(first_position_reward_extension.reward_infos[0].reward_pendings == 0);
+}
+
+#[test]
+fn test_claim_ineligible_extended_reward() {
+    let reward_index = NUM_REWARDS;
+    let amount = 1_000_000;
+    let mut reward_extension = get_funded_reward_extension(reward_index, amount);
+
+    // no liquidity for the whole reward duration
+    let pool = get_pool_with_liquidity(0);
+    reward_extension.update_rewards(&pool, ONE_DAY, 0).unwrap();
+
+    let ineligible_reward = reward_extension
+        .get_reward_info_mut(reward_index)
+        .unwrap()
+        .claim_ineligible_reward()
+        .unwrap();
+    assert!(ineligible_reward <= amount && ineligible_reward >= amount - 1);
+}
+
+#[test]
+fn test_position_reward_extension_created_late() {
+    let reward_index = NUM_REWARDS;
+    let amount = 1_000_000;
+    let mut reward_extension = get_funded_reward_extension(reward_index, amount);
+    let pool = get_pool_with_liquidity(LIQUIDITY);
+    let position = get_position_with_liquidity(LIQUIDITY);
+
+    reward_extension
+        .update_rewards(&pool, ONE_DAY / 2, 0)
+        .unwrap();
+
+    // position earns extended rewards from the creation of its reward extension only
+    let mut position_reward_extension = PositionRewardExtension::default();
+    position_reward_extension.initialize(Pubkey::new_unique(), &reward_extension);
+
+    reward_extension.update_rewards(&pool, ONE_DAY, 0).unwrap();
+    position_reward_extension
+        .update_position_reward(&position, &reward_extension)
+        .unwrap();
+
+    let reward = position_reward_extension
+        .claim_reward(reward_index)
+        .unwrap();
+    assert!(reward <= amount / 2 && reward >= amount / 2 - 1);
+}
+
+#[test]
+fn test_boosted_extended_reward_distribution() {
+    let reward_index = NUM_REWARDS;
+    let amount = 1_000_000;
+    let mut reward_extension = RewardExtension::default();
+    reward_extension.initialize(Pubkey::new_unique());
+    let reward_info = reward_extension.get_reward_info_mut(reward_index).unwrap();
+    reward_info.init_reward(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        ONE_DAY,
+        0,
+    );
+    // locked liquidity weights 3x
+    reward_info.boost_multiplier_bps = 30_000;
+    reward_info.update_rate_after_funding(0, amount).unwrap();
+    assert!(reward_extension.has_boosted_reward());
+
+    let mut pool = get_pool_with_liquidity(LIQUIDITY * 2);
+    pool.reward_extension_flag |= REWARD_EXTENSION_BOOSTED_FLAG;
+    assert!(pool.has_reward_extension());
+    assert!(pool.has_boosted_extended_reward());
+    assert!(pool.has_boosted_reward());
+
+    let unlocked_position = get_position_with_liquidity(LIQUIDITY);
+    let mut permanent_locked_position = get_position_with_liquidity(LIQUIDITY);
+    permanent_locked_position
+        .permanent_lock_liquidity(LIQUIDITY)
+        .unwrap();
+    pool.accumulate_permanent_locked_liquidity(LIQUIDITY)
+        .unwrap();
+
+    let mut unlocked_position_reward_extension = PositionRewardExtension::default();
+    unlocked_position_reward_extension.initialize(Pubkey::new_unique(), &reward_extension);
+    let mut locked_position_reward_extension = PositionRewardExtension::default();
+    locked_position_reward_extension.initialize(Pubkey::new_unique(), &reward_extension);
+
+    reward_extension.update_rewards(&pool, ONE_DAY, 0).unwrap();
+    unlocked_position_reward_extension
+        .update_position_reward(&unlocked_position, &reward_extension)
+        .unwrap();
+    locked_position_reward_extension
+        .update_position_reward(&permanent_locked_position, &reward_extension)
+        .unwrap();
+
+    // boosted supply is 4 liquidity, locked position gets 3 of them
+    let unlocked_reward = unlocked_position_reward_extension
+        .claim_reward(reward_index)
+        .unwrap();
+    let locked_reward = locked_position_reward_extension
+        .claim_reward(reward_index)
+        .unwrap();
+    assert!(unlocked_reward + locked_reward <= amount);
+    assert!(unlocked_reward + locked_reward >= amount - 2);
+    assert!(locked_reward.abs_diff(unlocked_reward * 3) <= 3);
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{RewardFunderLedger, RewardFunding};
+
+fn new_funder_ledger(reward_funding: &RewardFunding) -> RewardFunderLedger {
+    let mut funder_ledger = RewardFunderLedger::default();
+    funder_ledger.initialize(Pubkey::new_unique(), Pubkey::new_unique(), reward_funding);
+    funder_ledger
+}
+
+#[test]
+fn test_ineligible_reward_shared_by_funded_amount() {
+    let mut reward_funding = RewardFunding::default();
+    reward_funding.initialize(Pubkey::new_unique(), 0);
+
+    let mut first_funder = new_funder_ledger(&reward_funding);
+    let mut second_funder = new_funder_ledger(&reward_funding);
+    first_funder
+        .accumulate_funded_amount(&mut reward_funding, 3_072)
+        .unwrap();
+    second_funder
+        .accumulate_funded_amount(&mut reward_funding, 1_024)
+        .unwrap();
+
+    reward_funding.distribute_ineligible_reward(400).unwrap();
+
+    // third funder joins after the distribution and gets nothing from it
+    let mut third_funder = new_funder_ledger(&reward_funding);
+    third_funder
+        .accumulate_funded_amount(&mut reward_funding, 4_096)
+        .unwrap();
+
+    reward_funding.distribute_ineligible_reward(800).unwrap();
+
+    assert_eq!(
+        first_funder
+            .claim_ineligible_reward(&reward_funding)
+            .unwrap(),
+        300 + 300
+    );
+    assert_eq!(
+        second_funder
+            .claim_ineligible_reward(&reward_funding)
+            .unwrap(),
+        100 + 100
+    );
+    assert_eq!(
+        third_funder
+            .claim_ineligible_reward(&reward_funding)
+            .unwrap(),
+        400
+    );
+
+    // nothing left after claim
+    assert_eq!(
+        first_funder
+            .claim_ineligible_reward(&reward_funding)
+            .unwrap(),
+        0
+    );
+    assert_eq!(first_funder.total_withdrawn_ineligible_reward, 600);
+}
+
+#[test]
+fn test_distribute_ineligible_reward_without_funding() {
+    let mut reward_funding = RewardFunding::default();
+    assert_eq!(reward_funding.distribute_ineligible_reward(0).unwrap(), 0);
+    // nothing funded, ineligible reward is left undistributed
+    assert_eq!(
+        reward_funding.distribute_ineligible_reward(100).unwrap(),
+        100
+    );
+    assert_eq!(reward_funding.ineligible_reward_per_funded_amount, 0);
+}
+
+#[test]
+fn test_close_funder_ledger_after_withdraw() {
+    let mut reward_funding = RewardFunding::default();
+    reward_funding.initialize(Pubkey::new_unique(), 0);
+
+    let mut first_funder = new_funder_ledger(&reward_funding);
+    let mut second_funder = new_funder_ledger(&reward_funding);
+    reward_funding.number_of_funder_ledgers = 2;
+    first_funder
+        .accumulate_funded_amount(&mut reward_funding, 3_072)
+        .unwrap();
+    second_funder
+        .accumulate_funded_amount(&mut reward_funding, 1_024)
+        .unwrap();
+
+    reward_funding.distribute_ineligible_reward(400).unwrap();
+
+    // share not withdrawn yet
+    assert!(first_funder.close(&mut reward_funding).is_err());
+
+    first_funder
+        .claim_ineligible_reward(&reward_funding)
+        .unwrap();
+    first_funder.close(&mut reward_funding).unwrap();
+    assert_eq!(reward_funding.total_funded_amount, 1_024);
+    assert_eq!(reward_funding.number_of_funder_ledgers, 1);
+
+    // later distributions go to remaining funders only
+    reward_funding.distribute_ineligible_reward(200).unwrap();
+    assert_eq!(
+        second_funder
+            .claim_ineligible_reward(&reward_funding)
+            .unwrap(),
+        100 + 200
+    );
+}
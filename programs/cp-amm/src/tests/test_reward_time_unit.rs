@@ -0,0 +1,100 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{
+    activation_handler::ActivationType,
+    constants::{
+        MAX_REWARD_DURATION, MAX_REWARD_SLOT_DURATION, MIN_REWARD_DURATION,
+        MIN_REWARD_SLOT_DURATION,
+    },
+    state::{Pool, Position, RewardInfo, RewardTimeUnit},
+};
+
+const LIQUIDITY: u128 = 1 << 64;
+const CURRENT_TIMESTAMP: u64 = 1_700_000_000;
+const CURRENT_SLOT: u64 = 300_000_000;
+
+#[test]
+fn test_get_current_point() {
+    let mut reward_info = RewardInfo::default();
+    let slot = ActivationType::Slot as u8;
+    let timestamp = ActivationType::Timestamp as u8;
+
+    // timestamp unit ignores pool activation type
+    assert_eq!(
+        reward_info.get_current_point(slot, CURRENT_TIMESTAMP, CURRENT_SLOT),
+        CURRENT_TIMESTAMP
+    );
+    assert_eq!(
+        reward_info.get_reward_duration_range(slot),
+        (MIN_REWARD_DURATION, MAX_REWARD_DURATION)
+    );
+
+    reward_info.time_unit = RewardTimeUnit::ActivationPoint as u8;
+    assert_eq!(
+        reward_info.get_current_point(slot, CURRENT_TIMESTAMP, CURRENT_SLOT),
+        CURRENT_SLOT
+    );
+    assert_eq!(
+        reward_info.get_reward_duration_range(slot),
+        (MIN_REWARD_SLOT_DURATION, MAX_REWARD_SLOT_DURATION)
+    );
+    assert_eq!(
+        reward_info.get_current_point(timestamp, CURRENT_TIMESTAMP, CURRENT_SLOT),
+        CURRENT_TIMESTAMP
+    );
+    assert_eq!(
+        reward_info.get_reward_duration_range(timestamp),
+        (MIN_REWARD_DURATION, MAX_REWARD_DURATION)
+    );
+}
+
+#[test]
+fn test_slot_reward_follows_slot_activated_pool() {
+    let amount = 1_000_000;
+    let mut pool = Pool::default();
+    pool.activation_type = ActivationType::Slot as u8;
+    pool.liquidity = LIQUIDITY;
+
+    for reward_info in pool.reward_infos.iter_mut() {
+        reward_info.init_reward(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            MIN_REWARD_SLOT_DURATION,
+            0,
+        );
+    }
+    // reward 0 in slots, reward 1 in seconds
+    let slot_reward_info = &mut pool.reward_infos[0];
+    slot_reward_info.time_unit = RewardTimeUnit::ActivationPoint as u8;
+    slot_reward_info
+        .update_rate_after_funding(CURRENT_SLOT, amount)
+        .unwrap();
+    assert_eq!(
+        slot_reward_info.reward_duration_end,
+        CURRENT_SLOT + MIN_REWARD_SLOT_DURATION
+    );
+    pool.reward_infos[1]
+        .update_rate_after_funding(CURRENT_TIMESTAMP, amount)
+        .unwrap();
+
+    // half of the slot campaign elapsed, while the timestamp campaign is over
+    let mut position = Position::default();
+    position.unlocked_liquidity = LIQUIDITY;
+    pool.update_rewards(
+        CURRENT_TIMESTAMP + MIN_REWARD_SLOT_DURATION,
+        CURRENT_SLOT + MIN_REWARD_SLOT_DURATION / 2,
+    )
+    .unwrap();
+    position.update_position_reward(&pool).unwrap();
+
+    assert_eq!(
+        pool.reward_infos[0].last_update_time,
+        CURRENT_SLOT + MIN_REWARD_SLOT_DURATION / 2
+    );
+    let slot_reward = position.reward_infos[0].reward_pendings;
+    assert!(slot_reward <= amount / 2 && slot_reward >= amount / 2 - 1);
+
+    let timestamp_reward = position.reward_infos[1].reward_pendings;
+    assert!(timestamp_reward <= amount && timestamp_reward >= amount - 1);
+}
@@ -0,0 +1,140 @@
+use crate::{
+    params::swap::TradeDirection,
+    state::{
+        fee::FeeMode, SwapResult2, TraderReward, TradingRewardCampaign, TradingRewardPointMode,
+    },
+};
+
+// price 4 token b per token a
+const SQRT_PRICE: u128 = 2 << 64;
+
+fn get_swap_result() -> SwapResult2 {
+    SwapResult2 {
+        included_fee_input_amount: 1_000,
+        excluded_fee_input_amount: 990,
+        amount_left: 0,
+        output_amount: 3_960,
+        next_sqrt_price: SQRT_PRICE,
+        trading_fee: 8,
+        protocol_fee: 1,
+        partner_fee: 0,
+        referral_fee: 1,
+    }
+}
+
+fn get_fee_mode(fees_on_token_a: bool) -> FeeMode {
+    FeeMode {
+        fees_on_input: fees_on_token_a,
+        fees_on_token_a,
+        has_referral: true,
+    }
+}
+
+#[test]
+fn test_swap_points() {
+    let swap_result = get_swap_result();
+    let mut campaign = TradingRewardCampaign::default();
+
+    campaign.point_mode = TradingRewardPointMode::Fee.into();
+    // fee in token b is counted as is
+    let points = campaign
+        .get_swap_points(
+            &swap_result,
+            &get_fee_mode(false),
+            TradeDirection::AtoB,
+            SQRT_PRICE,
+        )
+        .unwrap();
+    assert_eq!(points, 10);
+    // fee in token a is valued in token b
+    let points = campaign
+        .get_swap_points(
+            &swap_result,
+            &get_fee_mode(true),
+            TradeDirection::AtoB,
+            SQRT_PRICE,
+        )
+        .unwrap();
+    assert_eq!(points, 40);
+
+    campaign.point_mode = TradingRewardPointMode::Volume.into();
+    let points = campaign
+        .get_swap_points(
+            &swap_result,
+            &get_fee_mode(false),
+            TradeDirection::AtoB,
+            SQRT_PRICE,
+        )
+        .unwrap();
+    assert_eq!(points, 3_960);
+    let points = campaign
+        .get_swap_points(
+            &swap_result,
+            &get_fee_mode(false),
+            TradeDirection::BtoA,
+            SQRT_PRICE,
+        )
+        .unwrap();
+    assert_eq!(points, 1_000);
+}
+
+#[test]
+fn test_claim_trading_reward() {
+    let mut campaign = TradingRewardCampaign::default();
+    campaign.start_point = 100;
+    campaign.end_point = 200;
+    assert!(!campaign.is_active(99));
+    assert!(campaign.is_active(100));
+    assert!(!campaign.is_active(200));
+    assert!(campaign.is_ended(200));
+
+    campaign.accumulate_reward(1_000).unwrap();
+
+    let mut trader_1 = TraderReward::default();
+    let mut trader_2 = TraderReward::default();
+    campaign.accumulate_points(&mut trader_1, 10).unwrap();
+    campaign.accumulate_points(&mut trader_2, 20).unwrap();
+    campaign.accumulate_points(&mut trader_1, 10).unwrap();
+
+    // no reward left for the funder when traders earned points
+    assert_eq!(campaign.withdraw_ineligible_reward().unwrap(), 0);
+
+    assert_eq!(campaign.claim_reward(&mut trader_1).unwrap(), 500);
+    // claim again gives nothing
+    assert_eq!(campaign.claim_reward(&mut trader_1).unwrap(), 0);
+    assert_eq!(campaign.claim_reward(&mut trader_2).unwrap(), 500);
+    assert_eq!(campaign.total_claimed_reward, 1_000);
+}
+
+#[test]
+fn test_withdraw_ineligible_trading_reward() {
+    let mut campaign = TradingRewardCampaign::default();
+    campaign.accumulate_reward(1_000).unwrap();
+
+    assert_eq!(campaign.withdraw_ineligible_reward().unwrap(), 1_000);
+    assert_eq!(campaign.withdraw_ineligible_reward().unwrap(), 0);
+
+    let mut trader = TraderReward::default();
+    assert_eq!(campaign.claim_reward(&mut trader).unwrap(), 0);
+}
+
+#[test]
+fn test_close_trader_reward_after_claim() {
+    let mut campaign = TradingRewardCampaign::default();
+    campaign.accumulate_reward(1_000).unwrap();
+
+    let mut trader_1 = TraderReward::default();
+    let trader_2 = TraderReward::default();
+    campaign.number_of_trader_rewards = 2;
+    campaign.accumulate_points(&mut trader_1, 10).unwrap();
+
+    // reward not claimed yet
+    assert!(campaign.close_trader_reward(&trader_1).is_err());
+
+    assert_eq!(campaign.claim_reward(&mut trader_1).unwrap(), 1_000);
+    campaign.close_trader_reward(&trader_1).unwrap();
+
+    // trader without points has nothing to claim
+    campaign.close_trader_reward(&trader_2).unwrap();
+    assert_eq!(campaign.number_of_trader_rewards, 0);
+}
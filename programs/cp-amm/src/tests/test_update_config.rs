@@ -0,0 +1,100 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AnchorSerialize;
+
+use crate::{
+    base_fee::fee_time_scheduler::BorshFeeTimeScheduler,
+    constants::{MAX_SQRT_PRICE, MIN_SQRT_PRICE},
+    params::fee_parameters::{BaseFeeParameters, PoolFeeParameters},
+    state::{fee::BaseFeeMode, Config, PreActivationBuyerMode},
+    StaticConfigParameters,
+};
+
+fn get_static_config_parameters(cliff_fee_numerator: u64) -> StaticConfigParameters {
+    let base_fee = BorshFeeTimeScheduler {
+        cliff_fee_numerator,
+        base_fee_mode: BaseFeeMode::FeeTimeSchedulerLinear.into(),
+        ..Default::default()
+    };
+    let mut base_fee_params = BaseFeeParameters::default();
+    base_fee_params
+        .data
+        .copy_from_slice(&base_fee.try_to_vec().unwrap());
+
+    StaticConfigParameters {
+        pool_fees: PoolFeeParameters {
+            base_fee: base_fee_params,
+            dynamic_fee: None,
+        },
+        sqrt_min_price: MIN_SQRT_PRICE,
+        sqrt_max_price: MAX_SQRT_PRICE,
+        vault_config_key: Pubkey::default(),
+        pool_creator_authority: Pubkey::default(),
+        activation_type: 1,
+        collect_fee_mode: 0,
+    }
+}
+
+fn get_static_config(params: &StaticConfigParameters) -> Config {
+    let mut config: Config = bytemuck::Zeroable::zeroed();
+    config
+        .init_static_config(
+            0,
+            &params.pool_fees,
+            params.vault_config_key,
+            params.pool_creator_authority,
+            params.activation_type,
+            params.sqrt_min_price,
+            params.sqrt_max_price,
+            params.collect_fee_mode,
+        )
+        .unwrap();
+    config
+}
+
+#[test]
+fn test_update_static_config() {
+    let params = get_static_config_parameters(10_000_000);
+    let mut config = get_static_config(&params);
+    assert_eq!(config.version, 0);
+
+    let old_params = config.to_static_config_parameters().unwrap();
+    assert_eq!(
+        old_params.pool_fees.base_fee.data,
+        params.pool_fees.base_fee.data
+    );
+
+    let mut new_params = get_static_config_parameters(5_000_000);
+    new_params.sqrt_min_price = MIN_SQRT_PRICE * 2;
+    new_params.pool_creator_authority = Pubkey::new_unique();
+    config.update_static_config(&new_params).unwrap();
+
+    assert_eq!(config.version, 1);
+    assert_eq!(config.index, 0);
+    assert_eq!(config.sqrt_min_price, new_params.sqrt_min_price);
+    assert_eq!(
+        config.pool_creator_authority,
+        new_params.pool_creator_authority
+    );
+    let updated_params = config.to_static_config_parameters().unwrap();
+    assert_eq!(
+        updated_params.pool_fees.base_fee.data,
+        new_params.pool_fees.base_fee.data
+    );
+
+    // other config updates increase the version too
+    config
+        .update_pre_activation_buyer(PreActivationBuyerMode::Address, Pubkey::new_unique())
+        .unwrap();
+    assert_eq!(config.version, 2);
+}
+
+#[test]
+fn test_update_dynamic_config_is_rejected() {
+    let mut config: Config = bytemuck::Zeroable::zeroed();
+    config.init_dynamic_config(0, Pubkey::new_unique());
+
+    assert!(config
+        .update_static_config(&get_static_config_parameters(5_000_000))
+        .is_err());
+    assert_eq!(config.version, 0);
+}
@@ -0,0 +1,98 @@
+use crate::{
+    constants::{MAX_SQRT_PRICE, MIN_SQRT_PRICE},
+    curve::get_initialize_amounts,
+    state::Pool,
+};
+
+const SQRT_PRICE: u128 = 1 << 64;
+const SQRT_MIN_PRICE: u128 = SQRT_PRICE / 2;
+const SQRT_MAX_PRICE: u128 = SQRT_PRICE * 2;
+const LIQUIDITY: u128 = 1_000_000_000u128 << 64;
+
+fn get_pool(liquidity: u128) -> Pool {
+    Pool {
+        sqrt_price: SQRT_PRICE,
+        sqrt_min_price: SQRT_MIN_PRICE,
+        sqrt_max_price: SQRT_MAX_PRICE,
+        liquidity,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_widen_price_range_deposits_reserve_delta() {
+    let mut pool = get_pool(LIQUIDITY);
+    let new_sqrt_min_price = SQRT_MIN_PRICE / 2;
+    let new_sqrt_max_price = SQRT_MAX_PRICE * 2;
+
+    let deposit = pool
+        .update_price_range(new_sqrt_min_price, new_sqrt_max_price)
+        .unwrap();
+
+    assert_eq!(pool.sqrt_min_price, new_sqrt_min_price);
+    assert_eq!(pool.sqrt_max_price, new_sqrt_max_price);
+    assert_eq!(pool.liquidity, LIQUIDITY);
+
+    // reserve after deposit covers the new range at unchanged liquidity
+    let (old_a, old_b) =
+        get_initialize_amounts(SQRT_MIN_PRICE, SQRT_MAX_PRICE, SQRT_PRICE, LIQUIDITY).unwrap();
+    let (new_a, new_b) = get_initialize_amounts(
+        new_sqrt_min_price,
+        new_sqrt_max_price,
+        SQRT_PRICE,
+        LIQUIDITY,
+    )
+    .unwrap();
+    assert!(deposit.token_a_amount > 0 && deposit.token_b_amount > 0);
+    assert!(old_a + deposit.token_a_amount >= new_a);
+    assert!(old_b + deposit.token_b_amount >= new_b);
+}
+
+#[test]
+fn test_widen_one_bound_only() {
+    let mut pool = get_pool(LIQUIDITY);
+    let deposit = pool
+        .update_price_range(SQRT_MIN_PRICE, SQRT_MAX_PRICE * 2)
+        .unwrap();
+    assert!(deposit.token_a_amount > 0);
+    assert_eq!(deposit.token_b_amount, 0);
+}
+
+#[test]
+fn test_narrow_price_range_requires_no_liquidity() {
+    let mut pool = get_pool(LIQUIDITY);
+    assert!(pool
+        .update_price_range(SQRT_MIN_PRICE * 3 / 2, SQRT_MAX_PRICE)
+        .is_err());
+    // widen one bound but narrow the other
+    assert!(pool
+        .update_price_range(SQRT_MIN_PRICE / 2, SQRT_MAX_PRICE / 2 * 3 / 2)
+        .is_err());
+
+    let mut pool = get_pool(0);
+    let deposit = pool
+        .update_price_range(SQRT_MIN_PRICE * 3 / 2, SQRT_MAX_PRICE / 2 * 3 / 2)
+        .unwrap();
+    assert_eq!(deposit.token_a_amount, 0);
+    assert_eq!(deposit.token_b_amount, 0);
+}
+
+#[test]
+fn test_invalid_price_range() {
+    let mut pool = get_pool(LIQUIDITY);
+    // current price outside of range
+    assert!(pool
+        .update_price_range(SQRT_PRICE * 3 / 2, SQRT_MAX_PRICE * 2)
+        .is_err());
+    // out of bound
+    assert!(pool
+        .update_price_range(MIN_SQRT_PRICE - 1, SQRT_MAX_PRICE)
+        .is_err());
+    assert!(pool
+        .update_price_range(SQRT_MIN_PRICE, MAX_SQRT_PRICE + 1)
+        .is_err());
+    // unchanged
+    assert!(pool
+        .update_price_range(SQRT_MIN_PRICE, SQRT_MAX_PRICE)
+        .is_err());
+}
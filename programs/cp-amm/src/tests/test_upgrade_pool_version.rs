@@ -0,0 +1,40 @@
+use crate::{
+    constants::fee::{get_max_fee_numerator, CURRENT_POOL_VERSION, MAX_FEE_NUMERATOR_V1},
+    state::{Pool, PoolVersion},
+};
+
+#[test]
+fn test_upgrade_pool_version_v0_to_v1() {
+    let mut pool = Pool {
+        version: PoolVersion::V0.into(),
+        ..Default::default()
+    };
+
+    pool.upgrade_version(PoolVersion::V1.into()).unwrap();
+
+    assert_eq!(pool.version, u8::from(PoolVersion::V1));
+    assert_eq!(
+        get_max_fee_numerator(pool.version).unwrap(),
+        MAX_FEE_NUMERATOR_V1
+    );
+}
+
+#[test]
+fn test_upgrade_pool_version_invalid_target() {
+    let mut pool = Pool {
+        version: PoolVersion::V0.into(),
+        ..Default::default()
+    };
+    // same version
+    assert!(pool.upgrade_version(PoolVersion::V0.into()).is_err());
+    // unknown version
+    assert!(pool.upgrade_version(CURRENT_POOL_VERSION + 1).is_err());
+
+    let mut pool = Pool {
+        version: CURRENT_POOL_VERSION,
+        ..Default::default()
+    };
+    // no downgrade
+    assert!(pool.upgrade_version(PoolVersion::V0.into()).is_err());
+    assert!(pool.upgrade_version(CURRENT_POOL_VERSION).is_err());
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AllowlistEntry, Pool},
+    PoolError,
+};
+
+/// Take the allowlist entry of the acting wallet from the front of remaining accounts, only for permissioned pools
+pub fn load_allowlist_entry_if_permissioned<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    pool_key: Pubkey,
+    pool: &Pool,
+) -> Result<Option<AllowlistEntry>> {
+    if !pool.is_permissioned() {
+        return Ok(None);
+    }
+
+    let (account, rest) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::MissingAllowlistEntry)?;
+    *remaining_accounts = rest;
+
+    let allowlist_entry_loader: AccountLoader<'info, AllowlistEntry> =
+        AccountLoader::try_from(account)?;
+    let allowlist_entry = *allowlist_entry_loader.load()?;
+    require!(
+        allowlist_entry.pool == pool_key,
+        PoolError::InvalidAllowlistEntry
+    );
+
+    Ok(Some(allowlist_entry))
+}
@@ -8,9 +8,18 @@ pub mod alpha_vault {
     pub const ID: Pubkey = pubkey!("SNPmGgnywBvvrAKMLundzG6StojyHTHDLu7T4sdhP4k");
 
     pub fn derive_vault_pubkey(vault_base: Pubkey, pool: Pubkey) -> Pubkey {
+        derive_vault_pubkey_with_program(self::ID, vault_base, pool)
+    }
+
+    /// Vault of a program following the alpha vault seed scheme
+    pub fn derive_vault_pubkey_with_program(
+        program_id: Pubkey,
+        vault_base: Pubkey,
+        pool: Pubkey,
+    ) -> Pubkey {
         let (vault_pk, _) = Pubkey::find_program_address(
             &[b"vault", vault_base.as_ref(), pool.as_ref()],
-            &self::ID,
+            &program_id,
         );
         vault_pk
     }
@@ -1,4 +1,8 @@
 pub mod activation_handler;
+pub mod allowlist;
 pub mod alpha_vault;
 pub mod p_helper;
+pub mod position_nft_metadata;
+pub mod reward_boost;
+pub mod reward_extension;
 pub mod token;
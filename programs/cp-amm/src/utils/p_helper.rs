@@ -8,7 +8,7 @@ use anchor_lang::{
 use anchor_spl::token_interface::TokenAccount;
 use bytemuck::Pod;
 use pinocchio::{
-    account_info::{AccountInfo, RefMut},
+    account_info::{AccountInfo, Ref, RefMut},
     entrypoint::ProgramResult,
     sysvars::instructions::IntrospectedInstruction,
 };
@@ -99,6 +99,34 @@ pub fn p_load_mut_checked<T: Pod + Discriminator + Owner>(
     }))
 }
 
+// same as AccountLoader load() but check for discriminator and owner
+pub fn p_load_checked<T: Pod + Discriminator + Owner>(acc_info: &AccountInfo) -> Result<Ref<T>> {
+    // validate owner
+    require!(
+        acc_info.owner().eq(&T::owner().to_bytes()),
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let disc = T::DISCRIMINATOR;
+    let data = acc_info
+        .try_borrow_data()
+        .map_err(|err| ProgramError::from(u64::from(err)))?;
+
+    if data.len() < disc.len() {
+        return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+    }
+
+    let given_disc = &data[..disc.len()];
+    if given_disc != disc {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    Ok(Ref::map(data, |data| {
+        // just panic if it is wrong
+        bytemuck::from_bytes(&data[disc.len()..mem::size_of::<T>() + disc.len()])
+    }))
+}
+
 pub fn p_load_mut_unchecked<T: Pod + Discriminator + Owner>(
     acc_info: &AccountInfo,
 ) -> Result<RefMut<T>> {
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+    },
+    token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_interface::{token_metadata_update_field, TokenMetadataUpdateField},
+};
+
+use crate::{
+    constants::position_nft_metadata::*,
+    state::{Pool, Position},
+    PoolError,
+};
+
+// Metaplex metadata layout: key (1) + update_authority (32) + mint (32) + name (4 + len) + symbol (4 + len)
+const METAPLEX_MINT_OFFSET: usize = 33;
+const METAPLEX_NAME_OFFSET: usize = 65;
+
+pub fn get_position_nft_pool_attributes(
+    pool_key: Pubkey,
+    pool: &Pool,
+    token_a_symbol: String,
+    token_b_symbol: String,
+) -> Vec<(&'static str, String)> {
+    vec![
+        (POOL_KEY, pool_key.to_string()),
+        (TOKEN_A_MINT_KEY, pool.token_a_mint.to_string()),
+        (TOKEN_B_MINT_KEY, pool.token_b_mint.to_string()),
+        (TOKEN_A_SYMBOL_KEY, token_a_symbol),
+        (TOKEN_B_SYMBOL_KEY, token_b_symbol),
+    ]
+}
+
+pub fn get_position_nft_liquidity_attributes(position: &Position) -> Vec<(&'static str, String)> {
+    vec![
+        (
+            UNLOCKED_LIQUIDITY_KEY,
+            position.unlocked_liquidity.to_string(),
+        ),
+        (VESTED_LIQUIDITY_KEY, position.vested_liquidity.to_string()),
+        (
+            PERMANENT_LOCKED_LIQUIDITY_KEY,
+            position.permanent_locked_liquidity.to_string(),
+        ),
+    ]
+}
+
+/// Get symbol of a token from its token 2022 metadata extension, or from metaplex metadata accounts
+/// Return empty string if the symbol cannot be found
+pub fn get_token_symbol(
+    token_mint: &AccountInfo,
+    metadata_accounts: &[AccountInfo],
+) -> Result<String> {
+    if token_mint.owner.eq(&anchor_spl::token_2022::ID) {
+        let mint_data = token_mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        if let Ok(token_metadata) = mint.get_variable_len_extension::<TokenMetadata>() {
+            if token_metadata.mint == token_mint.key() {
+                return Ok(sanitize_symbol(&token_metadata.symbol));
+            }
+        }
+    }
+
+    for metadata_account in metadata_accounts.iter() {
+        require!(
+            metadata_account
+                .owner
+                .eq(&METAPLEX_TOKEN_METADATA_PROGRAM_ID),
+            PoolError::InvalidTokenMetadataAccount
+        );
+        let data = metadata_account.try_borrow_data()?;
+        if let Some(symbol) = parse_metaplex_symbol(&data, &token_mint.key()) {
+            return Ok(symbol);
+        }
+    }
+
+    Ok(String::new())
+}
+
+/// Return the sanitized symbol if the metaplex metadata belongs to the mint
+pub fn parse_metaplex_symbol(data: &[u8], mint: &Pubkey) -> Option<String> {
+    let metadata_mint = data.get(METAPLEX_MINT_OFFSET..METAPLEX_NAME_OFFSET)?;
+    if metadata_mint != mint.as_ref() {
+        return None;
+    }
+
+    let name_length = read_u32_le(data, METAPLEX_NAME_OFFSET)?;
+    let symbol_offset = METAPLEX_NAME_OFFSET
+        .checked_add(4)?
+        .checked_add(name_length)?;
+    let symbol_length = read_u32_le(data, symbol_offset)?;
+    let symbol_start = symbol_offset.checked_add(4)?;
+    let symbol_end = symbol_start.checked_add(symbol_length)?;
+    let symbol = std::str::from_utf8(data.get(symbol_start..symbol_end)?).ok()?;
+
+    Some(sanitize_symbol(symbol))
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes: [u8; 4] = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+    usize::try_from(u32::from_le_bytes(bytes)).ok()
+}
+
+/// Trim null padding and cap length, so the symbol always fits in the reserved space
+pub fn sanitize_symbol(symbol: &str) -> String {
+    let symbol = symbol.trim_matches(char::from(0)).trim();
+    let mut end = symbol.len().min(MAX_SYMBOL_LENGTH);
+    while !symbol.is_char_boundary(end) {
+        end -= 1;
+    }
+    symbol[..end].to_string()
+}
+
+fn get_additional_metadata(position_nft_mint: &AccountInfo) -> Result<Vec<(String, String)>> {
+    let mint_data = position_nft_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let token_metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+    Ok(token_metadata.additional_metadata)
+}
+
+/// Write attributes to additional metadata of position nft. Only changed attributes are written.
+/// If `insert_missing` is false, attributes which don't exist yet are skipped, so old position nft never grow without rent top up.
+pub fn update_position_nft_attributes<'info>(
+    position_nft_mint: &AccountInfo<'info>,
+    pool_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    attributes: &[(&str, String)],
+    insert_missing: bool,
+) -> Result<()> {
+    let additional_metadata = get_additional_metadata(position_nft_mint)?;
+    let seeds = pool_authority_seeds!();
+    let signer_seeds = &[&seeds[..]];
+
+    for (key, value) in attributes.iter() {
+        match additional_metadata.iter().find(|(k, _)| k.as_str() == *key) {
+            Some((_, current_value)) if current_value == value => continue,
+            None if !insert_missing => continue,
+            _ => {}
+        }
+
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: position_nft_mint.clone(),
+                    update_authority: pool_authority.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key.to_string()),
+            value.clone(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Pool, Position},
+    utils::reward_extension::update_extended_rewards_if_enabled,
+};
+
+/// Update pool and position rewards before locked liquidity of the position changes, only needed when the pool has boosted rewards.
+/// Pool account must be writable in that case. When an extended reward is boosted, remaining accounts must start with
+/// the pool reward extension and the position reward extension address. Return whether the pool has boosted rewards
+pub fn update_boosted_rewards_if_needed<'c: 'info, 'info>(
+    pool_loader: &AccountLoader<Pool>,
+    position_key: Pubkey,
+    position: &mut Position,
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+) -> Result<bool> {
+    if !pool_loader.load()?.has_boosted_reward() {
+        return Ok(false);
+    }
+
+    let mut pool = pool_loader.load_mut()?;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp as u64;
+    pool.update_rewards(current_timestamp, clock.slot)?;
+    position.update_position_reward(&pool)?;
+
+    if pool.has_boosted_extended_reward() {
+        update_extended_rewards_if_enabled(
+            remaining_accounts,
+            pool_loader.key(),
+            &pool,
+            &[(position_key, &*position)],
+            current_timestamp,
+            clock.slot,
+        )?;
+    }
+
+    Ok(true)
+}
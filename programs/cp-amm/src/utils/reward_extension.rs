@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::POSITION_REWARD_EXTENSION_PREFIX,
+    state::{Pool, Position, PositionRewardExtension, RewardExtension},
+    PoolError,
+};
+
+fn next_account<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+) -> Result<&'c AccountInfo<'info>> {
+    let (account, rest) = remaining_accounts
+        .split_first()
+        .ok_or(PoolError::MissingRewardExtensionAccount)?;
+    *remaining_accounts = rest;
+    Ok(account)
+}
+
+/// Take the pool reward extension from the front of remaining accounts
+pub fn load_reward_extension<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    pool_key: Pubkey,
+) -> Result<AccountLoader<'info, RewardExtension>> {
+    let reward_extension: AccountLoader<'info, RewardExtension> =
+        AccountLoader::try_from(next_account(remaining_accounts)?)?;
+    require!(
+        reward_extension.load()?.pool == pool_key,
+        PoolError::InvalidRewardExtensionAccount
+    );
+    Ok(reward_extension)
+}
+
+/// Take the position reward extension from the front of remaining accounts
+pub fn load_position_reward_extension<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    position_key: Pubkey,
+) -> Result<AccountLoader<'info, PositionRewardExtension>> {
+    let position_reward_extension: AccountLoader<'info, PositionRewardExtension> =
+        AccountLoader::try_from(next_account(remaining_accounts)?)?;
+    require!(
+        position_reward_extension.load()?.position == position_key,
+        PoolError::InvalidRewardExtensionAccount
+    );
+    Ok(position_reward_extension)
+}
+
+/// Take the position reward extension from the front of remaining accounts, None if the position hasn't created it yet.
+/// The uninitialized account must still be the position reward extension address
+pub fn load_position_reward_extension_if_initialized<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    position_key: Pubkey,
+) -> Result<Option<AccountLoader<'info, PositionRewardExtension>>> {
+    let account = next_account(remaining_accounts)?;
+    if account.owner != &crate::ID {
+        let (position_reward_extension_key, _bump) = Pubkey::find_program_address(
+            &[
+                POSITION_REWARD_EXTENSION_PREFIX.as_ref(),
+                position_key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require!(
+            account.key() == position_reward_extension_key,
+            PoolError::InvalidRewardExtensionAccount
+        );
+        return Ok(None);
+    }
+
+    let position_reward_extension: AccountLoader<'info, PositionRewardExtension> =
+        AccountLoader::try_from(account)?;
+    require!(
+        position_reward_extension.load()?.position == position_key,
+        PoolError::InvalidRewardExtensionAccount
+    );
+    Ok(Some(position_reward_extension))
+}
+
+/// Update extended rewards of the pool and positions, must be called before liquidity of the positions change.
+/// Once the pool has a reward extension, remaining accounts must start with the pool reward extension,
+/// followed by the reward extension address of each position in order. Positions without reward extension are skipped,
+/// they earn extended rewards from the creation of their reward extension.
+pub fn update_extended_rewards_if_enabled<'c: 'info, 'info>(
+    remaining_accounts: &mut &'c [AccountInfo<'info>],
+    pool_key: Pubkey,
+    pool: &Pool,
+    positions: &[(Pubkey, &Position)],
+    current_timestamp: u64,
+    current_slot: u64,
+) -> Result<()> {
+    if !pool.has_reward_extension() {
+        return Ok(());
+    }
+
+    let reward_extension_loader = load_reward_extension(remaining_accounts, pool_key)?;
+    let mut reward_extension = reward_extension_loader.load_mut()?;
+    reward_extension.update_rewards(pool, current_timestamp, current_slot)?;
+
+    for (position_key, position) in positions.iter() {
+        if let Some(position_reward_extension_loader) =
+            load_position_reward_extension_if_initialized(remaining_accounts, *position_key)?
+        {
+            let mut position_reward_extension = position_reward_extension_loader.load_mut()?;
+            position_reward_extension.update_position_reward(position, &reward_extension)?;
+        }
+    }
+
+    Ok(())
+}
@@ -270,12 +270,15 @@ pub fn is_token_badge_initialized<'c: 'info, 'info>(
     Ok(token_badge.token_mint == mint)
 }
 
+/// Top up account lamports to be rent exempt for current data length plus `reserved_space`
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
+    reserved_space: usize,
 ) -> Result<()> {
-    let minimum_balance = Rent::get()?.minimum_balance(account.data_len());
+    let minimum_balance =
+        Rent::get()?.minimum_balance(account.data_len().safe_add(reserved_space)?);
     let current_lamport = account.get_lamports();
     if minimum_balance > current_lamport {
         let extra_lamports = minimum_balance.safe_sub(current_lamport)?;
@@ -1,7 +1,12 @@
 pub mod calculate_init_sqrt_price;
+pub mod position_pnl;
+pub mod position_valuation;
 pub mod quote_exact_in;
 pub mod quote_exact_out;
 pub mod quote_partial_fill_in;
+pub mod reward_apr;
+pub mod reward_emission;
+pub mod vesting_timeline;
 mod utils;
 
 #[cfg(test)]
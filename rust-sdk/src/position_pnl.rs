@@ -0,0 +1,166 @@
+use anyhow::{ensure, Context, Ok, Result};
+use cp_amm::{
+    constants::fee::MAX_BASIS_POINT,
+    state::{ExtendedVesting, Pool, Position, Vesting},
+};
+
+use crate::position_valuation::{get_position_valuation, get_token_a_value_in_token_b};
+
+const SECONDS_PER_YEAR: u128 = 365 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PositionPnl {
+    /// Value of all deposits at current pool price
+    pub deposited_value_in_token_b: u128,
+    /// Value of all withdrawals at current pool price
+    pub withdrawn_value_in_token_b: u128,
+    /// Value of position liquidity at current pool price, fees excluded
+    pub position_value_in_token_b: u128,
+    /// Value of tokens if deposits minus withdrawals had been held instead, at current pool price
+    pub hodl_value_in_token_b: i128,
+    /// Value of claimed and claimable fees at current pool price
+    pub fee_value_in_token_b: u128,
+    /// position value - hodl value, negative when the position lost value against holding
+    pub impermanent_loss_in_token_b: i128,
+    /// Impermanent loss over hodl value in basis point, 0 when hodl value is not positive
+    pub impermanent_loss_bps: i64,
+    /// Annualized fee value over deposited value in basis point
+    pub fee_apr_bps: u64,
+    /// position value + withdrawn value + fee value - deposited value, rewards excluded
+    pub net_pnl_in_token_b: i128,
+}
+
+/// Profit and loss of a position from its cost basis, against holding the deposited tokens.
+/// All amounts are valued in token b at current pool price.
+pub fn get_position_pnl(
+    pool: &Pool,
+    position: &Position,
+    vestings: &[Vesting],
+    extended_vestings: &[ExtendedVesting],
+    current_timestamp: u64,
+    current_slot: u64,
+) -> Result<PositionPnl> {
+    let valuation = get_position_valuation(
+        pool,
+        position,
+        vestings,
+        extended_vestings,
+        current_timestamp,
+        current_slot,
+    )?;
+
+    let cost_basis = &position.cost_basis;
+    let value_in_token_b = |token_a_amount: u64, token_b_amount: u64| -> Result<u128> {
+        get_token_a_value_in_token_b(token_a_amount.into(), pool.sqrt_price)?
+            .checked_add(token_b_amount.into())
+            .context("Math overflow")
+    };
+
+    let deposited_value_in_token_b = value_in_token_b(
+        cost_basis.total_deposited_a_amount,
+        cost_basis.total_deposited_b_amount,
+    )?;
+    let withdrawn_value_in_token_b = value_in_token_b(
+        cost_basis.total_withdrawn_a_amount,
+        cost_basis.total_withdrawn_b_amount,
+    )?;
+
+    let position_token_a_amount = valuation
+        .withdrawable_token_a_amount
+        .checked_add(valuation.locked_token_a_amount)
+        .context("Math overflow")?;
+    let position_token_b_amount = valuation
+        .withdrawable_token_b_amount
+        .checked_add(valuation.locked_token_b_amount)
+        .context("Math overflow")?;
+    let position_value_in_token_b =
+        value_in_token_b(position_token_a_amount, position_token_b_amount)?;
+
+    let total_fee_a = position
+        .metrics
+        .total_claimed_a_fee
+        .checked_add(valuation.claimable_fee_a)
+        .context("Math overflow")?;
+    let total_fee_b = position
+        .metrics
+        .total_claimed_b_fee
+        .checked_add(valuation.claimable_fee_b)
+        .context("Math overflow")?;
+    let fee_value_in_token_b = value_in_token_b(total_fee_a, total_fee_b)?;
+
+    let deposited_value = to_i128(deposited_value_in_token_b)?;
+    let withdrawn_value = to_i128(withdrawn_value_in_token_b)?;
+    let position_value = to_i128(position_value_in_token_b)?;
+    let fee_value = to_i128(fee_value_in_token_b)?;
+
+    let hodl_value_in_token_b = deposited_value
+        .checked_sub(withdrawn_value)
+        .context("Math overflow")?;
+    let impermanent_loss_in_token_b = position_value
+        .checked_sub(hodl_value_in_token_b)
+        .context("Math overflow")?;
+    let impermanent_loss_bps = if hodl_value_in_token_b > 0 {
+        let bps = impermanent_loss_in_token_b
+            .checked_mul(MAX_BASIS_POINT.into())
+            .context("Math overflow")?
+            / hodl_value_in_token_b;
+        i64::try_from(bps).context("Type cast failed")?
+    } else {
+        0
+    };
+
+    let net_pnl_in_token_b = position_value
+        .checked_add(withdrawn_value)
+        .and_then(|value| value.checked_add(fee_value))
+        .and_then(|value| value.checked_sub(deposited_value))
+        .context("Math overflow")?;
+
+    let fee_apr_bps = get_fee_apr_bps(
+        fee_value_in_token_b,
+        deposited_value_in_token_b,
+        cost_basis.first_deposit_timestamp,
+        current_timestamp,
+    )?;
+
+    Ok(PositionPnl {
+        deposited_value_in_token_b,
+        withdrawn_value_in_token_b,
+        position_value_in_token_b,
+        hodl_value_in_token_b,
+        fee_value_in_token_b,
+        impermanent_loss_in_token_b,
+        impermanent_loss_bps,
+        fee_apr_bps,
+        net_pnl_in_token_b,
+    })
+}
+
+/// fee_value / deposited_value, annualized from first deposit time. Return 0 if nothing was deposited or no time passed
+pub fn get_fee_apr_bps(
+    fee_value: u128,
+    deposited_value: u128,
+    first_deposit_timestamp: u64,
+    current_timestamp: u64,
+) -> Result<u64> {
+    ensure!(
+        current_timestamp >= first_deposit_timestamp,
+        "current timestamp is before first deposit"
+    );
+    let elapsed = u128::from(current_timestamp - first_deposit_timestamp);
+    if deposited_value == 0 || elapsed == 0 {
+        return Ok(0);
+    }
+
+    let apr_bps = fee_value
+        .checked_mul(MAX_BASIS_POINT.into())
+        .and_then(|value| value.checked_mul(SECONDS_PER_YEAR))
+        .context("Math overflow")?
+        / deposited_value
+        / elapsed;
+
+    u64::try_from(apr_bps).context("Type cast failed")
+}
+
+fn to_i128(value: u128) -> Result<i128> {
+    i128::try_from(value).context("Type cast failed")
+}
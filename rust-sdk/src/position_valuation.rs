@@ -0,0 +1,113 @@
+use crate::utils::*;
+use alloy_primitives::U256;
+use anyhow::{Ok, Result};
+use cp_amm::{
+    constants::NUM_REWARDS,
+    safe_math::SafeMath,
+    state::{ExtendedVesting, Pool, Position, Vesting},
+    u128x128_math::Rounding,
+    utils_math::safe_mul_shr_256_cast,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PositionValuation {
+    /// Liquidity which can be removed, including liquidity released from vestings
+    pub unlocked_liquidity: u128,
+    /// Liquidity still locked in vestings
+    pub vested_liquidity: u128,
+    pub permanent_locked_liquidity: u128,
+    /// Token a amount received when removing all unlocked liquidity
+    pub withdrawable_token_a_amount: u64,
+    /// Token b amount received when removing all unlocked liquidity
+    pub withdrawable_token_b_amount: u64,
+    /// Token a amount backing vested and permanent locked liquidity
+    pub locked_token_a_amount: u64,
+    /// Token b amount backing vested and permanent locked liquidity
+    pub locked_token_b_amount: u64,
+    pub claimable_fee_a: u64,
+    pub claimable_fee_b: u64,
+    /// Pending reward per reward index
+    pub pending_rewards: [u64; NUM_REWARDS],
+    /// Value of all liquidity and claimable fees in token b, at current pool price. Rewards are excluded
+    pub total_value_in_token_b: u128,
+}
+
+/// Value a position at the given time, with the same math as the program.
+/// Vestings of the position are released until current point before valuation.
+pub fn get_position_valuation(
+    pool: &Pool,
+    position: &Position,
+    vestings: &[Vesting],
+    extended_vestings: &[ExtendedVesting],
+    current_timestamp: u64,
+    current_slot: u64,
+) -> Result<PositionValuation> {
+    let mut pool = *pool;
+    let mut position = *position;
+
+    let current_point = get_current_point(pool.activation_type, current_slot, current_timestamp)?;
+
+    // liquidity bootstrapping releases reserve as fees when sqrt max price moves down
+    pool.update_lbp_price_bound(current_point)?;
+
+    // fee and reward must be updated before any liquidity change
+    position.update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())?;
+    pool.update_rewards(current_timestamp, current_slot)?;
+    position.update_position_reward(&pool)?;
+
+    for vesting in vestings.iter() {
+        let released_liquidity = vesting.get_new_release_liquidity(current_point)?;
+        position.release_vested_liquidity(released_liquidity)?;
+    }
+
+    for vesting in extended_vestings.iter() {
+        let released_liquidity = vesting.get_new_release_liquidity(current_point)?;
+        position.release_vested_liquidity(released_liquidity)?;
+    }
+
+    let withdrawable_amounts =
+        pool.get_amounts_for_modify_liquidity(position.unlocked_liquidity, Rounding::Down)?;
+
+    let locked_liquidity = position
+        .vested_liquidity
+        .safe_add(position.permanent_locked_liquidity)?;
+    let locked_amounts = pool.get_amounts_for_modify_liquidity(locked_liquidity, Rounding::Down)?;
+
+    let mut pending_rewards = [0u64; NUM_REWARDS];
+    for (pending_reward, reward_info) in pending_rewards.iter_mut().zip(position.reward_infos) {
+        *pending_reward = reward_info.reward_pendings;
+    }
+
+    let total_token_a_amount = u128::from(withdrawable_amounts.token_a_amount)
+        .safe_add(locked_amounts.token_a_amount.into())?
+        .safe_add(position.fee_a_pending.into())?;
+    let total_token_b_amount = u128::from(withdrawable_amounts.token_b_amount)
+        .safe_add(locked_amounts.token_b_amount.into())?
+        .safe_add(position.fee_b_pending.into())?;
+
+    let total_value_in_token_b =
+        get_token_a_value_in_token_b(total_token_a_amount, pool.sqrt_price)?
+            .safe_add(total_token_b_amount)?;
+
+    Ok(PositionValuation {
+        unlocked_liquidity: position.unlocked_liquidity,
+        vested_liquidity: position.vested_liquidity,
+        permanent_locked_liquidity: position.permanent_locked_liquidity,
+        withdrawable_token_a_amount: withdrawable_amounts.token_a_amount,
+        withdrawable_token_b_amount: withdrawable_amounts.token_b_amount,
+        locked_token_a_amount: locked_amounts.token_a_amount,
+        locked_token_b_amount: locked_amounts.token_b_amount,
+        claimable_fee_a: position.fee_a_pending,
+        claimable_fee_b: position.fee_b_pending,
+        pending_rewards,
+        total_value_in_token_b,
+    })
+}
+
+/// token_a_amount * sqrt_price^2 >> 128, rounding down
+pub fn get_token_a_value_in_token_b(token_a_amount: u128, sqrt_price: u128) -> Result<u128> {
+    let value: u128 =
+        safe_mul_shr_256_cast(U256::from(token_a_amount), U256::from(sqrt_price), 64)?;
+    let value: u128 = safe_mul_shr_256_cast(U256::from(value), U256::from(sqrt_price), 64)?;
+    Ok(value)
+}
@@ -25,6 +25,10 @@ pub fn get_quote(
         TradeDirection::BtoA
     };
 
+    // liquidity bootstrapping moves sqrt max price down before the swap, same as the program
+    let mut pool = *pool;
+    pool.update_lbp_price_bound(current_point)?;
+
     let fee_mode = &FeeMode::get_fee_mode(pool.collect_fee_mode, trade_direction, has_referral)?;
 
     let swap_result = pool.get_swap_result_from_partial_input(
@@ -0,0 +1,116 @@
+use anyhow::{Ok, Result};
+use cp_amm::{
+    constants::{NUM_REWARDS, REWARD_RATE_SCALE},
+    safe_math::SafeMath,
+    state::Pool,
+};
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+// 1 slot = 400 mls
+const SLOTS_PER_YEAR: u64 = SECONDS_PER_YEAR * 1000 / 400;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenPrice {
+    /// USD price of one whole token
+    pub price: f64,
+    pub decimals: u8,
+}
+
+impl TokenPrice {
+    /// USD value of a raw token amount
+    pub fn get_value(&self, amount: u128) -> f64 {
+        amount as f64 / 10f64.powi(self.decimals.into()) * self.price
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RewardAnalytics {
+    pub reward_index: usize,
+    /// Current emission rate of the reward, scaled by REWARD_RATE_SCALE. Differs from reward_rate for non constant emission schedules
+    pub current_reward_rate: u128,
+    /// Points until reward duration end, in seconds or in slots for rewards in slots. 0 if the reward ended
+    pub points_until_end: u64,
+    /// Reward not emitted yet
+    pub remaining_reward: u64,
+    /// Yearly USD value of rewards at current emission rate over pool TVL, in percent. 0 if the pool has no TVL
+    pub apr: f64,
+    /// Seconds (or slots for rewards in slots) the reward was emitted while the pool had no liquidity
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+    /// Reward emitted while the pool had no liquidity, carried over to the next funding or withdrawn by funder
+    pub ineligible_reward: u64,
+}
+
+/// USD value of the pool reserves
+pub fn get_pool_tvl(
+    pool: &Pool,
+    token_a_price: TokenPrice,
+    token_b_price: TokenPrice,
+) -> Result<f64> {
+    let (reserve_a_amount, reserve_b_amount) = pool.get_reserves_amount()?;
+    Ok(token_a_price.get_value(reserve_a_amount.into())
+        + token_b_price.get_value(reserve_b_amount.into()))
+}
+
+/// Emission and APR of each initialized reward of the pool at current time.
+/// reward_prices are indexed by reward index, rewards without price have 0 APR
+pub fn get_reward_analytics(
+    pool: &Pool,
+    current_timestamp: u64,
+    current_slot: u64,
+    token_a_price: TokenPrice,
+    token_b_price: TokenPrice,
+    reward_prices: &[Option<TokenPrice>; NUM_REWARDS],
+) -> Result<Vec<RewardAnalytics>> {
+    // bring empty liquidity periods up to current time
+    let mut pool = *pool;
+    pool.update_rewards(current_timestamp, current_slot)?;
+
+    let tvl = get_pool_tvl(&pool, token_a_price, token_b_price)?;
+
+    let mut analytics = vec![];
+    for (reward_index, reward_info) in pool.reward_infos.iter().enumerate() {
+        if !reward_info.initialized() {
+            continue;
+        }
+
+        let current_time =
+            reward_info.get_current_point(pool.activation_type, current_timestamp, current_slot);
+        let points_per_year = if reward_info.is_slot_time_unit(pool.activation_type) {
+            SLOTS_PER_YEAR
+        } else {
+            SECONDS_PER_YEAR
+        };
+
+        let current_reward_rate =
+            reward_info.get_scaled_emission(current_time, current_time.safe_add(1)?)?;
+        let points_until_end = reward_info.reward_duration_end.saturating_sub(current_time);
+        let remaining_reward =
+            reward_info.get_emission(current_time, reward_info.reward_duration_end)?;
+
+        let apr = match reward_prices[reward_index] {
+            Some(reward_price) if tvl > 0.0 => {
+                let yearly_reward = current_reward_rate
+                    .safe_mul(points_per_year.into())?
+                    .safe_shr(REWARD_RATE_SCALE.into())?;
+                reward_price.get_value(yearly_reward) / tvl * 100.0
+            }
+            _ => 0.0,
+        };
+
+        let mut carry_over = *reward_info;
+        let ineligible_reward = carry_over.claim_ineligible_reward()?;
+
+        analytics.push(RewardAnalytics {
+            reward_index,
+            current_reward_rate,
+            points_until_end,
+            remaining_reward,
+            apr,
+            cumulative_seconds_with_empty_liquidity_reward: reward_info
+                .cumulative_seconds_with_empty_liquidity_reward,
+            ineligible_reward,
+        });
+    }
+
+    Ok(analytics)
+}
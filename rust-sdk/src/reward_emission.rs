@@ -0,0 +1,52 @@
+use anyhow::{ensure, Ok, Result};
+use cp_amm::{safe_math::SafeMath, state::RewardInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardEmission {
+    pub start_point: u64,
+    pub end_point: u64,
+    /// Reward emitted between start point and end point
+    pub amount: u64,
+    /// Total reward emitted from the projection start until end point
+    pub total_amount: u64,
+}
+
+/// Reward not yet emitted at current point, with the emission schedule of the reward.
+/// Current point is from RewardInfo::get_current_point, timestamp or slot depending on the reward time unit
+pub fn get_remaining_reward_emission(reward_info: &RewardInfo, current_point: u64) -> Result<u64> {
+    Ok(reward_info.get_emission(current_point, reward_info.reward_duration_end)?)
+}
+
+/// Project emissions of the reward from current point to the end of reward duration, by interval of `interval_points`.
+/// The last interval ends at reward duration end. Current point and interval are in slots for rewards in slots
+pub fn get_reward_emission_projection(
+    reward_info: &RewardInfo,
+    current_point: u64,
+    interval_points: u64,
+) -> Result<Vec<RewardEmission>> {
+    ensure!(interval_points > 0, "interval must be positive");
+
+    let mut projection = vec![];
+    let mut start_point = current_point;
+    let mut total_amount = 0u64;
+
+    while start_point < reward_info.reward_duration_end {
+        let end_point = start_point
+            .safe_add(interval_points)?
+            .min(reward_info.reward_duration_end);
+
+        // emitted amount of each interval is derived from total emission to avoid accumulating rounding
+        let new_total_amount = reward_info.get_emission(current_point, end_point)?;
+        projection.push(RewardEmission {
+            start_point,
+            end_point,
+            amount: new_total_amount.safe_sub(total_amount)?,
+            total_amount: new_total_amount,
+        });
+
+        total_amount = new_total_amount;
+        start_point = end_point;
+    }
+
+    Ok(projection)
+}
@@ -1,7 +1,12 @@
 pub mod test_calculate_init_sqrt_price;
+pub mod test_position_pnl;
+pub mod test_position_valuation;
 pub mod test_quote_exact_in;
 pub mod test_quote_exact_out;
 pub mod test_quote_partial_fill_in;
+pub mod test_reward_apr;
+pub mod test_reward_emission;
+pub mod test_vesting_timeline;
 
 use cp_amm::state::Pool;
 use std::fs;
@@ -0,0 +1,66 @@
+use cp_amm::{state::Position, u128x128_math::Rounding};
+
+use crate::{
+    position_pnl::{get_fee_apr_bps, get_position_pnl},
+    tests::{get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+#[test]
+fn test_position_pnl_without_price_change() {
+    let mut pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    pool.reward_infos = Default::default();
+
+    let liquidity = pool.liquidity / 100;
+    let deposit_amounts = pool
+        .get_amounts_for_modify_liquidity(liquidity, Rounding::Up)
+        .unwrap();
+
+    let mut position = Position::default();
+    position.fee_a_per_token_checkpoint = pool.fee_a_per_liquidity;
+    position.fee_b_per_token_checkpoint = pool.fee_b_per_liquidity;
+    position.add_liquidity(liquidity).unwrap();
+    position
+        .cost_basis
+        .accumulate_deposit(
+            deposit_amounts.token_a_amount,
+            deposit_amounts.token_b_amount,
+            pool.sqrt_price,
+            1_000,
+        )
+        .unwrap();
+    position.metrics.accumulate_claimed_fee(0, 1_000).unwrap();
+
+    let pnl = get_position_pnl(&pool, &position, &[], &[], 1_000 + SECONDS_PER_YEAR, 0).unwrap();
+
+    assert_eq!(pnl.withdrawn_value_in_token_b, 0);
+    assert_eq!(
+        pnl.hodl_value_in_token_b,
+        pnl.deposited_value_in_token_b as i128
+    );
+    // only rounding loss when price doesn't move
+    assert!(pnl.impermanent_loss_in_token_b <= 0);
+    assert!(pnl.impermanent_loss_in_token_b >= -2);
+    assert_eq!(pnl.impermanent_loss_bps, 0);
+    assert_eq!(pnl.fee_value_in_token_b, 1_000);
+    assert_eq!(
+        pnl.net_pnl_in_token_b,
+        pnl.impermanent_loss_in_token_b + pnl.fee_value_in_token_b as i128
+    );
+}
+
+#[test]
+fn test_fee_apr_bps() {
+    assert_eq!(
+        get_fee_apr_bps(1_000, 10_000, 0, SECONDS_PER_YEAR).unwrap(),
+        1_000
+    );
+    assert_eq!(
+        get_fee_apr_bps(1_000, 10_000, 100, 100 + SECONDS_PER_YEAR / 2).unwrap(),
+        2_000
+    );
+    assert_eq!(get_fee_apr_bps(1_000, 0, 0, SECONDS_PER_YEAR).unwrap(), 0);
+    assert_eq!(get_fee_apr_bps(1_000, 10_000, 100, 100).unwrap(), 0);
+    assert!(get_fee_apr_bps(1_000, 10_000, 100, 99).is_err());
+}
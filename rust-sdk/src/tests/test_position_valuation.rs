@@ -0,0 +1,94 @@
+use alloy_primitives::U256;
+use cp_amm::{
+    state::{Position, Vesting},
+    u128x128_math::Rounding,
+};
+
+use crate::{
+    position_valuation::{get_position_valuation, get_token_a_value_in_token_b},
+    tests::{get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+#[test]
+fn test_position_valuation() {
+    let mut pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    // avoid touching pool rewards
+    pool.reward_infos = Default::default();
+
+    let liquidity = pool.liquidity / 100;
+    let mut position = Position::default();
+    position.fee_a_per_token_checkpoint = pool.fee_a_per_liquidity;
+    position.fee_b_per_token_checkpoint = pool.fee_b_per_liquidity;
+    position.add_liquidity(liquidity).unwrap();
+    position.lock(liquidity / 2).unwrap();
+
+    let mut vesting = Vesting::default();
+    vesting.initialize(Default::default(), 100, 10, liquidity / 4, liquidity / 8, 2);
+
+    // accumulate some fee after position checkpoint
+    let fee_delta = U256::from(1u128 << 64) * U256::from(1_000u64);
+    pool.fee_a_per_liquidity = (pool.fee_a_per_liquidity() + fee_delta).to_le_bytes();
+    pool.fee_b_per_liquidity = (pool.fee_b_per_liquidity() + fee_delta).to_le_bytes();
+
+    // cliff and 1 period passed
+    let current_point = 110;
+    let valuation = get_position_valuation(
+        &pool,
+        &position,
+        &[vesting],
+        &[],
+        current_point,
+        current_point,
+    )
+    .unwrap();
+
+    let released_liquidity = liquidity / 4 + liquidity / 8;
+    assert_eq!(
+        valuation.unlocked_liquidity,
+        liquidity - liquidity / 2 + released_liquidity
+    );
+    assert_eq!(
+        valuation.vested_liquidity,
+        liquidity / 2 - released_liquidity
+    );
+
+    let withdrawable_amounts = pool
+        .get_amounts_for_modify_liquidity(valuation.unlocked_liquidity, Rounding::Down)
+        .unwrap();
+    assert_eq!(
+        valuation.withdrawable_token_a_amount,
+        withdrawable_amounts.token_a_amount
+    );
+    assert_eq!(
+        valuation.withdrawable_token_b_amount,
+        withdrawable_amounts.token_b_amount
+    );
+
+    let mut expected_position = position;
+    expected_position
+        .update_fee(pool.fee_a_per_liquidity(), pool.fee_b_per_liquidity())
+        .unwrap();
+    assert!(valuation.claimable_fee_a > 0);
+    assert_eq!(valuation.claimable_fee_a, expected_position.fee_a_pending);
+    assert_eq!(valuation.claimable_fee_b, expected_position.fee_b_pending);
+
+    assert!(valuation.total_value_in_token_b > u128::from(valuation.withdrawable_token_b_amount));
+
+    // snapshot is not mutated
+    assert_eq!(position.vested_liquidity, liquidity / 2);
+    assert_eq!(position.fee_a_pending, 0);
+}
+
+#[test]
+fn test_token_a_value_in_token_b() {
+    // price = 1
+    assert_eq!(
+        get_token_a_value_in_token_b(1_000, 1u128 << 64).unwrap(),
+        1_000
+    );
+    // price = 4
+    assert_eq!(
+        get_token_a_value_in_token_b(1_000, 2u128 << 64).unwrap(),
+        4_000
+    );
+}
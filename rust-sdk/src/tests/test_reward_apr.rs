@@ -0,0 +1,95 @@
+use cp_amm::state::Pool;
+
+use crate::{
+    reward_apr::{get_pool_tvl, get_reward_analytics, TokenPrice},
+    tests::{get_pool_account, SOL_USDC_CL_ADDRESS},
+};
+
+const ONE_DAY: u64 = 60 * 60 * 24;
+const SECONDS_PER_YEAR: u64 = 365 * ONE_DAY;
+const REWARD_AMOUNT: u64 = 1_000_000_000;
+
+const SOL_PRICE: TokenPrice = TokenPrice {
+    price: 150.0,
+    decimals: 9,
+};
+const USDC_PRICE: TokenPrice = TokenPrice {
+    price: 1.0,
+    decimals: 6,
+};
+const REWARD_PRICE: TokenPrice = TokenPrice {
+    price: 2.0,
+    decimals: 6,
+};
+
+fn get_pool_with_reward(start_time: u64) -> Pool {
+    let mut pool = get_pool_account(SOL_USDC_CL_ADDRESS);
+    pool.reward_infos = Default::default();
+
+    let reward_info = &mut pool.reward_infos[0];
+    reward_info.initialized = 1;
+    reward_info.reward_duration = ONE_DAY;
+    reward_info
+        .update_rate_after_funding(start_time, REWARD_AMOUNT)
+        .unwrap();
+    pool
+}
+
+#[test]
+fn test_reward_apr() {
+    let start_time = 1_000;
+    let pool = get_pool_with_reward(start_time);
+    let current_time = start_time + ONE_DAY / 4;
+
+    let analytics = get_reward_analytics(
+        &pool,
+        current_time,
+        0,
+        SOL_PRICE,
+        USDC_PRICE,
+        &[Some(REWARD_PRICE), None],
+    )
+    .unwrap();
+    assert_eq!(analytics.len(), 1);
+
+    let reward = analytics[0];
+    assert_eq!(reward.reward_index, 0);
+    assert_eq!(reward.current_reward_rate, pool.reward_infos[0].reward_rate);
+    assert_eq!(reward.points_until_end, ONE_DAY / 4 * 3);
+    assert!(reward.remaining_reward.abs_diff(REWARD_AMOUNT / 4 * 3) <= 1);
+    assert_eq!(reward.ineligible_reward, 0);
+
+    // whole reward amount is emitted every day
+    let tvl = get_pool_tvl(&pool, SOL_PRICE, USDC_PRICE).unwrap();
+    let expected_apr =
+        REWARD_PRICE.get_value(REWARD_AMOUNT.into()) * (SECONDS_PER_YEAR / ONE_DAY) as f64 / tvl
+            * 100.0;
+    assert!((reward.apr - expected_apr).abs() / expected_apr < 1e-6);
+}
+
+#[test]
+fn test_reward_analytics_without_liquidity() {
+    let start_time = 1_000;
+    let mut pool = get_pool_with_reward(start_time);
+    pool.liquidity = 0;
+
+    let analytics = get_reward_analytics(
+        &pool,
+        start_time + ONE_DAY * 2,
+        0,
+        SOL_PRICE,
+        USDC_PRICE,
+        &[Some(REWARD_PRICE), None],
+    )
+    .unwrap();
+
+    let reward = analytics[0];
+    assert_eq!(reward.apr, 0.0);
+    assert_eq!(reward.points_until_end, 0);
+    assert_eq!(reward.remaining_reward, 0);
+    assert_eq!(
+        reward.cumulative_seconds_with_empty_liquidity_reward,
+        ONE_DAY
+    );
+    assert!(reward.ineligible_reward.abs_diff(REWARD_AMOUNT) <= 1);
+}
@@ -0,0 +1,62 @@
+use cp_amm::state::{RewardEmissionSchedule, RewardInfo};
+
+use crate::reward_emission::{get_remaining_reward_emission, get_reward_emission_projection};
+
+const ONE_DAY: u64 = 60 * 60 * 24;
+const ONE_HOUR: u64 = 60 * 60;
+const AMOUNT: u64 = 1_000_000_000;
+
+fn get_funded_reward_info(
+    emission_schedule: RewardEmissionSchedule,
+    emission_parameter: u8,
+) -> RewardInfo {
+    let mut reward_info = RewardInfo::default();
+    reward_info.initialized = 1;
+    reward_info.reward_duration = ONE_DAY;
+    reward_info.emission_schedule = emission_schedule.into();
+    reward_info.emission_parameter = emission_parameter;
+    reward_info.update_rate_after_funding(0, AMOUNT).unwrap();
+    reward_info
+}
+
+#[test]
+fn test_reward_emission_projection() {
+    let reward_info = get_funded_reward_info(RewardEmissionSchedule::Halving, 4);
+    let current_point = ONE_HOUR * 5;
+
+    let remaining = get_remaining_reward_emission(&reward_info, current_point).unwrap();
+    let projection = get_reward_emission_projection(&reward_info, current_point, ONE_HOUR).unwrap();
+
+    assert_eq!(projection.len(), 19);
+    assert_eq!(projection.first().unwrap().start_point, current_point);
+    assert_eq!(projection.last().unwrap().end_point, ONE_DAY);
+    assert_eq!(projection.last().unwrap().total_amount, remaining);
+    assert_eq!(
+        projection
+            .iter()
+            .map(|emission| emission.amount)
+            .sum::<u64>(),
+        remaining
+    );
+
+    // emission halves every 6 hours
+    let hour_before_halving = projection[0].amount;
+    let hour_after_halving = projection[1].amount;
+    assert!(hour_before_halving.abs_diff(hour_after_halving * 2) <= 2);
+}
+
+#[test]
+fn test_reward_emission_projection_after_end() {
+    let reward_info = get_funded_reward_info(RewardEmissionSchedule::LinearDecay, 0);
+
+    assert_eq!(
+        get_remaining_reward_emission(&reward_info, ONE_DAY).unwrap(),
+        0
+    );
+    assert!(
+        get_reward_emission_projection(&reward_info, ONE_DAY, ONE_HOUR)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(get_reward_emission_projection(&reward_info, 0, 0).is_err());
+}
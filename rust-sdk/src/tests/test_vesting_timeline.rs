@@ -0,0 +1,41 @@
+use cp_amm::{
+    state::{Vesting, VestingCurveType},
+    ExtendedVestingParameters,
+};
+
+use crate::vesting_timeline::{
+    get_extended_vesting_parameters_unlock_timeline, get_vesting_unlock_timeline,
+};
+
+#[test]
+fn test_vesting_unlock_timeline() {
+    let mut vesting = Vesting::default();
+    vesting.initialize(Default::default(), 100, 10, 500, 250, 4);
+
+    let timeline = get_vesting_unlock_timeline(&vesting).unwrap();
+    assert_eq!(timeline.len(), 5);
+    assert_eq!(timeline[0].point, 100);
+    assert_eq!(timeline[0].unlocked_liquidity, 500);
+    assert_eq!(timeline[4].point, 140);
+    assert_eq!(timeline[4].unlocked_liquidity, 250);
+    assert_eq!(timeline[4].total_unlocked_liquidity, 1_500);
+}
+
+#[test]
+fn test_extended_vesting_parameters_unlock_timeline() {
+    let params = ExtendedVestingParameters {
+        cliff_point: Some(100),
+        period_frequency: 10,
+        cliff_unlock_liquidity: 0,
+        periodic_unlock_liquidity: 1_000,
+        number_of_period: 3,
+        curve_type: VestingCurveType::Custom.into(),
+        curve_parameter: 0,
+        schedule: vec![100, 200, 700],
+    };
+
+    let timeline = get_extended_vesting_parameters_unlock_timeline(&params, 0, 1_000).unwrap();
+    let unlocked_liquidity: Vec<u128> = timeline.iter().map(|u| u.unlocked_liquidity).collect();
+    assert_eq!(unlocked_liquidity, vec![0, 100, 200, 700]);
+    assert_eq!(timeline.last().unwrap().total_unlocked_liquidity, 1_000);
+}
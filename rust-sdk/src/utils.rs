@@ -22,5 +22,5 @@ pub fn get_current_point(
 
 pub fn is_swap_enable(pool: &Pool, current_point: u64) -> Result<bool> {
     let pool_status = PoolStatus::try_from(pool.pool_status).context("invalid pool status")?;
-    Ok(pool_status == PoolStatus::Enable && current_point >= pool.activation_point)
+    Ok(pool_status.is_swap_allowed() && current_point >= pool.activation_point)
 }
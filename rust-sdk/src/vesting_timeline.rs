@@ -0,0 +1,94 @@
+use anyhow::{Ok, Result};
+use cp_amm::{
+    safe_math::SafeMath,
+    state::{ExtendedVesting, Vesting},
+    ExtendedVestingParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VestingUnlock {
+    /// Slot or timestamp, depends on pool activation type
+    pub point: u64,
+    /// Liquidity unlocked at this point
+    pub unlocked_liquidity: u128,
+    /// Total liquidity unlocked until this point, including cliff
+    pub total_unlocked_liquidity: u128,
+}
+
+/// Unlock timeline of a vesting, from cliff point to the last period
+pub fn get_vesting_unlock_timeline(vesting: &Vesting) -> Result<Vec<VestingUnlock>> {
+    build_unlock_timeline(
+        vesting.cliff_point,
+        vesting.period_frequency,
+        vesting.number_of_period,
+        |point| Ok(vesting.get_max_unlocked_liquidity(point)?),
+    )
+}
+
+/// Unlock timeline of an extended vesting, from cliff point to the last period
+pub fn get_extended_vesting_unlock_timeline(
+    vesting: &ExtendedVesting,
+) -> Result<Vec<VestingUnlock>> {
+    build_unlock_timeline(
+        vesting.cliff_point,
+        vesting.period_frequency,
+        vesting.number_of_period,
+        |point| Ok(vesting.get_max_unlocked_liquidity(point)?),
+    )
+}
+
+/// Preview unlock timeline of extended vesting parameters before locking the position
+pub fn get_extended_vesting_parameters_unlock_timeline(
+    params: &ExtendedVestingParameters,
+    current_point: u64,
+    max_vesting_duration: u64,
+) -> Result<Vec<VestingUnlock>> {
+    params.validate(current_point, max_vesting_duration)?;
+
+    let mut vesting = ExtendedVesting::default();
+    vesting.initialize(
+        Default::default(),
+        params.get_cliff_point(current_point)?,
+        params.period_frequency,
+        params.cliff_unlock_liquidity,
+        params.periodic_unlock_liquidity,
+        params.number_of_period,
+        params.curve_type,
+        params.curve_parameter,
+        &params.schedule,
+    );
+
+    get_extended_vesting_unlock_timeline(&vesting)
+}
+
+fn build_unlock_timeline(
+    cliff_point: u64,
+    period_frequency: u64,
+    number_of_period: u16,
+    get_max_unlocked_liquidity: impl Fn(u64) -> Result<u128>,
+) -> Result<Vec<VestingUnlock>> {
+    let mut timeline = vec![];
+    let mut total_unlocked_liquidity = 0u128;
+
+    let number_of_period = if period_frequency == 0 {
+        0
+    } else {
+        number_of_period
+    };
+
+    for period in 0..=u64::from(number_of_period) {
+        let point = period
+            .safe_mul(period_frequency)
+            .and_then(|duration| cliff_point.safe_add(duration))
+            .map_err(|_| anyhow::anyhow!("Math overflow"))?;
+        let max_unlocked_liquidity = get_max_unlocked_liquidity(point)?;
+        timeline.push(VestingUnlock {
+            point,
+            unlocked_liquidity: max_unlocked_liquidity.saturating_sub(total_unlocked_liquidity),
+            total_unlocked_liquidity: max_unlocked_liquidity,
+        });
+        total_unlocked_liquidity = max_unlocked_liquidity;
+    }
+
+    Ok(timeline)
+}
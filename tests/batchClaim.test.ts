@@ -0,0 +1,240 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import {
+  addLiquidity,
+  batchClaim,
+  batchClaimInstruction,
+  createConfigIx,
+  CreateConfigParams,
+  createOperator,
+  createPosition,
+  createToken,
+  deriveRewardVaultAddress,
+  encodePermissions,
+  expectThrowsErrorCode,
+  freezeTokenAccount,
+  fundReward,
+  getCpAmmProgramErrorCode,
+  getPosition,
+  getTokenBalance,
+  initializePool,
+  initializeReward,
+  MAX_SQRT_PRICE,
+  MIN_LP_AMOUNT,
+  MIN_SQRT_PRICE,
+  mintSplTokenTo,
+  OperatorPermission,
+  sendTransaction,
+  startSvm,
+  swapExactIn,
+  U64_MAX,
+  warpToTimestamp,
+} from "./helpers";
+import { generateKpAndFund } from "./helpers/common";
+import { BaseFeeMode, encodeFeeTimeSchedulerParams } from "./helpers/feeCodec";
+
+describe("Batch claim", () => {
+  let svm: LiteSVM;
+  let admin: Keypair;
+  let user: Keypair;
+  let creator: Keypair;
+  let funder: Keypair;
+  let whitelistedAccount: Keypair;
+  let config: PublicKey;
+  let pool: PublicKey;
+  let positions: PublicKey[];
+  let tokenAMint: PublicKey;
+  let tokenBMint: PublicKey;
+  let rewardMint: PublicKey;
+  const rewardIndex = 0;
+  const configId = Math.floor(Math.random() * 1000);
+
+  beforeEach(async () => {
+    svm = startSvm();
+
+    user = generateKpAndFund(svm);
+    admin = generateKpAndFund(svm);
+    creator = generateKpAndFund(svm);
+    funder = generateKpAndFund(svm);
+    whitelistedAccount = generateKpAndFund(svm);
+
+    tokenAMint = createToken(svm, admin.publicKey, admin.publicKey);
+    tokenBMint = createToken(svm, admin.publicKey, admin.publicKey);
+    rewardMint = createToken(svm, admin.publicKey, creator.publicKey);
+
+    mintSplTokenTo(svm, tokenAMint, admin, user.publicKey);
+    mintSplTokenTo(svm, tokenBMint, admin, user.publicKey);
+    mintSplTokenTo(svm, tokenAMint, admin, creator.publicKey);
+    mintSplTokenTo(svm, tokenBMint, admin, creator.publicKey);
+    mintSplTokenTo(svm, rewardMint, admin, funder.publicKey);
+
+    const data = encodeFeeTimeSchedulerParams(
+      BigInt(2_500_000),
+      0,
+      BigInt(0),
+      BigInt(0),
+      BaseFeeMode.FeeTimeSchedulerLinear
+    );
+
+    const createConfigParams: CreateConfigParams = {
+      poolFees: {
+        baseFee: {
+          data: Array.from(data),
+        },
+        padding: [],
+        dynamicFee: null,
+      },
+      sqrtMinPrice: new BN(MIN_SQRT_PRICE),
+      sqrtMaxPrice: new BN(MAX_SQRT_PRICE),
+      vaultConfigKey: PublicKey.default,
+      poolCreatorAuthority: PublicKey.default,
+      activationType: 0,
+      collectFeeMode: 0,
+    };
+
+    await createOperator(svm, {
+      admin,
+      whitelistAddress: whitelistedAccount.publicKey,
+      permission: encodePermissions([OperatorPermission.CreateConfigKey]),
+    });
+
+    config = await createConfigIx(
+      svm,
+      whitelistedAccount,
+      new BN(configId),
+      createConfigParams
+    );
+
+    const result = await initializePool(svm, {
+      payer: creator,
+      creator: creator.publicKey,
+      config,
+      tokenAMint,
+      tokenBMint,
+      liquidity: new BN(MIN_LP_AMOUNT),
+      sqrtPrice: new BN(MIN_SQRT_PRICE.muln(2)),
+      activationPoint: null,
+    });
+    pool = result.pool;
+
+    // two positions of the same owner
+    positions = [];
+    for (let i = 0; i < 2; i++) {
+      const position = await createPosition(svm, user, user.publicKey, pool);
+      await addLiquidity(svm, {
+        owner: user,
+        pool,
+        position,
+        liquidityDelta: new BN(MIN_SQRT_PRICE.muln(30)),
+        tokenAAmountThreshold: U64_MAX,
+        tokenBAmountThreshold: U64_MAX,
+      });
+      positions.push(position);
+    }
+
+    await initializeReward(svm, {
+      index: rewardIndex,
+      payer: creator,
+      rewardDuration: new BN(24 * 60 * 60),
+      pool,
+      rewardMint,
+      funder: funder.publicKey,
+    });
+
+    await fundReward(svm, {
+      index: rewardIndex,
+      funder,
+      pool,
+      carryForward: true,
+      amount: new BN("1000000000"),
+    });
+
+    // earn fees of both tokens
+    for (const [inputTokenMint, outputTokenMint] of [
+      [tokenAMint, tokenBMint],
+      [tokenBMint, tokenAMint],
+    ]) {
+      await swapExactIn(svm, {
+        payer: user,
+        pool,
+        inputTokenMint,
+        outputTokenMint,
+        amountIn: new BN(1_000_000),
+        minimumAmountOut: new BN(0),
+        referralTokenAccount: null,
+      });
+    }
+
+    const currentClock = svm.getClock();
+    warpToTimestamp(svm, new BN(Number(currentClock.unixTimestamp) + 3600));
+  });
+
+  it("Claim fees and rewards of many positions", async () => {
+    await batchClaim(svm, {
+      owner: user,
+      pool,
+      positions,
+      rewardIndexes: [rewardIndex],
+    });
+
+    for (const position of positions) {
+      const positionState = getPosition(svm, position);
+      expect(positionState.feeAPending.toNumber()).eq(0);
+      expect(positionState.feeBPending.toNumber()).eq(0);
+      expect(
+        positionState.rewardInfos[rewardIndex].rewardPendings.toNumber()
+      ).eq(0);
+    }
+
+    const userRewardBalance = getTokenBalance(
+      svm,
+      getAssociatedTokenAddressSync(rewardMint, user.publicKey)
+    );
+    expect(new BN(userRewardBalance).gtn(0)).to.be.true;
+  });
+
+  it("Reward mint must match the reward info", async () => {
+    const transaction = await batchClaimInstruction(svm, {
+      owner: user,
+      pool,
+      positions,
+      rewardIndexes: [rewardIndex],
+    });
+
+    // replace reward 0 mint with another mint
+    const rewardMintAccount = transaction.instructions[0].keys.find((key) =>
+      key.pubkey.equals(rewardMint)
+    );
+    rewardMintAccount.pubkey = tokenAMint;
+
+    const result = sendTransaction(svm, transaction, [user]);
+    expectThrowsErrorCode(
+      result,
+      getCpAmmProgramErrorCode("InvalidRewardVault")
+    );
+  });
+
+  it("Frozen reward vault keeps reward pending and fees are claimed", async () => {
+    const rewardVault = deriveRewardVaultAddress(pool, rewardIndex);
+    freezeTokenAccount(svm, creator, rewardMint, rewardVault);
+
+    await batchClaim(svm, {
+      owner: user,
+      pool,
+      positions,
+      rewardIndexes: [rewardIndex],
+    });
+
+    for (const position of positions) {
+      const positionState = getPosition(svm, position);
+      expect(positionState.feeAPending.toNumber()).eq(0);
+      expect(positionState.feeBPending.toNumber()).eq(0);
+      expect(
+        positionState.rewardInfos[rewardIndex].rewardPendings.gtn(0)
+      ).to.be.true;
+    }
+  });
+});
@@ -0,0 +1,202 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { expect } from "chai";
+import { LiteSVM, TransactionMetadata } from "litesvm";
+import {
+  addLiquidity,
+  addLiquidityInstruction,
+  AddLiquidityParams,
+  claimPositionFeeInstruction,
+  createConfigIx,
+  CreateConfigParams,
+  createOperator,
+  createPosition,
+  createToken,
+  encodePermissions,
+  expectThrowsErrorCode,
+  getCpAmmProgramErrorCode,
+  initializePool,
+  InitializePoolParams,
+  MAX_SQRT_PRICE,
+  MIN_LP_AMOUNT,
+  MIN_SQRT_PRICE,
+  mintSplTokenTo,
+  OperatorPermission,
+  PauseAction,
+  pauseActions,
+  removeLiquidityInstruction,
+  sendTransaction,
+  startSvm,
+  swapExactIn,
+  swapInstruction,
+  SwapParams,
+  unpauseActions,
+} from "./helpers";
+import { generateKpAndFund } from "./helpers/common";
+import { BaseFeeMode, encodeFeeTimeSchedulerParams } from "./helpers/feeCodec";
+
+describe("Global pause", () => {
+  let svm: LiteSVM;
+  let admin: Keypair;
+  let user: Keypair;
+  let creator: Keypair;
+  let whitelistedAccount: Keypair;
+  let config: PublicKey;
+  let pool: PublicKey;
+  let position: PublicKey;
+  let tokenAMint: PublicKey;
+  let tokenBMint: PublicKey;
+  let addLiquidityParams: AddLiquidityParams;
+  let swapParams: SwapParams;
+  const configId = Math.floor(Math.random() * 1000);
+  const actionPausedErrorCode = getCpAmmProgramErrorCode("ActionPaused");
+
+  beforeEach(async () => {
+    svm = startSvm();
+
+    user = generateKpAndFund(svm);
+    admin = generateKpAndFund(svm);
+    creator = generateKpAndFund(svm);
+    whitelistedAccount = generateKpAndFund(svm);
+
+    tokenAMint = createToken(svm, admin.publicKey, admin.publicKey);
+    tokenBMint = createToken(svm, admin.publicKey, admin.publicKey);
+
+    mintSplTokenTo(svm, tokenAMint, admin, user.publicKey);
+    mintSplTokenTo(svm, tokenBMint, admin, user.publicKey);
+    mintSplTokenTo(svm, tokenAMint, admin, creator.publicKey);
+    mintSplTokenTo(svm, tokenBMint, admin, creator.publicKey);
+
+    const data = encodeFeeTimeSchedulerParams(
+      BigInt(2_500_000),
+      0,
+      BigInt(0),
+      BigInt(0),
+      BaseFeeMode.FeeTimeSchedulerLinear
+    );
+
+    const createConfigParams: CreateConfigParams = {
+      poolFees: {
+        baseFee: {
+          data: Array.from(data),
+        },
+        padding: [],
+        dynamicFee: null,
+      },
+      sqrtMinPrice: new BN(MIN_SQRT_PRICE),
+      sqrtMaxPrice: new BN(MAX_SQRT_PRICE),
+      vaultConfigKey: PublicKey.default,
+      poolCreatorAuthority: PublicKey.default,
+      activationType: 0,
+      collectFeeMode: 0,
+    };
+
+    await createOperator(svm, {
+      admin,
+      whitelistAddress: whitelistedAccount.publicKey,
+      permission: encodePermissions([OperatorPermission.CreateConfigKey]),
+    });
+
+    config = await createConfigIx(
+      svm,
+      whitelistedAccount,
+      new BN(configId),
+      createConfigParams
+    );
+
+    const initPoolParams: InitializePoolParams = {
+      payer: creator,
+      creator: creator.publicKey,
+      config,
+      tokenAMint,
+      tokenBMint,
+      liquidity: new BN(MIN_LP_AMOUNT),
+      sqrtPrice: new BN(MIN_SQRT_PRICE.muln(2)),
+      activationPoint: null,
+    };
+
+    const result = await initializePool(svm, initPoolParams);
+    pool = result.pool;
+    position = await createPosition(svm, user, user.publicKey, pool);
+
+    addLiquidityParams = {
+      owner: user,
+      pool,
+      position,
+      liquidityDelta: new BN(MIN_SQRT_PRICE.muln(30)),
+      tokenAAmountThreshold: new BN(200),
+      tokenBAmountThreshold: new BN(200),
+    };
+
+    swapParams = {
+      payer: user,
+      pool,
+      inputTokenMint: tokenAMint,
+      outputTokenMint: tokenBMint,
+      amountIn: new BN(10),
+      minimumAmountOut: new BN(0),
+      referralTokenAccount: null,
+    };
+  });
+
+  it("Paused swap is rejected and works again after unpause", async () => {
+    await addLiquidity(svm, addLiquidityParams);
+
+    await pauseActions(svm, { admin, actions: [PauseAction.Swap] });
+
+    const transaction = await swapInstruction(svm, swapParams);
+    const result = sendTransaction(svm, transaction, [user]);
+    expectThrowsErrorCode(result, actionPausedErrorCode);
+
+    await unpauseActions(svm, { admin, actions: [PauseAction.Swap] });
+    await swapExactIn(svm, swapParams);
+  });
+
+  it("Paused add liquidity is rejected", async () => {
+    await pauseActions(svm, { admin, actions: [PauseAction.AddLiquidity] });
+
+    const transaction = await addLiquidityInstruction(svm, addLiquidityParams);
+    const result = sendTransaction(svm, transaction, [user]);
+    expectThrowsErrorCode(result, actionPausedErrorCode);
+  });
+
+  it("Paused remove liquidity is rejected", async () => {
+    await addLiquidity(svm, addLiquidityParams);
+
+    await pauseActions(svm, { admin, actions: [PauseAction.RemoveLiquidity] });
+
+    const transaction = await removeLiquidityInstruction(svm, {
+      ...addLiquidityParams,
+      tokenAAmountThreshold: new BN(0),
+      tokenBAmountThreshold: new BN(0),
+    });
+    const result = sendTransaction(svm, transaction, [user]);
+    expectThrowsErrorCode(result, actionPausedErrorCode);
+  });
+
+  it("Paused claim is rejected", async () => {
+    await addLiquidity(svm, addLiquidityParams);
+    await swapExactIn(svm, swapParams);
+
+    await pauseActions(svm, { admin, actions: [PauseAction.Claim] });
+
+    const transaction = await claimPositionFeeInstruction(svm, {
+      owner: user,
+      pool,
+      position,
+    });
+    const result = sendTransaction(svm, transaction, [user]);
+    expectThrowsErrorCode(result, actionPausedErrorCode);
+  });
+
+  it("Pausing one action keeps other actions open", async () => {
+    await pauseActions(svm, {
+      admin,
+      actions: [PauseAction.Swap, PauseAction.Claim],
+    });
+
+    const transaction = await addLiquidityInstruction(svm, addLiquidityParams);
+    const result = sendTransaction(svm, transaction, [user]);
+    expect(result).instanceOf(TransactionMetadata);
+  });
+});
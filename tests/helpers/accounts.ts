@@ -11,6 +11,20 @@ export function derivePoolAuthority(): PublicKey {
   )[0];
 }
 
+export function deriveGlobalPause(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("global_pause")],
+    CP_AMM_PROGRAM_ID
+  )[0];
+}
+
+export function deriveAdminState(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_state")],
+    CP_AMM_PROGRAM_ID
+  )[0];
+}
+
 export function deriveEventAuthority(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("__event_authority")],
@@ -42,8 +42,10 @@ import {
 import CpAmmIDL from "../../target/idl/cp_amm.json";
 import { CpAmm } from "../../target/types/cp_amm";
 import {
+  deriveAdminState,
   deriveConfigAddress,
   deriveCustomizablePoolAddress,
+  deriveGlobalPause,
   deriveOperatorAddress,
   derivePoolAddress,
   derivePoolAuthority,
@@ -445,6 +447,7 @@ export async function createOperator(
   const transaction = await program.methods
     .createOperatorAccount(permission)
     .accountsPartial({
+      adminState: deriveAdminState(),
       operator: deriveOperatorAddress(whitelistAddress),
       whitelistedAddress: whitelistAddress,
       signer: admin.publicKey,
@@ -458,6 +461,72 @@ export async function createOperator(
   expect(result).instanceOf(TransactionMetadata);
 }
 
+export function globalPauseAccountMeta(): AccountMeta {
+  return {
+    isSigner: false,
+    isWritable: false,
+    pubkey: deriveGlobalPause(),
+  };
+}
+
+export enum PauseAction {
+  Swap, // 0
+  AddLiquidity, // 1
+  RemoveLiquidity, // 2
+  Claim, // 3
+}
+
+export function encodePauseActions(actions: PauseAction[]): number {
+  return actions.reduce((acc, action) => acc | (1 << action), 0);
+}
+
+export type UpdateGlobalPauseParams = {
+  admin: Keypair;
+  actions: PauseAction[];
+};
+
+export async function pauseActions(
+  svm: LiteSVM,
+  params: UpdateGlobalPauseParams
+) {
+  const program = createCpAmmProgram();
+  const { admin, actions } = params;
+
+  const transaction = await program.methods
+    .pauseActions(encodePauseActions(actions))
+    .accountsPartial({
+      globalPause: deriveGlobalPause(),
+      adminState: deriveAdminState(),
+      signer: admin.publicKey,
+    })
+    .transaction();
+
+  const result = sendTransaction(svm, transaction, [admin]);
+
+  expect(result).instanceOf(TransactionMetadata);
+}
+
+export async function unpauseActions(
+  svm: LiteSVM,
+  params: UpdateGlobalPauseParams
+) {
+  const program = createCpAmmProgram();
+  const { admin, actions } = params;
+
+  const transaction = await program.methods
+    .unpauseActions(encodePauseActions(actions))
+    .accountsPartial({
+      globalPause: deriveGlobalPause(),
+      adminState: deriveAdminState(),
+      signer: admin.publicKey,
+    })
+    .transaction();
+
+  const result = sendTransaction(svm, transaction, [admin]);
+
+  expect(result).instanceOf(TransactionMetadata);
+}
+
 export type UpdatePoolFeesParams = {
   pool: PublicKey;
   whitelistedOperator: Keypair;
@@ -565,6 +634,7 @@ export async function claimProtocolFee(
       tokenAProgram,
       tokenBProgram,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
   const result = sendTransaction(svm, transaction, [whitelistedKP]);
@@ -617,6 +687,7 @@ export async function claimPartnerFee(
       tokenAProgram,
       tokenBProgram,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
   const result = sendTransaction(svm, transaction, [partner]);
@@ -707,6 +778,7 @@ export async function initializePool(
       tokenBProgram,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
   // requires more compute budget than usual
   transaction.add(
@@ -839,6 +911,7 @@ export async function initializePoolWithCustomizeConfig(
       tokenBProgram,
       token2022Program: TOKEN_2022_PROGRAM_ID,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
   // requires more compute budget than usual
   transaction.add(
@@ -1000,6 +1073,7 @@ export async function initializeCustomizablePool(
       tokenBProgram,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
   // requires more compute budget than usual
   transaction.add(
@@ -1367,6 +1441,7 @@ export async function claimReward(
       owner: user.publicKey,
       tokenProgram,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
   const result = sendTransaction(svm, transaction, [user]);
@@ -1407,6 +1482,7 @@ export async function withdrawIneligibleReward(
       funder: funder.publicKey,
       tokenProgram,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
   const result = sendTransaction(svm, transaction, [funder]);
@@ -1581,7 +1657,10 @@ export type AddLiquidityParams = {
   tokenBAmountThreshold: BN;
 };
 
-export async function addLiquidity(svm: LiteSVM, params: AddLiquidityParams) {
+export async function addLiquidityInstruction(
+  svm: LiteSVM,
+  params: AddLiquidityParams
+): Promise<Transaction> {
   const {
     owner,
     pool,
@@ -1636,19 +1715,26 @@ export async function addLiquidity(svm: LiteSVM, params: AddLiquidityParams) {
       tokenAMint,
       tokenBMint,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
-  const result = sendTransaction(svm, transaction, [owner]);
+  return transaction;
+}
+
+export async function addLiquidity(svm: LiteSVM, params: AddLiquidityParams) {
+  const transaction = await addLiquidityInstruction(svm, params);
+
+  const result = sendTransaction(svm, transaction, [params.owner]);
 
   expect(result).instanceOf(TransactionMetadata);
 }
 
 export type RemoveLiquidityParams = AddLiquidityParams;
 
-export async function removeLiquidity(
+export async function removeLiquidityInstruction(
   svm: LiteSVM,
   params: RemoveLiquidityParams
-) {
+): Promise<Transaction> {
   const {
     owner,
     pool,
@@ -1705,9 +1791,19 @@ export async function removeLiquidity(
       tokenAMint,
       tokenBMint,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
-  const result = sendTransaction(svm, transaction, [owner]);
+  return transaction;
+}
+
+export async function removeLiquidity(
+  svm: LiteSVM,
+  params: RemoveLiquidityParams
+) {
+  const transaction = await removeLiquidityInstruction(svm, params);
+
+  const result = sendTransaction(svm, transaction, [params.owner]);
   expect(result).instanceOf(TransactionMetadata);
 }
 
@@ -1774,6 +1870,7 @@ export async function removeAllLiquidity(
       tokenAMint,
       tokenBMint,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
   const result = sendTransaction(svm, transaction, [owner]);
@@ -1882,6 +1979,7 @@ export async function swapInstruction(
     .remainingAccounts(
       // TODO should check condition to add this in remaining accounts
       [
+        globalPauseAccountMeta(),
         {
           isSigner: false,
           isWritable: false,
@@ -1969,6 +2067,7 @@ export async function swap2Instruction(svm: LiteSVM, params: Swap2Params) {
     .remainingAccounts(
       // TODO should check condition to add this in remaining accounts
       [
+        globalPauseAccountMeta(),
         {
           isSigner: false,
           isWritable: false,
@@ -2035,10 +2134,10 @@ export type ClaimPositionFeeParams = {
   position: PublicKey;
 };
 
-export async function claimPositionFee(
+export async function claimPositionFeeInstruction(
   svm: LiteSVM,
   params: ClaimPositionFeeParams
-) {
+): Promise<Transaction> {
   const { owner, pool, position } = params;
 
   const program = createCpAmmProgram();
@@ -2084,9 +2183,133 @@ export async function claimPositionFee(
       tokenAMint,
       tokenBMint,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .transaction();
 
-  const result = sendTransaction(svm, transaction, [owner]);
+  return transaction;
+}
+
+export async function claimPositionFee(
+  svm: LiteSVM,
+  params: ClaimPositionFeeParams
+) {
+  const transaction = await claimPositionFeeInstruction(svm, params);
+
+  const result = sendTransaction(svm, transaction, [params.owner]);
+
+  expect(result).instanceOf(TransactionMetadata);
+}
+
+export type BatchClaimParams = {
+  owner: Keypair;
+  pool: PublicKey;
+  positions: PublicKey[];
+  rewardIndexes: number[];
+};
+
+export async function batchClaimInstruction(
+  svm: LiteSVM,
+  params: BatchClaimParams
+): Promise<Transaction> {
+  const { owner, pool, positions, rewardIndexes } = params;
+
+  const program = createCpAmmProgram();
+  const poolState = getPool(svm, pool);
+
+  const poolAuthority = derivePoolAuthority();
+  const tokenAProgram = svm.getAccount(poolState.tokenAMint).owner;
+  const tokenBProgram = svm.getAccount(poolState.tokenBMint).owner;
+
+  const tokenAAccount = getOrCreateAssociatedTokenAccount(
+    svm,
+    owner,
+    poolState.tokenAMint,
+    owner.publicKey,
+    tokenAProgram
+  );
+  const tokenBAccount = getOrCreateAssociatedTokenAccount(
+    svm,
+    owner,
+    poolState.tokenBMint,
+    owner.publicKey,
+    tokenBProgram
+  );
+
+  // reward accounts are passed only for claimed reward indexes
+  const rewardAccounts = [0, 1].map((index) => {
+    if (rewardIndexes.indexOf(index) === -1) {
+      return {
+        vault: null,
+        mint: null,
+        tokenAccount: null,
+        tokenProgram: null,
+      };
+    }
+
+    const rewardInfo = poolState.rewardInfos[index];
+    const tokenProgram = svm.getAccount(rewardInfo.mint).owner;
+    return {
+      vault: rewardInfo.vault,
+      mint: rewardInfo.mint,
+      tokenAccount: getOrCreateAssociatedTokenAccount(
+        svm,
+        owner,
+        rewardInfo.mint,
+        owner.publicKey,
+        tokenProgram
+      ),
+      tokenProgram,
+    };
+  });
+
+  const remainingAccounts: AccountMeta[] = [];
+  for (const position of positions) {
+    const positionState = getPosition(svm, position);
+    remainingAccounts.push({
+      isSigner: false,
+      isWritable: true,
+      pubkey: position,
+    });
+    remainingAccounts.push({
+      isSigner: false,
+      isWritable: false,
+      pubkey: derivePositionNftAccount(positionState.nftMint),
+    });
+  }
+
+  const transaction = await program.methods
+    .batchClaim()
+    .accountsPartial({
+      poolAuthority,
+      pool,
+      tokenAAccount,
+      tokenBAccount,
+      tokenAVault: poolState.tokenAVault,
+      tokenBVault: poolState.tokenBVault,
+      tokenAMint: poolState.tokenAMint,
+      tokenBMint: poolState.tokenBMint,
+      reward0Vault: rewardAccounts[0].vault,
+      reward0Mint: rewardAccounts[0].mint,
+      reward0TokenAccount: rewardAccounts[0].tokenAccount,
+      reward0TokenProgram: rewardAccounts[0].tokenProgram,
+      reward1Vault: rewardAccounts[1].vault,
+      reward1Mint: rewardAccounts[1].mint,
+      reward1TokenAccount: rewardAccounts[1].tokenAccount,
+      reward1TokenProgram: rewardAccounts[1].tokenProgram,
+      owner: owner.publicKey,
+      tokenAProgram,
+      tokenBProgram,
+    })
+    .remainingAccounts([globalPauseAccountMeta(), ...remainingAccounts])
+    .transaction();
+
+  return transaction;
+}
+
+export async function batchClaim(svm: LiteSVM, params: BatchClaimParams) {
+  const transaction = await batchClaimInstruction(svm, params);
+
+  const result = sendTransaction(svm, transaction, [params.owner]);
 
   expect(result).instanceOf(TransactionMetadata);
 }
@@ -2238,6 +2461,7 @@ export async function zapProtocolFee(params: {
       tokenProgram,
       sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
     })
+    .remainingAccounts([globalPauseAccountMeta()])
     .postInstructions(postInstruction ? [postInstruction] : [])
     .transaction();
 
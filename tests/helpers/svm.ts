@@ -1,6 +1,14 @@
 import { base64 } from "@coral-xyz/anchor/dist/cjs/utils/bytes";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { PublicKey, Signer, SystemProgram, Transaction } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import BN from "bn.js";
 import { expect } from "chai";
 import {
@@ -10,10 +18,14 @@ import {
   TransactionMetadata,
 } from "litesvm";
 import path from "path";
+import CpAmmIDL from "../../target/idl/cp_amm.json";
 import {
   ALPHA_VAULT_PROGRAM_ID,
   CP_AMM_PROGRAM_ID,
   createCpAmmProgram,
+  deriveAdminState,
+  deriveEventAuthority,
+  deriveGlobalPause,
   JUPITER_V6_PROGRAM_ID,
   NATIVE_MINT,
   ZAP_PROGRAM_ID,
@@ -67,9 +79,59 @@ export function startSvm() {
     executable: false,
   });
 
+  initializeGlobalAccounts(svm);
+
   return svm;
 }
 
+function getInstructionDiscriminator(name: string): Buffer {
+  const instruction = CpAmmIDL.instructions.find((ix) => ix.name === name);
+  return Buffer.from(instruction.discriminator);
+}
+
+// Admin state and global pause are singletons required by admin, swap, liquidity and claim endpoints.
+// Any signer is an admin with the local feature.
+function initializeGlobalAccounts(svm: LiteSVM) {
+  const admin = Keypair.generate();
+  svm.airdrop(admin.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+  const adminState = deriveAdminState();
+  const eventAuthority = deriveEventAuthority();
+
+  const initializeAdminStateIx = new TransactionInstruction({
+    programId: CP_AMM_PROGRAM_ID,
+    keys: [
+      { pubkey: adminState, isSigner: false, isWritable: true },
+      { pubkey: admin.publicKey, isSigner: true, isWritable: false },
+      { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: eventAuthority, isSigner: false, isWritable: false },
+      { pubkey: CP_AMM_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: getInstructionDiscriminator("initialize_admin_state"),
+  });
+
+  const createGlobalPauseIx = new TransactionInstruction({
+    programId: CP_AMM_PROGRAM_ID,
+    keys: [
+      { pubkey: deriveGlobalPause(), isSigner: false, isWritable: true },
+      { pubkey: adminState, isSigner: false, isWritable: false },
+      { pubkey: admin.publicKey, isSigner: true, isWritable: false },
+      { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: eventAuthority, isSigner: false, isWritable: false },
+      { pubkey: CP_AMM_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: getInstructionDiscriminator("create_global_pause"),
+  });
+
+  const transaction = new Transaction()
+    .add(initializeAdminStateIx)
+    .add(createGlobalPauseIx);
+  const result = sendTransaction(svm, transaction, [admin]);
+  expect(result).instanceOf(TransactionMetadata);
+}
+
 export function sendTransaction(
   svm: LiteSVM,
   transaction: Transaction,